@@ -0,0 +1,279 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    num::NonZeroU32,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock, OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Error};
+use log::{info, warn};
+use ring::{
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::{
+    configs::{
+        config_core::{ConfigCore, ConfigCoreContent},
+        trait_config::ConfigImpl,
+    },
+    credential_manager::CredentialManager,
+};
+
+const LOG_TARGET: &str = "tari::universe::access_control";
+const PBKDF2_ITERATIONS: u32 = 210_000;
+const SALT_LENGTH: usize = 16;
+const MIN_PIN_LENGTH: usize = 4;
+const MAX_PIN_LENGTH: usize = 12;
+const MAX_FAILED_UNLOCKS: u32 = 5;
+const UNLOCK_LOCKOUT: Duration = Duration::from_secs(60);
+const OWNER_REQUIRED_MESSAGE: &str = "This action requires the admin PIN";
+const PIN_RECORD_NAME: &str = "admin_pin";
+
+static IS_ADMIN_UNLOCKED: AtomicBool = AtomicBool::new(false);
+static FAILED_UNLOCKS: LazyLock<Mutex<(u32, Option<Instant>)>> =
+    LazyLock::new(|| Mutex::new((0, None)));
+static PIN_STORE: OnceLock<CredentialManager> = OnceLock::new();
+
+/// What is kept in the credential store for the admin PIN
+#[derive(Serialize, Deserialize)]
+struct AdminPinRecord {
+    pin_hash: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Owner,
+    Guest,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccessStatus {
+    pub role: Role,
+    pub has_admin_pin: bool,
+}
+
+fn iterations() -> Result<NonZeroU32, Error> {
+    NonZeroU32::new(PBKDF2_ITERATIONS).ok_or_else(|| anyhow!("Invalid iteration count"))
+}
+
+fn derive_pin_hash(pin: &str, salt: &[u8]) -> Result<[u8; 32], Error> {
+    let iterations = iterations()?;
+    let mut hash = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        pin.as_bytes(),
+        &mut hash,
+    );
+    Ok(hash)
+}
+
+/// Stored as `<salt hex>:<hash hex>` in the credential store
+fn hash_pin(pin: &str) -> Result<String, Error> {
+    let mut salt = [0u8; SALT_LENGTH];
+    SystemRandom::new()
+        .fill(&mut salt)
+        .map_err(|_| anyhow!("Could not generate PIN salt"))?;
+    let hash = derive_pin_hash(pin, &salt)?;
+    Ok(format!("{}:{}", hex::encode(salt), hex::encode(hash)))
+}
+
+fn verify_pin(pin: &str, stored: &str) -> bool {
+    let Some((salt, hash)) = stored.split_once(':') else {
+        return false;
+    };
+    let (Ok(salt), Ok(hash), Ok(iterations)) = (hex::decode(salt), hex::decode(hash), iterations())
+    else {
+        return false;
+    };
+    pbkdf2::verify(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        &salt,
+        pin.as_bytes(),
+        &hash,
+    )
+    .is_ok()
+}
+
+fn is_valid_pin(pin: &str) -> bool {
+    (MIN_PIN_LENGTH..=MAX_PIN_LENGTH).contains(&pin.len())
+        && pin.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Lightweight owner/guest split for shared machines. Without an admin PIN everyone is the
+/// owner; once a PIN is set the app starts as a guest who can watch and control mining but
+/// needs the PIN to move funds, reveal secrets or change security settings.
+pub struct AccessControl;
+
+impl AccessControl {
+    /// Opens the credential store holding the PIN hash
+    pub fn initialize(config_dir: PathBuf) {
+        PIN_STORE.get_or_init(|| CredentialManager::for_record(PIN_RECORD_NAME, config_dir));
+    }
+
+    async fn has_pin() -> bool {
+        *ConfigCore::content().await.has_admin_pin()
+    }
+
+    /// A PIN marked as set whose record is missing or unreadable keeps the app locked
+    async fn stored_pin_hash() -> Result<String, Error> {
+        let store = PIN_STORE
+            .get()
+            .ok_or_else(|| anyhow!("Credential store is not initialized"))?;
+        let record: AdminPinRecord = store.get_record().await?;
+        Ok(record.pin_hash)
+    }
+
+    pub async fn role() -> Role {
+        if !Self::has_pin().await || IS_ADMIN_UNLOCKED.load(Ordering::SeqCst) {
+            Role::Owner
+        } else {
+            Role::Guest
+        }
+    }
+
+    pub async fn status() -> AccessStatus {
+        AccessStatus {
+            role: Self::role().await,
+            has_admin_pin: Self::has_pin().await,
+        }
+    }
+
+    pub async fn require_owner() -> Result<(), String> {
+        match Self::role().await {
+            Role::Owner => Ok(()),
+            Role::Guest => Err(OWNER_REQUIRED_MESSAGE.to_string()),
+        }
+    }
+
+    pub async fn unlock(pin: &str) -> Result<AccessStatus, String> {
+        if let Some(locked_until) = FAILED_UNLOCKS.lock().await.1 {
+            if Instant::now() < locked_until {
+                return Err("Too many wrong PIN attempts, try again later".to_string());
+            }
+        }
+
+        if !Self::has_pin().await {
+            return Ok(Self::status().await);
+        }
+        let stored = Self::stored_pin_hash().await.map_err(|e| {
+            warn!(target: LOG_TARGET, "Could not read the admin PIN record: {}", e);
+            "The admin PIN could not be read, the app stays locked".to_string()
+        })?;
+        let pin = pin.to_string();
+        let is_valid = tokio::task::spawn_blocking(move || verify_pin(&pin, &stored))
+            .await
+            .map_err(|e| e.to_string())?;
+        if !is_valid {
+            let mut failed_unlocks = FAILED_UNLOCKS.lock().await;
+            failed_unlocks.0 += 1;
+            warn!(target: LOG_TARGET, "Wrong admin PIN entered ({} attempts)", failed_unlocks.0);
+            if failed_unlocks.0 >= MAX_FAILED_UNLOCKS {
+                *failed_unlocks = (0, Some(Instant::now() + UNLOCK_LOCKOUT));
+            }
+            return Err("Wrong PIN".to_string());
+        }
+
+        *FAILED_UNLOCKS.lock().await = (0, None);
+        IS_ADMIN_UNLOCKED.store(true, Ordering::SeqCst);
+        info!(target: LOG_TARGET, "Admin mode unlocked");
+        Ok(Self::status().await)
+    }
+
+    pub async fn lock() -> AccessStatus {
+        IS_ADMIN_UNLOCKED.store(false, Ordering::SeqCst);
+        Self::status().await
+    }
+
+    /// Sets, changes or (with `None`) removes the admin PIN. Only the owner may do this.
+    pub async fn set_pin(new_pin: Option<String>) -> Result<AccessStatus, String> {
+        Self::require_owner().await?;
+        let store = PIN_STORE
+            .get()
+            .ok_or_else(|| "Credential store is not initialized".to_string())?;
+        match new_pin {
+            Some(pin) if !is_valid_pin(&pin) => {
+                return Err(format!(
+                    "PIN must be {} to {} digits",
+                    MIN_PIN_LENGTH, MAX_PIN_LENGTH
+                ));
+            }
+            Some(pin) => {
+                let pin_hash = tokio::task::spawn_blocking(move || hash_pin(&pin))
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .map_err(|e| e.to_string())?;
+                store
+                    .set_record(&AdminPinRecord { pin_hash })
+                    .await
+                    .map_err(|e| e.to_string())?;
+                ConfigCore::update_field(ConfigCoreContent::set_has_admin_pin, true)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            None => {
+                ConfigCore::update_field(ConfigCoreContent::set_has_admin_pin, false)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                if let Err(e) = store.delete_record() {
+                    warn!(target: LOG_TARGET, "Could not delete the admin PIN record: {}", e);
+                }
+            }
+        }
+        // Keep the current session unlocked so the owner isn't locked out by setting a PIN
+        IS_ADMIN_UNLOCKED.store(true, Ordering::SeqCst);
+        Ok(Self::status().await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pin_round_trip() {
+        let stored = hash_pin("4821").unwrap_or_default();
+        assert!(verify_pin("4821", &stored));
+        assert!(!verify_pin("1284", &stored));
+        assert!(!verify_pin("4821", "not-a-hash"));
+    }
+
+    #[test]
+    fn pin_must_be_short_numeric() {
+        assert!(is_valid_pin("1234"));
+        assert!(!is_valid_pin("123"));
+        assert!(!is_valid_pin("12ab"));
+        assert!(!is_valid_pin("1234567890123"));
+    }
+}
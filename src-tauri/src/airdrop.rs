@@ -20,20 +20,113 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock, OnceLock,
+    },
+};
+
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
-use log::{error, info, warn};
+use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sha2::{Digest, Sha256};
+use tari_common::configuration::Network;
 use tauri::{AppHandle, Manager};
+use tokio::sync::RwLock;
 
 use crate::{
-    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    configs::{
+        config_core::{AirdropTokens, ConfigCore, ConfigCoreContent},
+        trait_config::ConfigImpl,
+    },
+    credential_manager::{CredentialError, CredentialManager},
+    events_emitter::EventsEmitter,
     tasks_tracker::TasksTrackers,
+    websocket_manager::WebsocketManagerStatusMessage,
     UniverseAppState,
 };
 
 const LOG_TARGET: &str = "tari::universe::airdrop";
 
+static AIRDROP_ACTIVITY: LazyLock<RwLock<AirdropActivity>> =
+    LazyLock::new(|| RwLock::new(AirdropActivity::default()));
+static TOKENS_STORE: OnceLock<CredentialManager> = OnceLock::new();
+/// Set once the credential store holds the tokens, from then on they are left out of the
+/// core config file
+static ARE_TOKENS_IN_STORE: AtomicBool = AtomicBool::new(false);
+
+/// Keeps the airdrop tokens in the credential store instead of the plaintext core config
+pub struct AirdropTokensStore;
+
+impl AirdropTokensStore {
+    /// Opens the credential store and loads the tokens into the core config, moving tokens
+    /// still kept in the config file into the store once
+    pub async fn initialize(config_dir: PathBuf) {
+        let record_name = format!("airdrop_tokens_{}", Network::get_current().as_key_str());
+        let store =
+            TOKENS_STORE.get_or_init(|| CredentialManager::for_record(&record_name, config_dir));
+
+        if let Some(tokens) = ConfigCore::content().await.airdrop_tokens().clone() {
+            if let Err(e) = store.set_record(&tokens).await {
+                warn!(target: LOG_TARGET, "Could not move airdrop tokens to the credential store: {}", e);
+                return;
+            }
+            ARE_TOKENS_IN_STORE.store(true, Ordering::SeqCst);
+            let _unused = ConfigCore::update_sensitive_field(
+                ConfigCoreContent::set_airdrop_tokens,
+                Some(tokens),
+            )
+            .await;
+            info!(target: LOG_TARGET, "Moved airdrop tokens to the credential store");
+            return;
+        }
+
+        match store.get_record::<AirdropTokens>().await {
+            Ok(tokens) => {
+                ARE_TOKENS_IN_STORE.store(true, Ordering::SeqCst);
+                ConfigCore::current()
+                    .write()
+                    .await
+                    ._get_content_mut()
+                    .set_airdrop_tokens(Some(tokens));
+            }
+            Err(CredentialError::NoEntry(_)) => {
+                ARE_TOKENS_IN_STORE.store(true, Ordering::SeqCst);
+            }
+            Err(CredentialError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+                ARE_TOKENS_IN_STORE.store(true, Ordering::SeqCst);
+            }
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Could not read airdrop tokens from the credential store: {}", e);
+            }
+        }
+    }
+
+    pub fn are_tokens_in_store() -> bool {
+        ARE_TOKENS_IN_STORE.load(Ordering::SeqCst)
+    }
+
+    /// Writes the tokens to the credential store, or removes them for `None`. The core config
+    /// file keeps holding them when the store can't be written.
+    pub async fn save(tokens: Option<AirdropTokens>) -> Result<(), anyhow::Error> {
+        let stored = match (TOKENS_STORE.get(), &tokens) {
+            (Some(store), Some(tokens)) => {
+                store.set_record(tokens).await.map_err(anyhow::Error::from)
+            }
+            (Some(store), None) => store.delete_record().map_err(anyhow::Error::from),
+            (None, _) => Err(anyhow::anyhow!("Credential store is not initialized")),
+        };
+        if let Err(e) = &stored {
+            warn!(target: LOG_TARGET, "Could not write airdrop tokens to the credential store: {}", e);
+        }
+        ARE_TOKENS_IN_STORE.store(stored.is_ok(), Ordering::SeqCst);
+        ConfigCore::update_sensitive_field(ConfigCoreContent::set_airdrop_tokens, tokens).await
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AirdropAccessToken {
     pub exp: u64,
@@ -52,6 +145,139 @@ pub struct AirdropMinedBlockMessage {
     pub block_height: u64,
 }
 
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AirdropUserPoints {
+    pub gems: f64,
+    pub shells: f64,
+    pub hammers: f64,
+}
+
+/// Rewards reported by the airdrop websocket during this session
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AirdropActivity {
+    pub user_points: Option<AirdropUserPoints>,
+    pub completed_quests: Vec<String>,
+    pub referral_install_rewards: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AirdropUserEventData {
+    quest_name: Option<String>,
+    user_points: Option<AirdropUserPoints>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AirdropUserEvent {
+    name: String,
+    data: Option<AirdropUserEventData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AirdropStatus {
+    pub connection_status: WebsocketManagerStatusMessage,
+    pub is_logged_in: bool,
+    pub user_id: Option<String>,
+    pub activity: AirdropActivity,
+    pub allow_telemetry: bool,
+    pub allow_airdrop_participation: bool,
+}
+
+impl AirdropActivity {
+    pub async fn current() -> AirdropActivity {
+        AIRDROP_ACTIVITY.read().await.clone()
+    }
+
+    pub async fn reset() {
+        *AIRDROP_ACTIVITY.write().await = AirdropActivity::default();
+    }
+
+    /// Picks up gems and claimed rewards from a message received on the airdrop websocket.
+    /// Messages are `{ event, data }` where `data` holds the user event as a JSON string.
+    pub async fn handle_websocket_message(message: &Value) {
+        let Some(data) = message.get("data").and_then(Value::as_str) else {
+            return;
+        };
+        let event = match serde_json::from_str::<AirdropUserEvent>(data) {
+            Ok(event) => event,
+            Err(e) => {
+                debug!(target: LOG_TARGET, "Skipping websocket message that is not a user event: {}", e);
+                return;
+            }
+        };
+
+        match event.name.as_str() {
+            "completed_quest" => {
+                let Some(data) = event.data else {
+                    return;
+                };
+                if let Some(user_points) = data.user_points.clone() {
+                    Self::update_user_points(user_points).await;
+                }
+                if let Some(quest_name) = data.quest_name {
+                    AIRDROP_ACTIVITY
+                        .write()
+                        .await
+                        .completed_quests
+                        .push(quest_name.clone());
+                    EventsEmitter::emit_airdrop_quest_completed(quest_name, data.user_points).await;
+                }
+            }
+            "user_score_update" => {
+                if let Some(user_points) = event.data.and_then(|data| data.user_points) {
+                    Self::update_user_points(user_points).await;
+                }
+            }
+            "referral_install_reward" => {
+                let referral_install_rewards = {
+                    let mut activity = AIRDROP_ACTIVITY.write().await;
+                    activity.referral_install_rewards += 1;
+                    activity.referral_install_rewards
+                };
+                EventsEmitter::emit_airdrop_referral_applied(referral_install_rewards).await;
+            }
+            _ => {}
+        }
+    }
+
+    async fn update_user_points(user_points: AirdropUserPoints) {
+        let total_gems = user_points.gems;
+        let previous_gems = AIRDROP_ACTIVITY
+            .write()
+            .await
+            .user_points
+            .replace(user_points)
+            .map(|points| points.gems);
+
+        // The first score update of a session only sets the baseline
+        if let Some(previous_gems) = previous_gems {
+            if total_gems > previous_gems {
+                EventsEmitter::emit_airdrop_gems_earned(total_gems - previous_gems, total_gems)
+                    .await;
+            }
+        }
+    }
+}
+
+pub async fn get_airdrop_status(connection_status: WebsocketManagerStatusMessage) -> AirdropStatus {
+    let config = ConfigCore::content().await;
+    let token = config.airdrop_tokens().clone().map(|tokens| tokens.token);
+    let user_id = token
+        .as_deref()
+        .and_then(decode_jwt_claims_without_exp)
+        .map(|claims| claims.id);
+    let is_logged_in = validate_jwt(token).await.is_some();
+
+    AirdropStatus {
+        connection_status,
+        is_logged_in,
+        user_id,
+        activity: AirdropActivity::current().await,
+        allow_telemetry: *config.allow_telemetry(),
+        allow_airdrop_participation: *config.allow_airdrop_participation(),
+    }
+}
+
 pub fn decode_jwt_claims(t: &str) -> Option<AirdropAccessToken> {
     let key = DecodingKey::from_secret(&[]);
     let mut validation = Validation::new(Algorithm::HS256);
@@ -111,6 +337,9 @@ pub async fn get_wallet_view_key_hashed(app: AppHandle) -> String {
 }
 
 pub async fn send_new_block_mined(app: AppHandle, block_height: u64) {
+    if !*ConfigCore::content().await.allow_airdrop_participation() {
+        return;
+    }
     TasksTrackers::current().wallet_phase.get_task_tracker().await.spawn(async move {
         let app_in_config_memory = app.state::<UniverseAppState>().in_memory_config.clone();
         let config = ConfigCore::content().await;
@@ -20,7 +20,13 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::sync::LazyLock;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+    time::Duration,
+};
 
 use anyhow::anyhow;
 use auto_launch::{AutoLaunch, AutoLaunchBuilder};
@@ -38,20 +44,26 @@ use tokio::sync::RwLock;
 #[cfg(target_os = "windows")]
 use whoami::username;
 
-use crate::utils::platform_utils::{CurrentOperatingSystem, PlatformUtils};
+use crate::{
+    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
+};
 
 const LOG_TARGET: &str = "tari::universe::auto_launcher";
+pub const AUTO_LAUNCHED_ARG: &str = "--auto-launched";
 
 static INSTANCE: LazyLock<AutoLauncher> = LazyLock::new(AutoLauncher::new);
 
 pub struct AutoLauncher {
     auto_launcher: RwLock<Option<AutoLaunch>>,
+    is_auto_launched: AtomicBool,
 }
 
 impl AutoLauncher {
     fn new() -> Self {
         Self {
             auto_launcher: RwLock::new(None),
+            is_auto_launched: AtomicBool::new(false),
         }
     }
 
@@ -62,18 +74,21 @@ impl AutoLauncher {
             CurrentOperatingSystem::Windows => AutoLaunchBuilder::new()
                 .set_app_name(app_name)
                 .set_app_path(app_path)
+                .set_args(&[AUTO_LAUNCHED_ARG])
                 .set_use_launch_agent(false)
                 .build()
                 .map_err(|e| e.into()),
             CurrentOperatingSystem::Linux => AutoLaunchBuilder::new()
                 .set_app_name(app_name)
                 .set_app_path(app_path)
+                .set_args(&[AUTO_LAUNCHED_ARG])
                 .set_use_launch_agent(false)
                 .build()
                 .map_err(|e| e.into()),
             CurrentOperatingSystem::MacOS => AutoLaunchBuilder::new()
                 .set_app_name(app_name)
                 .set_app_path(app_path)
+                .set_args(&[AUTO_LAUNCHED_ARG])
                 .set_use_launch_agent(true)
                 .build()
                 .map_err(|e| e.into()),
@@ -194,7 +209,12 @@ impl AutoLauncher {
             .create_logon()
             .author("Tari Universe")?
             .trigger("startup_trigger", is_triggered)?
-            .action(Action::new("startup_action", &app_path, "", ""))?
+            .action(Action::new(
+                "startup_action",
+                &app_path,
+                "",
+                AUTO_LAUNCHED_ARG,
+            ))?
             .principal(PrincipalSettings {
                 display_name: "Tari Universe".to_string(),
                 group_id: None,
@@ -298,6 +318,29 @@ impl AutoLauncher {
         Ok(())
     }
 
+    pub fn mark_as_auto_launched(&self) {
+        self.is_auto_launched.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_auto_launched(&self) -> bool {
+        self.is_auto_launched.load(Ordering::SeqCst)
+    }
+
+    /// Waits for the configured delay when the app was started by the auto-launcher
+    pub async fn wait_for_launch_delay(&self) {
+        if !self.is_auto_launched() {
+            return;
+        }
+        let delay_minutes = ConfigCore::content()
+            .await
+            .auto_launch_options()
+            .delay_minutes;
+        if delay_minutes > 0 {
+            info!(target: LOG_TARGET, "Delaying setup by {} minutes after auto-launch", delay_minutes);
+            tokio::time::sleep(Duration::from_secs(u64::from(delay_minutes) * 60)).await;
+        }
+    }
+
     pub fn current() -> &'static AutoLauncher {
         &INSTANCE
     }
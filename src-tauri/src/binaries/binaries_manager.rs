@@ -23,14 +23,21 @@ use anyhow::{anyhow, Error};
 use log::{debug, error, info, warn};
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, path::PathBuf, str::FromStr};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
 use tari_common::configuration::Network;
 use tauri_plugin_sentry::sentry;
 
 use crate::{
-    download_utils::{extract, validate_checksum},
+    download_utils::{calculate_checksum, extract, validate_checksum},
+    events_emitter::EventsEmitter,
     github::request_client::RequestClient,
-    progress_tracker_old::ProgressTracker,
+    progress_tracker_old::{ProgressTracker, ProgressUpdate},
+    APPLICATION_FOLDER_ID,
 };
 
 use super::{
@@ -52,10 +59,21 @@ pub(crate) struct BinaryManager {
     should_validate_checksum: bool,
     online_versions_list: Vec<VersionDownloadInfo>,
     local_aviailable_versions_list: Vec<Version>,
+    /// Installed before checksums were recorded, kept until they are checked against the release
+    unrecorded_local_versions: Vec<Version>,
     used_version: Option<Version>,
+    /// Binary that last passed the integrity check, it is hashed again only once it changes on disk
+    verified_binary: Option<VerifiedBinary>,
     adapter: Box<dyn LatestVersionApiAdapter>,
 }
 
+#[derive(PartialEq)]
+struct VerifiedBinary {
+    version: Version,
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
 impl BinaryManager {
     pub fn new(
         binary_name: String,
@@ -97,7 +115,9 @@ impl BinaryManager {
             version_requirements,
             online_versions_list: Vec::new(),
             local_aviailable_versions_list: Vec::new(),
+            unrecorded_local_versions: Vec::new(),
             used_version: None,
+            verified_binary: None,
             adapter,
         }
     }
@@ -195,9 +215,10 @@ impl BinaryManager {
             .join("in_progress");
 
         progress_tracker
-            .send_last_action(format!(
-                "Removing in progress folder: {:?}",
-                in_progress_folder
+            .send_progress(ProgressUpdate::new(
+                &self.binary_name,
+                "cleanup",
+                format!("Removing in progress folder: {:?}", in_progress_folder),
             ))
             .await;
         if in_progress_folder.exists() {
@@ -275,9 +296,10 @@ impl BinaryManager {
             assets: vec![asset.clone()],
         };
         progress_tracker
-            .send_last_action(format!(
-                "Downloading checksum file for dest: {:?}",
-                destination_dir
+            .send_progress(ProgressUpdate::new(
+                &self.binary_name,
+                "download_checksum",
+                format!("Downloading checksum file for dest: {:?}", destination_dir),
             ))
             .await;
         let checksum_file = self
@@ -302,9 +324,13 @@ impl BinaryManager {
             .await?;
 
         progress_tracker
-            .send_last_action(format!(
-                "Validating checksum for checksum file: {:?} and in progress file: {:?}",
-                checksum_file, in_progress_file_zip
+            .send_progress(ProgressUpdate::new(
+                &self.binary_name,
+                "validate_checksum",
+                format!(
+                    "Validating checksum for checksum file: {:?} and in progress file: {:?}",
+                    checksum_file, in_progress_file_zip
+                ),
             ))
             .await;
         match validate_checksum(in_progress_file_zip.clone(), expected_checksum).await {
@@ -462,6 +488,77 @@ impl BinaryManager {
         Err(anyhow!(last_error_message))
     }
 
+    /// Downloads the release asset to `destination`, from the fallback url if the main one fails
+    async fn download_asset(
+        &self,
+        asset: &VersionAsset,
+        version: &Version,
+        destination: &Path,
+        progress_tracker: &ProgressTracker,
+    ) -> Result<(), Error> {
+        let download_url = asset.clone().url;
+        let fallback_url = asset.clone().fallback_url;
+
+        info!(target: LOG_TARGET, "Downloading binary: {} from url: {}", self.binary_name, download_url);
+        progress_tracker
+            .send_progress(ProgressUpdate::new(
+                &self.binary_name,
+                "download",
+                format!(
+                    "Downloading binary: {} with version: {}",
+                    self.binary_name, version
+                ),
+            ))
+            .await;
+
+        if RequestClient::current()
+            .download_file_with_progress(
+                download_url.as_str(),
+                destination,
+                asset.source.is_mirror(),
+                progress_tracker,
+                &self.binary_name,
+            )
+            .await
+            .map_err(|e| anyhow!("Error downloading version: {:?}. Error: {:?}", version, e))
+            .is_err()
+        {
+            if let Some(fallback_url) = fallback_url {
+                info!(target: LOG_TARGET, "Downloading binary: {} from fallback url: {}", self.binary_name, fallback_url);
+                progress_tracker
+                    .send_progress(ProgressUpdate::new(
+                        &self.binary_name,
+                        "download_fallback",
+                        format!(
+                            "Downloading binary: {} with version: {} from fallback url",
+                            self.binary_name, version
+                        ),
+                    ))
+                    .await;
+
+                RequestClient::current()
+                    .download_file_with_progress(
+                        fallback_url.as_str(),
+                        destination,
+                        asset.source.is_mirror(),
+                        progress_tracker,
+                        &self.binary_name,
+                    )
+                    .await
+                    .map_err(|e| {
+                        anyhow!("Error downloading version: {:?}. Error: {:?}", version, e)
+                    })?;
+            } else {
+                return Err(anyhow!(
+                    "Error downloading version: {:?}. No fallback url provided",
+                    version
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_lines)]
     async fn download_selected_version(
         &self,
@@ -510,59 +607,18 @@ impl BinaryManager {
             .map_err(|e| anyhow!("Error creating in progress folder. Error: {:?}", e))?;
         let in_progress_file_zip = in_progress_dir.join(asset.name.clone());
 
-        let download_url = asset.clone().url;
-        let fallback_url = asset.clone().fallback_url;
-
-        info!(target: LOG_TARGET, "Downloading binary: {} from url: {}", self.binary_name, download_url);
-        progress_tracker
-            .send_last_action(format!(
-                "Downloading binary: {} with version: {}",
-                self.binary_name, version
-            ))
-            .await;
-
-        if RequestClient::current()
-            .download_file(
-                download_url.as_str(),
-                &in_progress_file_zip,
-                asset.source.is_mirror(),
-            )
-            .await
-            .map_err(|e| anyhow!("Error downloading version: {:?}. Error: {:?}", version, e))
-            .is_err()
-        {
-            if let Some(fallback_url) = fallback_url {
-                info!(target: LOG_TARGET, "Downloading binary: {} from fallback url: {}", self.binary_name, fallback_url);
-                progress_tracker
-                    .send_last_action(format!(
-                        "Downloading binary: {} with version: {} from fallback url",
-                        self.binary_name, version
-                    ))
-                    .await;
-
-                RequestClient::current()
-                    .download_file(
-                        fallback_url.as_str(),
-                        &in_progress_file_zip,
-                        asset.source.is_mirror(),
-                    )
-                    .await
-                    .map_err(|e| {
-                        anyhow!("Error downloading version: {:?}. Error: {:?}", version, e)
-                    })?;
-            } else {
-                return Err(anyhow!(
-                    "Error downloading version: {:?}. No fallback url provided",
-                    version
-                ));
-            }
-        }
+        self.download_asset(&asset, &version, &in_progress_file_zip, &progress_tracker)
+            .await?;
 
         progress_tracker
-            .send_last_action(format!(
-                "Extracting file: {} to dest: {}",
-                in_progress_file_zip.to_str().unwrap_or_default(),
-                destination_dir.to_str().unwrap_or_default()
+            .send_progress(ProgressUpdate::new(
+                &self.binary_name,
+                "extract",
+                format!(
+                    "Extracting file: {} to dest: {}",
+                    in_progress_file_zip.to_str().unwrap_or_default(),
+                    destination_dir.to_str().unwrap_or_default()
+                ),
             ))
             .await;
         extract(&in_progress_file_zip, &destination_dir)
@@ -585,6 +641,205 @@ impl BinaryManager {
             progress_tracker.clone(),
         )
         .await?;
+
+        self.record_binary_checksum(&version).await?;
+        Ok(())
+    }
+
+    fn get_binary_file_path(&self, version: &Version) -> Result<PathBuf, Error> {
+        let version_folder = self
+            .adapter
+            .get_binary_folder()
+            .map_err(|e| anyhow!("Error getting binary folder: {:?}", e))?
+            .join(version.to_string());
+        Ok(self.binary_file_in(version_folder, version))
+    }
+
+    /// Path of the binary inside an extracted release archive
+    fn binary_file_in(&self, mut version_folder: PathBuf, version: &Version) -> PathBuf {
+        if let Some(sub_folder) = &self.binary_subfolder {
+            version_folder = version_folder.join(sub_folder);
+        }
+        let binary_file = version_folder
+            .join(Binaries::from_name(&self.binary_name).binary_file_name(version.clone()));
+        let binary_file_with_exe = binary_file.with_extension("exe");
+        if !binary_file.exists() && binary_file_with_exe.exists() {
+            return binary_file_with_exe;
+        }
+        binary_file
+    }
+
+    /// Checksums are kept in the config dir, away from the binaries they describe, so
+    /// whoever can replace a binary in the cache dir can't just rewrite its record too
+    fn get_checksum_record_path(&self, version: &Version) -> Result<PathBuf, Error> {
+        let config_dir =
+            dirs::config_dir().ok_or_else(|| anyhow!("Failed to get config directory"))?;
+        Ok(config_dir
+            .join(APPLICATION_FOLDER_ID)
+            .join("binary_checksums")
+            .join(
+                Network::get_current_or_user_setting_or_default()
+                    .to_string()
+                    .to_lowercase(),
+            )
+            .join(&self.binary_name)
+            .join(format!("{}.sha256", version)))
+    }
+
+    fn has_checksum_record(&self, version: &Version) -> bool {
+        self.get_checksum_record_path(version)
+            .is_ok_and(|path| path.exists())
+    }
+
+    async fn record_binary_checksum(&self, version: &Version) -> Result<String, Error> {
+        let binary_file = self.get_binary_file_path(version)?;
+        let checksum = calculate_checksum(&binary_file).await.map_err(|e| {
+            anyhow!(
+                "Error calculating checksum of binary: {:?}. Error: {:?}",
+                binary_file,
+                e
+            )
+        })?;
+        let checksum_record = self.get_checksum_record_path(version)?;
+        if let Some(parent) = checksum_record.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(checksum_record, &checksum)?;
+        debug!(target: LOG_TARGET, "Recorded checksum for binary: {} with version: {:?}", self.binary_name, version);
+        Ok(checksum)
+    }
+
+    /// Records checksums for local versions installed before they were recorded. The release
+    /// checksum covers the archive and not the binary in it, so the archive is downloaded next
+    /// to the install, validated and the binary in it compared with the installed one. The
+    /// install itself is left alone, and versions that don't match stay ignored. Versions a newer
+    /// release replaces anyway are skipped.
+    pub async fn backfill_checksum_records(&mut self, progress_tracker: ProgressTracker) {
+        let highest_online_version = self.select_highest_online_version();
+        for version in std::mem::take(&mut self.unrecorded_local_versions) {
+            if highest_online_version
+                .as_ref()
+                .is_some_and(|online_version| online_version > &version)
+            {
+                continue;
+            }
+            match self
+                .verify_installed_against_release(&version, &progress_tracker)
+                .await
+            {
+                Ok(()) => {
+                    info!(target: LOG_TARGET, "Recorded checksum for previously installed binary: {} with version: {:?}", self.binary_name, version);
+                    self.local_aviailable_versions_list.push(version);
+                }
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "Ignoring local version: {:?} that could not be checked against its release. Error: {:?}", version, e);
+                }
+            }
+        }
+        self.local_aviailable_versions_list.sort_by(|a, b| b.cmp(a));
+    }
+
+    async fn verify_installed_against_release(
+        &self,
+        version: &Version,
+        progress_tracker: &ProgressTracker,
+    ) -> Result<(), Error> {
+        let asset = self.get_asset_for_selected_version(version.clone())?;
+        let in_progress_dir =
+            self.create_in_progress_folder_for_selected_version(version.clone())?;
+        let in_progress_file_zip = in_progress_dir.join(&asset.name);
+        let extracted_dir = in_progress_dir.join("extracted");
+
+        let result = async {
+            self.download_asset(&asset, version, &in_progress_file_zip, progress_tracker)
+                .await?;
+            if self.should_validate_checksum {
+                self.validate_checksum(
+                    version,
+                    asset.clone(),
+                    in_progress_dir.clone(),
+                    in_progress_file_zip.clone(),
+                    progress_tracker.clone(),
+                )
+                .await?;
+            }
+            extract(&in_progress_file_zip, &extracted_dir).await?;
+
+            let release_checksum =
+                calculate_checksum(&self.binary_file_in(extracted_dir.clone(), version)).await?;
+            let installed_checksum =
+                calculate_checksum(&self.get_binary_file_path(version)?).await?;
+            if release_checksum != installed_checksum {
+                return Err(anyhow!(
+                    "Installed binary does not match the release for version: {:?}",
+                    version
+                ));
+            }
+            Ok::<(), Error>(())
+        }
+        .await;
+
+        self.delete_in_progress_folder_for_selected_version(
+            version.clone(),
+            progress_tracker.clone(),
+        )
+        .await?;
+        result?;
+        self.record_binary_checksum(version).await?;
+        Ok(())
+    }
+
+    /// Compares the binary on disk against the checksum recorded when it was installed, so a
+    /// binary swapped out after download is never launched. The binary is only hashed again
+    /// when its size or modification time changed since it last passed.
+    pub async fn verify_binary_integrity(&mut self) -> Result<(), Error> {
+        let version = self
+            .used_version
+            .clone()
+            .ok_or_else(|| anyhow!("No version selected for binary {}", self.binary_name))?;
+
+        let binary_file = self.get_binary_file_path(&version)?;
+        let metadata = std::fs::metadata(&binary_file)?;
+        let on_disk = VerifiedBinary {
+            version: version.clone(),
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+        };
+        if self.verified_binary.as_ref() == Some(&on_disk) {
+            return Ok(());
+        }
+        self.verified_binary = None;
+
+        let checksum_record = self.get_checksum_record_path(&version)?;
+        let Ok(expected_checksum) = std::fs::read_to_string(&checksum_record) else {
+            error!(target: LOG_TARGET, "No recorded checksum for binary: {} with version: {:?}. Refusing to start it", self.binary_name, version);
+            return Err(anyhow!(
+                "Binary {} has no recorded checksum, reinstall it",
+                self.binary_name
+            ));
+        };
+
+        let actual_checksum = calculate_checksum(&binary_file).await?;
+        if actual_checksum != expected_checksum.trim() {
+            error!(target: LOG_TARGET, "Binary: {:?} does not match its recorded checksum. Refusing to start it", binary_file);
+            sentry::capture_message(
+                &format!("Binary integrity check failed for: {}", self.binary_name),
+                sentry::Level::Error,
+            );
+            EventsEmitter::emit_binary_integrity_violation(
+                self.binary_name.clone(),
+                version.to_string(),
+                expected_checksum.trim().to_string(),
+                actual_checksum,
+            )
+            .await;
+            return Err(anyhow!(
+                "Binary {} was modified after installation",
+                self.binary_name
+            ));
+        }
+
+        self.verified_binary = Some(on_disk);
         Ok(())
     }
 
@@ -614,9 +869,16 @@ impl BinaryManager {
                         match Version::from_str(version_folder_name) {
                             Ok(version) => {
                                 debug!(target: LOG_TARGET, "Found local version: {:?}", version);
-                                if self.check_if_version_meet_requirements(&version)
-                                    && self.check_if_files_for_version_exist(Some(version.clone()))
+                                if !self.check_if_version_meet_requirements(&version)
+                                    || !self.check_if_files_for_version_exist(Some(version.clone()))
                                 {
+                                    debug!(target: LOG_TARGET, "Skipping local version: {:?}", version);
+                                } else if !self.has_checksum_record(&version) {
+                                    // Installed before checksums were recorded, or the record is
+                                    // gone. Left out until it is checked against the release
+                                    warn!(target: LOG_TARGET, "Local version has no recorded checksum: {:?}", version);
+                                    self.unrecorded_local_versions.push(version);
+                                } else {
                                     debug!(target: LOG_TARGET, "Adding local version to list: {:?}", version);
                                     self.local_aviailable_versions_list.push(version);
                                 }
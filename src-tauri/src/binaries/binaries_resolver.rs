@@ -23,6 +23,7 @@
 use crate::configs::config_core::{ConfigCore, ConfigCoreContent};
 use crate::configs::trait_config::ConfigImpl;
 use crate::github::ReleaseSource;
+use crate::progress_tracker_old::ProgressUpdate;
 use crate::ProgressTracker;
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
@@ -263,6 +264,8 @@ impl BinaryResolver {
             )
         })?;
 
+        manager.lock().await.verify_binary_integrity().await?;
+
         if let Some(sub_folder) = manager.lock().await.binary_subfolder() {
             return Ok(base_dir
                 .join(sub_folder)
@@ -312,6 +315,9 @@ impl BinaryResolver {
 
         manager.read_local_versions().await;
         manager.check_for_updates().await;
+        manager
+            .backfill_checksum_records(progress_tracker.clone())
+            .await;
 
         // Selects the highest version from the Vec of downloaded versions and local versions
         let mut highest_version = manager.select_highest_version();
@@ -364,10 +370,14 @@ impl BinaryResolver {
         let highest_version = manager.select_highest_version();
 
         progress_tracker
-            .send_last_action(format!(
-                "Checking if files exist before download: {} {}",
+            .send_progress(ProgressUpdate::new(
                 binary.name(),
-                highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                "check_files",
+                format!(
+                    "Checking if files exist before download: {} {}",
+                    binary.name(),
+                    highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                ),
             ))
             .await;
 
@@ -380,10 +390,14 @@ impl BinaryResolver {
         }
 
         progress_tracker
-            .send_last_action(format!(
-                "Checking if files exist after download: {} {}",
+            .send_progress(ProgressUpdate::new(
                 binary.name(),
-                highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                "verify_files",
+                format!(
+                    "Checking if files exist after download: {} {}",
+                    binary.name(),
+                    highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                ),
             ))
             .await;
         let check_if_files_exist =
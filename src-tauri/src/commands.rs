@@ -20,41 +20,93 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::access_control::{AccessControl, AccessStatus, Role};
+use crate::airdrop::{AirdropActivity, AirdropStatus, AirdropTokensStore};
 use crate::app_in_memory_config::{
     get_der_encode_pub_key, get_websocket_key, AirdropInMemoryConfig, ExchangeMiner,
 };
 use crate::auto_launcher::AutoLauncher;
 use crate::binaries::{Binaries, BinaryResolver};
-use crate::configs::config_core::{AirdropTokens, ConfigCore, ConfigCoreContent};
-use crate::configs::config_mining::{ConfigMining, ConfigMiningContent, GpuThreads, MiningMode};
+use crate::configs::config_core::{
+    AirdropTokens, AutoLaunchOptions, ConfigCore, ConfigCoreContent, DnsOverHttps, FastSync,
+};
+use crate::configs::config_migration::{
+    migration_reports, MigrationReport, PendingMigrationReport,
+};
+use crate::configs::config_mining::{
+    ConfigMining, ConfigMiningContent, GpuThreads, LanCoordination, MiningMode, MiningSchedule,
+};
 use crate::configs::config_ui::{ConfigUI, ConfigUIContent, DisplayMode};
 use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent};
 use crate::configs::trait_config::ConfigImpl;
 use crate::credential_manager::{CredentialError, CredentialManager};
+use crate::demo_mode::DemoMode;
+use crate::emergency_stop::{EmergencyStop, EmergencyStopStatus};
 use crate::events::ConnectionStatusPayload;
 use crate::events_emitter::EventsEmitter;
 use crate::events_manager::EventsManager;
+use crate::exchange::exchange_manager::ExchangeManager;
+use crate::exchange::{SwapInitiation, SwapQuote, SwapQuoteRequest};
 use crate::external_dependencies::{
     ExternalDependencies, ExternalDependency, RequiredExternalDependency,
 };
 use crate::gpu_miner::EngineType;
 use crate::gpu_miner_adapter::{GpuMinerStatus, GpuNodeSource};
-use crate::gpu_status_file::GpuStatus;
+use crate::gpu_status_file::{GpuDevice, GpuStatus};
+use crate::hardware::gpu_driver_compat::{GpuDriverCompat, GpuDriverCompatibility};
+use crate::hardware::hardware_status_monitor::HardwareStatusMonitor;
+use crate::idempotency::IdempotencyCache;
 use crate::internal_wallet::{InternalWallet, PaperWalletConfig};
+use crate::jobs::{JobManager, JobStatus};
+use crate::mining_coordinator::{CoordinationStatus, MiningCoordinator};
+use crate::mining_tuner::{MiningTuner, TuningRecommendations};
+use crate::node::chain_snapshot::{self, ChainSnapshotInfo};
 use crate::node::node_manager::NodeType;
-use crate::p2pool::models::{Connections, P2poolStats};
+use crate::ootle::models::OotleTemplateCall;
+use crate::ootle::{OotleError, OotleWallet};
+use crate::outbound_queue::{OutboundQueue, PendingOutbound, MAX_OUTBOUND_REVIEW_MINUTES};
+use crate::p2pool::models::{ConnectedPeerInfo, Connections, P2poolStats};
+use crate::power_assertions::{PowerAssertionStatus, PowerAssertions};
 use crate::progress_tracker_old::ProgressTracker;
-use crate::setup::setup_manager::{SetupManager, SetupPhase};
+use crate::runtime_paths::RuntimePaths;
+use crate::service_installer::{ServiceInstallStatus, ServiceInstaller};
+use crate::setup::setup_manager::{InitializationState, SetupManager, SetupPhase, SetupProgress};
+use crate::spend_allowance::{SpendAllowanceStatus, SpendAllowances};
+use crate::spend_wallet_manager::{
+    check_sufficient_funds, estimate_send_fee, format_send_amount, parse_send_amount, sweep_amount,
+    SpendWalletError,
+};
 use crate::tapplets::interface::ActiveTapplet;
-use crate::tapplets::tapplet_server::start_tapplet;
+use crate::tapplets::tapplet_registry::{TappletRegistry, TappletStatus};
+use crate::tapplets::tapplet_storage::{
+    self, TappletStorageUsage, MAX_TAPPLET_STORAGE_QUOTA_BYTES,
+};
 use crate::tapplets::{TappletResolver, Tapplets};
 use crate::tasks_tracker::TasksTrackers;
 use crate::tor_adapter::TorConfig;
+use crate::tor_control_client::TorStatus;
 use crate::utils::address_utils::verify_send;
 use crate::utils::app_flow_utils::FrontendReadyChannel;
+use crate::utils::clipboard_monitor::ClipboardMonitor;
+use crate::utils::connectivity_check::{
+    check_inbound_port, port_from_multiaddr, run_connectivity_checks, ConnectivityCategory,
+    ConnectivityCheck, ConnectivityReport,
+};
+use crate::utils::earnings_report::{earnings_report_csv, rollup_daily_earnings, DailyEarnings};
+use crate::utils::explorer_utils::{build_explorer_urls, ExplorerUrls};
+use crate::utils::pagination::{parse_cursor, Page};
+use crate::utils::time_sync::{SystemTimeStatus, TimeSync};
+use crate::utils::timezone_utils::{parse_time_of_day, AppTimezone};
+use crate::utils::wallet_utils::{
+    apply_transaction_labels, normalize_tari_address, summarize_watched_addresses,
+    WatchedAddressStatus,
+};
 use crate::wallet_adapter::{TariAddressVariants, TransactionInfo, WalletBalance};
+use crate::wallet_backup::{self, WalletBackupStatus};
+use crate::wallet_grpc_connection::WalletConnectionHealth;
 use crate::wallet_manager::WalletManagerError;
 use crate::websocket_manager::WebsocketManagerStatusMessage;
+use crate::websocket_stats::{WebsocketStats, WebsocketStatsSnapshot};
 use crate::{airdrop, PoolStatus, UniverseAppState, APPLICATION_FOLDER_ID};
 
 use base64::prelude::*;
@@ -64,20 +116,27 @@ use monero_address_creator::Seed as MoneroSeed;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fs::{read_dir, remove_dir_all, remove_file, File};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
+use std::sync::LazyLock;
 use std::thread::{available_parallelism, sleep};
 use std::time::{Duration, Instant, SystemTime};
 use tari_common::configuration::Network;
 use tari_common_types::tari_address::{TariAddress, TariAddressFeatures};
 use tari_core::transactions::tari_amount::{MicroMinotari, Minotari};
+use tauri::http::header::ORIGIN;
 use tauri::ipc::InvokeError;
 use tauri::{Manager, PhysicalPosition, PhysicalSize};
 use tauri_plugin_sentry::sentry;
 
 const MAX_ACCEPTABLE_COMMAND_TIME: Duration = Duration::from_secs(1);
+const GPU_ENGINE_BENCHMARK_TIMEOUT: Duration = Duration::from_secs(60);
+static SEND_IDEMPOTENCY_CACHE: LazyLock<IdempotencyCache<Result<(), SpendWalletError>>> =
+    LazyLock::new(IdempotencyCache::default);
 const LOG_TARGET: &str = "tari::universe::commands";
 const LOG_TARGET_WEB: &str = "tari::universe::web";
 
@@ -106,6 +165,12 @@ pub struct ApplicationsVersions {
     bridge: String,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct TimezoneInfo {
+    timezone: String,
+    current_time: Option<String>,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct GpuMinerMetrics {
     hardware: Vec<GpuStatus>,
@@ -123,6 +188,7 @@ pub struct BaseNodeStatus {
 #[derive(Debug, Serialize, Clone)]
 pub struct CpuMinerStatus {
     pub is_mining: bool,
+    pub is_paused: bool,
     pub hash_rate: f64,
     pub estimated_earnings: u64,
     pub connection: CpuMinerConnectionStatus,
@@ -133,6 +199,7 @@ impl Default for CpuMinerStatus {
     fn default() -> Self {
         Self {
             is_mining: false,
+            is_paused: false,
             hash_rate: 0.0,
             estimated_earnings: 0,
             connection: CpuMinerConnectionStatus {
@@ -211,6 +278,7 @@ pub async fn user_selected_exchange(
     app_handle: tauri::AppHandle,
     exchange_miner: ExchangeMiner,
 ) -> Result<(), String> {
+    AccessControl::require_owner().await?;
     SetupManager::get_instance()
         .select_exchange_miner(exchange_miner, app_handle.clone())
         .await?;
@@ -365,6 +433,18 @@ pub async fn get_applications_versions(
     })
 }
 
+#[tauri::command]
+pub async fn get_explorer_urls(
+    block_height: Option<u64>,
+    tx_id: Option<String>,
+) -> Result<ExplorerUrls, String> {
+    Ok(build_explorer_urls(
+        Network::get_current_or_user_setting_or_default(),
+        block_height,
+        tx_id.as_deref(),
+    ))
+}
+
 #[tauri::command]
 pub async fn get_external_dependencies() -> Result<RequiredExternalDependency, String> {
     let timer = Instant::now();
@@ -431,6 +511,10 @@ pub async fn get_network(
 
 #[tauri::command]
 pub async fn get_monero_seed_words(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    AccessControl::require_owner().await?;
+    if DemoMode::is_enabled() {
+        return DemoMode::monero_seed_words();
+    }
     let timer = Instant::now();
 
     if !*ConfigWallet::content().await.monero_address_is_generated() {
@@ -469,6 +553,72 @@ pub async fn get_monero_seed_words(app: tauri::AppHandle) -> Result<Vec<String>,
     seed.seed_words().map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn get_swap_quote(
+    request: SwapQuoteRequest,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<SwapQuote, InvokeError> {
+    let timer = Instant::now();
+    let exchange_manager = ExchangeManager::from_config(&*state.in_memory_config.read().await);
+    let quote = exchange_manager
+        .get_swap_quote(request)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_swap_quote took too long: {:?}", timer.elapsed());
+    }
+    Ok(quote)
+}
+
+#[tauri::command]
+pub async fn initiate_swap(
+    quote_id: String,
+    destination_address: String,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<SwapInitiation, InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let timer = Instant::now();
+    let exchange_manager = ExchangeManager::from_config(&*state.in_memory_config.read().await);
+    let swap = exchange_manager
+        .initiate_swap(&quote_id, destination_address)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "initiate_swap took too long: {:?}", timer.elapsed());
+    }
+    Ok(swap)
+}
+
+#[tauri::command]
+pub async fn set_allow_exchange_swaps(allow_exchange_swaps: bool) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let timer = Instant::now();
+    ConfigCore::update_field(
+        ConfigCoreContent::set_allow_exchange_swaps,
+        allow_exchange_swaps,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_allow_exchange_swaps took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_ootle_account_balances() -> Result<(), OotleError> {
+    OotleWallet::get_account_balances().await
+}
+
+#[tauri::command]
+pub async fn call_ootle_template(call: OotleTemplateCall) -> Result<(), OotleError> {
+    OotleWallet::call_template(call).await
+}
+
 #[tauri::command]
 pub async fn get_p2pool_stats(
     state: tauri::State<'_, UniverseAppState>,
@@ -482,16 +632,83 @@ pub async fn get_p2pool_stats(
     Ok(p2pool_stats)
 }
 
+#[tauri::command]
+pub async fn check_connectivity(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<ConnectivityReport, String> {
+    let timer = Instant::now();
+    let telemetry_api_url = state
+        .in_memory_config
+        .read()
+        .await
+        .telemetry_api_url
+        .clone();
+    let mut checks = run_connectivity_checks(
+        Network::get_current_or_user_setting_or_default(),
+        telemetry_api_url,
+    )
+    .await;
+
+    if state.p2pool_manager.is_running().await {
+        let peer_count = state
+            .p2pool_manager
+            .get_connections()
+            .await
+            .ok()
+            .flatten()
+            .map(|connections| connections.peer_count())
+            .unwrap_or_default();
+        checks.push(ConnectivityCheck {
+            category: ConnectivityCategory::P2poolPeers,
+            target: "sha_p2pool".to_string(),
+            is_reachable: peer_count > 0,
+            latency_ms: None,
+            details: Some(format!("{} connected peers", peer_count)),
+        });
+    }
+
+    if state.node_manager.is_local_current().await.unwrap_or(false) {
+        if let Some(port) = state
+            .node_manager
+            .get_connection_details()
+            .await
+            .ok()
+            .and_then(|(_, address)| port_from_multiaddr(&address))
+        {
+            checks.push(check_inbound_port(port).await);
+        }
+    }
+
+    let report = ConnectivityReport {
+        is_fully_reachable: checks.iter().all(|check| check.is_reachable),
+        checks,
+    };
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "check_connectivity took too long: {:?}", timer.elapsed());
+    }
+    Ok(report)
+}
+
 #[tauri::command]
 pub async fn get_p2pool_connections(
     state: tauri::State<'_, UniverseAppState>,
-) -> Result<Option<Connections>, String> {
+    cursor: Option<String>,
+    limit: Option<u32>,
+) -> Result<Page<ConnectedPeerInfo>, String> {
     let timer = Instant::now();
     if state.is_getting_p2pool_connections.load(Ordering::SeqCst) {
         let read = state.cached_p2pool_connections.read().await;
         if let Some(connections) = &*read {
             warn!(target: LOG_TARGET, "Already getting p2pool connections, returning cached value");
-            return Ok(connections.clone());
+            return Page::from_all(
+                connections
+                    .clone()
+                    .map(Connections::into_peers)
+                    .unwrap_or_default(),
+                cursor.as_deref(),
+                limit,
+            );
         }
         warn!(target: LOG_TARGET, "Already getting p2pool connections");
         return Err("Already getting p2pool connections".to_string());
@@ -516,7 +733,13 @@ pub async fn get_p2pool_connections(
     state
         .is_getting_p2pool_connections
         .store(false, Ordering::SeqCst);
-    Ok(p2pool_connections)
+    Page::from_all(
+        p2pool_connections
+            .map(Connections::into_peers)
+            .unwrap_or_default(),
+        cursor.as_deref(),
+        limit,
+    )
 }
 
 #[tauri::command]
@@ -559,12 +782,84 @@ pub async fn get_universal_miner_initialized_exchange_id() -> Result<Option<Stri
         .clone())
 }
 
+#[tauri::command]
+pub async fn get_system_time_status() -> Result<SystemTimeStatus, String> {
+    Ok(TimeSync::current().get_status())
+}
+
+#[tauri::command]
+pub async fn get_timezone_info() -> Result<TimezoneInfo, String> {
+    let timezone = AppTimezone::current().await;
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    Ok(TimezoneInfo {
+        timezone: timezone.name(),
+        current_time: timezone.format_timestamp(i64::try_from(now).unwrap_or(i64::MAX)),
+    })
+}
+
+#[tauri::command]
+pub async fn get_daily_earnings(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<Vec<DailyEarnings>, String> {
+    let timer = Instant::now();
+    let transactions = state
+        .wallet_manager
+        .get_coinbase_transactions(false, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    let rollup = rollup_daily_earnings(&transactions, &AppTimezone::current().await);
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_daily_earnings took too long: {:?}", timer.elapsed());
+    }
+    Ok(rollup)
+}
+
+#[tauri::command]
+pub async fn export_earnings_report(
+    destination_dir: String,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<String, InvokeError> {
+    let destination_dir = PathBuf::from(destination_dir);
+    if !destination_dir.is_dir() {
+        return Err(InvokeError::from(
+            "Report destination must be an existing directory",
+        ));
+    }
+    let transactions = state
+        .wallet_manager
+        .get_coinbase_transactions(false, None)
+        .await
+        .map_err(|e| InvokeError::from(e.to_string()))?;
+    let timezone = AppTimezone::current().await;
+    let report_file = destination_dir.join(format!(
+        "earnings-report-{}.csv",
+        timezone.local_date_of(chrono::Utc::now())
+    ));
+    tokio::fs::write(&report_file, earnings_report_csv(&transactions, &timezone))
+        .await
+        .map_err(|e| InvokeError::from(format!("Could not write earnings report: {e}")))?;
+    Ok(report_file.to_string_lossy().to_string())
+}
+
 #[tauri::command]
 pub async fn get_paper_wallet_details(
     app: tauri::AppHandle,
     state: tauri::State<'_, UniverseAppState>,
     auth_uuid: Option<String>,
 ) -> Result<PaperWalletConfig, InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    if DemoMode::is_enabled() {
+        return Err(InvokeError::from(
+            "Paper wallet is not available in demo mode".to_string(),
+        ));
+    }
     let timer = Instant::now();
     let config_path = app
         .path()
@@ -596,6 +891,10 @@ pub async fn get_paper_wallet_details(
 
 #[tauri::command]
 pub async fn get_seed_words(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    AccessControl::require_owner().await?;
+    if DemoMode::is_enabled() {
+        return Ok(DemoMode::seed_words());
+    }
     let timer = Instant::now();
     let config_path = app
         .path()
@@ -609,6 +908,9 @@ pub async fn get_seed_words(app: tauri::AppHandle) -> Result<Vec<String>, String
         .decrypt_seed_words()
         .await
         .map_err(|e| e.to_string())?;
+    if let Err(e) = wallet_backup::record_seed_words_revealed().await {
+        warn!(target: LOG_TARGET, "Could not record seed words backup: {:?}", e);
+    }
     let mut res = vec![];
     for i in 0..seed_words.len() {
         match seed_words.get_word(i) {
@@ -626,6 +928,10 @@ pub async fn get_seed_words(app: tauri::AppHandle) -> Result<Vec<String>, String
 
 #[tauri::command]
 pub async fn set_tari_address(address: String, app_handle: tauri::AppHandle) -> Result<(), String> {
+    AccessControl::require_owner().await?;
+    if DemoMode::is_enabled() {
+        return Err("The wallet address can't be changed in demo mode".to_string());
+    }
     let timer = Instant::now();
     let config_path = app_handle
         .path()
@@ -672,6 +978,9 @@ pub async fn confirm_exchange_address(
     address: String,
     app: tauri::AppHandle,
 ) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
     let timer = Instant::now();
     let config_path = app
         .path()
@@ -699,6 +1008,28 @@ pub async fn confirm_exchange_address(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn install_headless_service() -> Result<ServiceInstallStatus, InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    ServiceInstaller::install()
+        .inspect_err(|e| error!(target: LOG_TARGET, "Could not install headless service: {:?}", e))
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn uninstall_headless_service() -> Result<ServiceInstallStatus, InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    ServiceInstaller::uninstall()
+        .inspect_err(
+            |e| error!(target: LOG_TARGET, "Could not uninstall headless service: {:?}", e),
+        )
+        .map_err(InvokeError::from_anyhow)
+}
+
 #[tauri::command]
 pub async fn get_tor_config(
     _window: tauri::Window,
@@ -735,6 +1066,7 @@ pub async fn get_airdrop_tokens(
     _window: tauri::Window,
     _app: tauri::AppHandle,
 ) -> Result<Option<AirdropTokens>, String> {
+    AccessControl::require_owner().await?;
     let timer = Instant::now();
     let airdrop_access_token = ConfigCore::content().await.airdrop_tokens().clone();
     if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
@@ -746,9 +1078,23 @@ pub async fn get_airdrop_tokens(
 #[tauri::command]
 pub async fn get_transactions_history(
     state: tauri::State<'_, UniverseAppState>,
-    offset: Option<i32>,
-    limit: Option<i32>,
-) -> Result<Vec<TransactionInfo>, String> {
+    cursor: Option<String>,
+    limit: Option<u32>,
+) -> Result<Page<TransactionInfo>, String> {
+    let offset = parse_cursor(cursor.as_deref())?;
+    let page_limit = limit.and_then(|limit| usize::try_from(limit).ok());
+    let wallet_offset = i32::try_from(offset).map_err(|e| e.to_string())?;
+    let wallet_limit = limit.and_then(|limit| i32::try_from(limit).ok());
+
+    if DemoMode::is_enabled() {
+        let mut transactions = DemoMode::get_transactions(Some(wallet_offset), wallet_limit).await;
+        apply_transaction_labels(&mut transactions, &ConfigWallet::content().await);
+        let total_estimate = u64::try_from(DemoMode::transaction_count().await).unwrap_or(u64::MAX);
+        return Ok(
+            Page::from_offset(transactions, offset, page_limit).with_total_estimate(total_estimate)
+        );
+    }
+
     let timer = Instant::now();
     if state.is_getting_transactions_history.load(Ordering::SeqCst) {
         warn!(target: LOG_TARGET, "Already getting transfers history");
@@ -757,9 +1103,9 @@ pub async fn get_transactions_history(
     state
         .is_getting_transactions_history
         .store(true, Ordering::SeqCst);
-    let transactions = state
+    let mut transactions = state
         .wallet_manager
-        .get_transactions_history(offset, limit)
+        .get_transactions_history(Some(wallet_offset), wallet_limit)
         .await
         .unwrap_or_else(|e| {
             if !matches!(e, WalletManagerError::WalletNotStarted) {
@@ -775,15 +1121,103 @@ pub async fn get_transactions_history(
     state
         .is_getting_transactions_history
         .store(false, Ordering::SeqCst);
-    Ok(transactions)
+    apply_transaction_labels(&mut transactions, &ConfigWallet::content().await);
+    Ok(Page::from_offset(transactions, offset, page_limit))
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TransactionLabels {
+    transactions: HashMap<String, String>,
+    addresses: HashMap<String, String>,
+}
+
+#[tauri::command]
+pub async fn get_tx_labels() -> Result<TransactionLabels, InvokeError> {
+    let config = ConfigWallet::content().await;
+    Ok(TransactionLabels {
+        transactions: config.tx_labels().clone(),
+        addresses: config.address_labels().clone(),
+    })
+}
+
+#[tauri::command]
+pub async fn set_tx_label(tx_id: String, label: String) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    ConfigWallet::update_field(ConfigWalletContent::set_tx_label, (tx_id, label))
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_tx_label took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_address_label(address: String, label: String) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    let address = normalize_tari_address(&address)
+        .ok_or_else(|| InvokeError::from(format!("Invalid Tari address: {}", address)))?;
+    ConfigWallet::update_field(ConfigWalletContent::set_address_label, (address, label))
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_address_label took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_watched_addresses(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<Vec<WatchedAddressStatus>, InvokeError> {
+    let timer = Instant::now();
+    let transactions = state
+        .wallet_manager
+        .get_transactions_history(None, None)
+        .await
+        .unwrap_or_else(|e| {
+            if !matches!(e, WalletManagerError::WalletNotStarted) {
+                warn!(target: LOG_TARGET, "Error getting transaction history: {}", e);
+            }
+            vec![]
+        });
+    let watched_addresses =
+        summarize_watched_addresses(&ConfigWallet::content().await, &transactions);
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_watched_addresses took too long: {:?}", timer.elapsed());
+    }
+    Ok(watched_addresses)
+}
+
+#[tauri::command]
+pub async fn add_watched_address(address: String) -> Result<(), InvokeError> {
+    let address = normalize_tari_address(&address)
+        .ok_or_else(|| InvokeError::from(format!("Invalid Tari address: {}", address)))?;
+    ConfigWallet::update_field(ConfigWalletContent::add_watched_address, address)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn remove_watched_address(address: String) -> Result<(), InvokeError> {
+    let address = normalize_tari_address(&address).unwrap_or(address);
+    ConfigWallet::update_field(ConfigWalletContent::remove_watched_address, address)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    Ok(())
 }
 
 #[tauri::command]
 pub async fn get_coinbase_transactions(
     state: tauri::State<'_, UniverseAppState>,
-    continuation: bool,
+    cursor: Option<String>,
     limit: Option<u32>,
-) -> Result<Vec<TransactionInfo>, String> {
+) -> Result<Page<TransactionInfo>, String> {
+    // The wallet keeps its place in the coinbase stream, the cursor only tells a follow-up page
+    // apart from a fresh listing
+    let offset = parse_cursor(cursor.as_deref())?;
+    let continuation = cursor.is_some();
     let timer = Instant::now();
     if state.is_getting_coinbase_history.load(Ordering::SeqCst) {
         warn!(target: LOG_TARGET, "Already getting coinbase history");
@@ -792,7 +1226,7 @@ pub async fn get_coinbase_transactions(
     state
         .is_getting_coinbase_history
         .store(true, Ordering::SeqCst);
-    let transactions = state
+    let mut transactions = state
         .wallet_manager
         .get_coinbase_transactions(continuation, limit)
         .await
@@ -810,7 +1244,12 @@ pub async fn get_coinbase_transactions(
     state
         .is_getting_coinbase_history
         .store(false, Ordering::SeqCst);
-    Ok(transactions)
+    apply_transaction_labels(&mut transactions, &ConfigWallet::content().await);
+    Ok(Page::from_offset(
+        transactions,
+        offset,
+        limit.and_then(|limit| usize::try_from(limit).ok()),
+    ))
 }
 
 #[tauri::command]
@@ -819,6 +1258,10 @@ pub async fn import_seed_words(
     _window: tauri::Window,
     app: tauri::AppHandle,
 ) -> Result<(), String> {
+    AccessControl::require_owner().await?;
+    if DemoMode::is_enabled() {
+        return Err("Seed words can't be imported in demo mode".to_string());
+    }
     let timer = Instant::now();
     let config_path = app
         .path()
@@ -835,6 +1278,9 @@ pub async fn import_seed_words(
             InternalWallet::clear_wallet_local_data(data_dir)
                 .await
                 .map_err(|e| e.to_string())?;
+            wallet_backup::clear_backup_records()
+                .await
+                .map_err(|e| e.to_string())?;
             info!(target: LOG_TARGET, "[import_seed_words] Restarting the app");
             app.restart();
         }
@@ -875,7 +1321,16 @@ pub async fn reset_settings(
     _window: tauri::Window,
     app: tauri::AppHandle,
 ) -> Result<(), String> {
+    AccessControl::require_owner().await?;
     TasksTrackers::current().stop_all_processes().await;
+    if reset_wallet {
+        // Cleared before the config files are removed so the save cannot bring them back
+        wallet_backup::clear_backup_records()
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    // Airdrop tokens live outside the config files that are removed below
+    let _unused = AirdropTokensStore::save(None).await;
     let network = Network::get_current_or_user_setting_or_default().as_key_str();
 
     let app_config_dir = app.path().app_config_dir();
@@ -1019,12 +1474,72 @@ pub async fn send_feedback(
 
 #[tauri::command]
 pub async fn set_allow_telemetry(allow_telemetry: bool) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
     ConfigCore::update_field(ConfigCoreContent::set_allow_telemetry, allow_telemetry)
         .await
         .map_err(InvokeError::from_anyhow)?;
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_allow_airdrop_participation(
+    allow_airdrop_participation: bool,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    ConfigCore::update_field(
+        ConfigCoreContent::set_allow_airdrop_participation,
+        allow_airdrop_participation,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    if !allow_airdrop_participation {
+        websocket_close(app_handle, state)
+            .await
+            .map_err(InvokeError::from)?;
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_allow_airdrop_participation took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_airdrop_status(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<AirdropStatus, InvokeError> {
+    let timer = Instant::now();
+    let connection_status = state.websocket_manager_status_rx.borrow().clone();
+    let status = airdrop::get_airdrop_status(connection_status).await;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_airdrop_status took too long: {:?}", timer.elapsed());
+    }
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn set_clipboard_protection_enabled(
+    clipboard_protection_enabled: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    ConfigCore::update_field(
+        ConfigCoreContent::set_clipboard_protection_enabled,
+        clipboard_protection_enabled,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ClipboardMonitor::spawn(app_handle).await;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_allow_notifications(allow_notifications: bool) -> Result<(), InvokeError> {
     ConfigCore::update_field(
@@ -1197,16 +1712,18 @@ pub async fn set_mode(
     mode: String,
     custom_cpu_usage: Option<u32>,
     custom_gpu_usage: Vec<GpuThreads>,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
 ) -> Result<(), InvokeError> {
     let timer = Instant::now();
     info!(target: LOG_TARGET, "[set_mode] called with mode: {:?}", mode);
-    if let Some(mode) = MiningMode::from_str(&mode) {
-        ConfigMining::update_field(ConfigMiningContent::set_mode, mode)
-            .await
-            .map_err(InvokeError::from_anyhow)?;
-    } else {
+    let Some(mode) = MiningMode::from_str(&mode) else {
         return Err(InvokeError::from("Invalid mode".to_string()));
-    }
+    };
+    let previous_mode = *ConfigMining::content().await.mode();
+    ConfigMining::update_field(ConfigMiningContent::set_mode, mode)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
 
     ConfigMining::update_field(
         ConfigMiningContent::set_custom_max_cpu_usage,
@@ -1222,6 +1739,27 @@ pub async fn set_mode(
     .await
     .map_err(InvokeError::from_anyhow)?;
 
+    // Running miners only pick up thread counts and grid sizes on start
+    let is_cpu_mining = state.cpu_miner.read().await.is_running().await;
+    let is_gpu_mining = state.gpu_miner.read().await.is_running().await;
+    if is_cpu_mining {
+        stop_cpu_mining(state.clone())
+            .await
+            .map_err(InvokeError::from)?;
+        start_cpu_mining(state.clone(), app_handle.clone())
+            .await
+            .map_err(InvokeError::from)?;
+    }
+    if is_gpu_mining {
+        stop_gpu_mining(state.clone())
+            .await
+            .map_err(InvokeError::from)?;
+        start_gpu_mining(state.clone(), app_handle, None)
+            .await
+            .map_err(InvokeError::from)?;
+    }
+    EventsEmitter::emit_mining_mode_changed(previous_mode, mode).await;
+
     if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
         warn!(target: LOG_TARGET, "set_mode took too long: {:?}", timer.elapsed());
     }
@@ -1230,24 +1768,154 @@ pub async fn set_mode(
 }
 
 #[tauri::command]
-pub async fn set_monero_address(
-    monero_address: String,
-    app_handle: tauri::AppHandle,
+pub async fn set_mining_schedule(
+    mining_schedule: Option<MiningSchedule>,
 ) -> Result<(), InvokeError> {
     let timer = Instant::now();
-    ConfigWallet::update_field_requires_restart(
-        ConfigWalletContent::set_user_monero_address,
-        monero_address,
-        vec![SetupPhase::Mining],
-    )
-    .await
-    .map_err(InvokeError::from_anyhow)?;
+    if let Some(schedule) = &mining_schedule {
+        parse_time_of_day(&schedule.start_time).map_err(InvokeError::from_anyhow)?;
+        parse_time_of_day(&schedule.end_time).map_err(InvokeError::from_anyhow)?;
+    }
+    ConfigMining::update_field(ConfigMiningContent::set_mining_schedule, mining_schedule)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
 
-    SetupManager::get_instance()
-        .restart_phases_from_queue(app_handle)
-        .await;
     if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
-        warn!(target: LOG_TARGET, "set_monero_address took too long: {:?}", timer.elapsed());
+        warn!(target: LOG_TARGET, "set_mining_schedule took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_coordination_status() -> Result<CoordinationStatus, InvokeError> {
+    Ok(MiningCoordinator::get_status().await)
+}
+
+#[tauri::command]
+pub async fn set_dns_over_https(
+    dns_over_https: DnsOverHttps,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let timer = Instant::now();
+    if dns_over_https.enabled && dns_over_https.providers.is_empty() {
+        return Err(InvokeError::from(
+            "At least one DNS-over-HTTPS provider is required",
+        ));
+    }
+    ConfigCore::update_field_requires_restart(
+        ConfigCoreContent::set_dns_over_https,
+        dns_over_https,
+        vec![SetupPhase::Node],
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_dns_over_https took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+/// Enabling requires `acknowledge_trust_risk`: the node will accept chain history from the
+/// snapshot signer instead of validating it from genesis
+#[tauri::command]
+pub async fn set_fast_sync(
+    fast_sync: FastSync,
+    acknowledge_trust_risk: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let timer = Instant::now();
+    if fast_sync.enabled {
+        if !acknowledge_trust_risk {
+            return Err(InvokeError::from(
+                "Fast-sync trusts the snapshot signer for chain history and must be explicitly acknowledged",
+            ));
+        }
+        if fast_sync.mirrors.is_empty() || fast_sync.trusted_public_key.trim().is_empty() {
+            return Err(InvokeError::from(
+                "Fast-sync requires at least one mirror and a trusted public key",
+            ));
+        }
+    }
+    ConfigCore::update_field_requires_restart(
+        ConfigCoreContent::set_fast_sync,
+        fast_sync,
+        vec![SetupPhase::Node],
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_fast_sync took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_lan_coordination(
+    lan_coordination: LanCoordination,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let timer = Instant::now();
+    if lan_coordination.group_name.trim().is_empty() {
+        return Err(InvokeError::from("Coordination group name cannot be empty"));
+    }
+    if lan_coordination.enabled && lan_coordination.shared_secret.len() < 16 {
+        return Err(InvokeError::from(
+            "Coordination shared secret must be at least 16 characters long",
+        ));
+    }
+    ConfigMining::update_sensitive_field(
+        ConfigMiningContent::set_lan_coordination,
+        lan_coordination,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    MiningCoordinator::spawn(app_handle).await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_lan_coordination took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_monero_address(
+    monero_address: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let timer = Instant::now();
+    ConfigWallet::update_field_requires_restart(
+        ConfigWalletContent::set_user_monero_address,
+        monero_address,
+        vec![SetupPhase::Mining],
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_monero_address took too long: {:?}", timer.elapsed());
     }
     Ok(())
 }
@@ -1290,8 +1958,9 @@ pub async fn set_monerod_config(
 #[tauri::command]
 pub async fn set_p2pool_enabled(
     p2pool_enabled: bool,
+    state: tauri::State<'_, UniverseAppState>,
     app_handle: tauri::AppHandle,
-) -> Result<(), InvokeError> {
+) -> Result<P2poolModeStatus, InvokeError> {
     let timer = Instant::now();
     ConfigCore::update_field_requires_restart(
         ConfigCoreContent::set_is_p2pool_enabled,
@@ -1308,7 +1977,68 @@ pub async fn set_p2pool_enabled(
     if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
         warn!(target: LOG_TARGET, "set_p2pool_enabled took too long: {:?}", timer.elapsed());
     }
-    Ok(())
+    Ok(P2poolModeStatus::current(&state).await)
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct P2poolModeStatus {
+    pub is_enabled: bool,
+    pub is_running: bool,
+    pub squad_override: Option<String>,
+    pub stats: Option<P2poolStats>,
+}
+
+impl P2poolModeStatus {
+    async fn current(state: &UniverseAppState) -> Self {
+        Self {
+            is_enabled: *ConfigCore::content().await.is_p2pool_enabled(),
+            is_running: state.p2pool_manager.is_running().await,
+            squad_override: ConfigMining::content().await.squad_override().clone(),
+            stats: state.p2pool_latest_status.borrow().clone(),
+        }
+    }
+}
+
+/// `None` or an empty name lets p2pool pick the squad itself
+#[tauri::command]
+pub async fn set_p2pool_squad(
+    squad: Option<String>,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<P2poolModeStatus, InvokeError> {
+    let squad = squad
+        .map(|squad| squad.trim().to_string())
+        .filter(|squad| !squad.is_empty());
+    if let Some(squad) = &squad {
+        let is_valid = squad.len() <= 64
+            && squad
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !is_valid {
+            return Err(InvokeError::from(
+                "Squad names may only use letters, digits, '-' and '_' (max 64 characters)",
+            ));
+        }
+    }
+
+    // The squad is only passed to p2pool on start, so restarting is pointless while it is off
+    if *ConfigCore::content().await.is_p2pool_enabled() {
+        ConfigMining::update_field_requires_restart(
+            ConfigMiningContent::set_squad_override,
+            squad,
+            vec![SetupPhase::Mining],
+        )
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+        SetupManager::get_instance()
+            .restart_phases_from_queue(app_handle)
+            .await;
+    } else {
+        ConfigMining::update_field(ConfigMiningContent::set_squad_override, squad)
+            .await
+            .map_err(InvokeError::from_anyhow)?;
+    }
+    Ok(P2poolModeStatus::current(&state).await)
 }
 
 #[tauri::command]
@@ -1356,6 +2086,41 @@ pub async fn set_should_auto_launch(should_auto_launch: bool) -> Result<(), Invo
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_timezone(timezone: Option<String>) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    AppTimezone::from_config_value(timezone.as_deref()).map_err(InvokeError::from_anyhow)?;
+    ConfigCore::update_field(ConfigCoreContent::set_timezone, timezone)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_timezone took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_auto_launch_options(
+    auto_launch_options: AutoLaunchOptions,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let timer = Instant::now();
+    ConfigCore::update_field(
+        ConfigCoreContent::set_auto_launch_options,
+        auto_launch_options,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_auto_launch_options took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_tor_config(
     config: TorConfig,
@@ -1363,6 +2128,7 @@ pub async fn set_tor_config(
     state: tauri::State<'_, UniverseAppState>,
     app_handle: tauri::AppHandle,
 ) -> Result<TorConfig, String> {
+    AccessControl::require_owner().await?;
     let timer = Instant::now();
     info!(target: LOG_TARGET, "[set_tor_config] called with config: {:?}", config);
     let tor_config = state
@@ -1390,7 +2156,14 @@ pub async fn set_tor_config(
 }
 
 #[tauri::command]
-pub async fn set_use_tor(use_tor: bool, app_handle: tauri::AppHandle) -> Result<(), InvokeError> {
+pub async fn set_use_tor(
+    use_tor: bool,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
     let timer = Instant::now();
     ConfigCore::update_field_requires_restart(
         ConfigCoreContent::set_use_tor,
@@ -1404,6 +2177,14 @@ pub async fn set_use_tor(use_tor: bool, app_handle: tauri::AppHandle) -> Result<
         .restart_phases_from_queue(app_handle.clone())
         .await;
 
+    // The node phase starts Tor when it is enabled but nothing stops it when it is disabled
+    if !use_tor && state.tor_manager.is_running().await {
+        info!(target: LOG_TARGET, "[set_use_tor] Stopping Tor");
+        if let Err(e) = state.tor_manager.stop().await {
+            warn!(target: LOG_TARGET, "[set_use_tor] Could not stop Tor: {:?}", e);
+        }
+    }
+
     let config_dir = app_handle
         .path()
         .app_config_dir()
@@ -1444,6 +2225,9 @@ pub async fn set_airdrop_tokens(
     airdrop_tokens: Option<AirdropTokens>,
     app_handle: tauri::AppHandle,
 ) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
     let old_id = ConfigCore::content()
         .await
         .airdrop_tokens()
@@ -1457,12 +2241,13 @@ pub async fn set_airdrop_tokens(
 
     let user_id_changed = old_id != new_id;
 
-    ConfigCore::update_field(ConfigCoreContent::set_airdrop_tokens, airdrop_tokens)
+    AirdropTokensStore::save(airdrop_tokens)
         .await
         .map_err(InvokeError::from_anyhow)?;
 
     info!(target: LOG_TARGET, "New Airdrop tokens saved, user id changed:{:?}", user_id_changed);
     if user_id_changed {
+        AirdropActivity::reset().await;
         // If the user id changed, we need to restart the mining phases to ensure that the new telemetry_id ( unique_string value )is used
         SetupManager::get_instance()
             .add_phases_to_restart_queue(vec![SetupPhase::Mining])
@@ -1480,6 +2265,12 @@ pub async fn start_cpu_mining(
     state: tauri::State<'_, UniverseAppState>,
     app: tauri::AppHandle,
 ) -> Result<(), String> {
+    EmergencyStop::ensure_inactive()?;
+    if DemoMode::is_enabled() {
+        DemoMode::set_cpu_mining(true).await;
+        return Ok(());
+    }
+
     let timer = Instant::now();
     let _lock = state.cpu_miner_stop_start_mutex.lock().await;
     let mut timestamp_lock = state.cpu_miner_timestamp_mutex.lock().await;
@@ -1543,10 +2334,35 @@ pub async fn start_cpu_mining(
 pub async fn start_gpu_mining(
     state: tauri::State<'_, UniverseAppState>,
     app: tauri::AppHandle,
+    device_indices: Option<Vec<u32>>,
 ) -> Result<(), String> {
+    EmergencyStop::ensure_inactive()?;
+    if DemoMode::is_enabled() {
+        DemoMode::set_gpu_mining(true).await;
+        return Ok(());
+    }
+    let engine = ConfigMining::content().await.gpu_engine().clone();
+    GpuDriverCompat::current()
+        .ensure_compatible(&engine)
+        .await?;
+
     let timer = Instant::now();
     let _lock = state.gpu_miner_stop_start_mutex.lock().await;
 
+    if let Some(device_indices) = device_indices {
+        let mut gpu_miner = state.gpu_miner.write().await;
+        if gpu_miner.is_running().await {
+            return Err(
+                "GPU miner is already running, stop it before selecting devices".to_string(),
+            );
+        }
+        let config_dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+        gpu_miner
+            .select_devices(config_dir, &device_indices)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
     let gpu_mining_enabled = *ConfigMining::content().await.gpu_mining_enabled();
     let mode = *ConfigMining::content().await.mode();
     let custom_gpu_usage = ConfigMining::content().await.custom_max_gpu_usage().clone();
@@ -1633,6 +2449,11 @@ pub async fn start_gpu_mining(
 
 #[tauri::command]
 pub async fn stop_cpu_mining(state: tauri::State<'_, UniverseAppState>) -> Result<(), String> {
+    if DemoMode::is_enabled() {
+        DemoMode::set_cpu_mining(false).await;
+        return Ok(());
+    }
+
     let _lock = state.cpu_miner_stop_start_mutex.lock().await;
     let timer = Instant::now();
     state
@@ -1664,8 +2485,53 @@ pub async fn stop_cpu_mining(state: tauri::State<'_, UniverseAppState>) -> Resul
 
     Ok(())
 }
+/// Only the CPU miner can be paused, glytex has no api to pause hashing
+#[tauri::command]
+pub async fn pause_mining(state: tauri::State<'_, UniverseAppState>) -> Result<(), String> {
+    let _lock = state.cpu_miner_stop_start_mutex.lock().await;
+    let timer = Instant::now();
+    state
+        .cpu_miner
+        .read()
+        .await
+        .pause()
+        .await
+        .map_err(|e| e.to_string())?;
+    info!(target: LOG_TARGET, "cpu miner paused");
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "pause_mining took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn resume_mining(state: tauri::State<'_, UniverseAppState>) -> Result<(), String> {
+    EmergencyStop::ensure_inactive()?;
+    let _lock = state.cpu_miner_stop_start_mutex.lock().await;
+    let timer = Instant::now();
+    state
+        .cpu_miner
+        .read()
+        .await
+        .resume()
+        .await
+        .map_err(|e| e.to_string())?;
+    info!(target: LOG_TARGET, "cpu miner resumed");
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "resume_mining took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn stop_gpu_mining(state: tauri::State<'_, UniverseAppState>) -> Result<(), String> {
+    if DemoMode::is_enabled() {
+        DemoMode::set_gpu_mining(false).await;
+        return Ok(());
+    }
+
     let _lock = state.gpu_miner_stop_start_mutex.lock().await;
     let timer = Instant::now();
 
@@ -1886,6 +2752,75 @@ pub async fn set_selected_engine(
     Ok(())
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct GpuEngineSwitch {
+    pub engine: EngineType,
+    pub available_engines: Vec<EngineType>,
+    /// Hash rate once the restarted miner reports one, `None` if it was not mining
+    pub hash_rate: Option<f64>,
+}
+
+/// Switches the GPU engine in one go, restarting the miner if it was running and waiting
+/// for the first hash rate from the new engine.
+#[tauri::command]
+pub async fn set_gpu_engine(
+    engine: String,
+    state: tauri::State<'_, UniverseAppState>,
+    app: tauri::AppHandle,
+) -> Result<GpuEngineSwitch, InvokeError> {
+    let engine_type = EngineType::from_string(&engine).map_err(InvokeError::from_anyhow)?;
+    let config_dir = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| InvokeError::from(e.to_string()))?;
+    let available_engines = state
+        .gpu_miner
+        .read()
+        .await
+        .get_available_gpu_engines(config_dir)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    if !available_engines.contains(&engine_type) {
+        return Err(InvokeError::from(format!(
+            "{} is not available on this machine, available engines: {:?}",
+            engine_type, available_engines
+        )));
+    }
+
+    let was_mining = state.gpu_miner.read().await.is_running().await;
+    if was_mining {
+        stop_gpu_mining(state.clone())
+            .await
+            .map_err(InvokeError::from)?;
+    }
+    set_selected_engine(&engine, state.clone(), app.clone()).await?;
+    if !was_mining {
+        return Ok(GpuEngineSwitch {
+            engine: engine_type,
+            available_engines,
+            hash_rate: None,
+        });
+    }
+
+    start_gpu_mining(state.clone(), app, None)
+        .await
+        .map_err(InvokeError::from)?;
+    let mut gpu_status_rx = (*state.gpu_latest_status).clone();
+    let hash_rate = tokio::time::timeout(
+        GPU_ENGINE_BENCHMARK_TIMEOUT,
+        gpu_status_rx.wait_for(|status| status.hash_rate > 0.0),
+    )
+    .await
+    .ok()
+    .and_then(Result::ok)
+    .map(|status| status.hash_rate);
+    Ok(GpuEngineSwitch {
+        engine: engine_type,
+        available_engines,
+        hash_rate,
+    })
+}
+
 #[tauri::command]
 pub async fn websocket_connect(
     _: tauri::AppHandle,
@@ -1902,6 +2837,11 @@ pub async fn websocket_connect(
         return Ok(());
     }
 
+    if !*ConfigCore::content().await.allow_airdrop_participation() {
+        info!(target: LOG_TARGET, "websocket_connect skipped as airdrop participation is disabled");
+        return Ok(());
+    }
+
     let mut websocket_manger_guard = state.websocket_manager.write().await;
 
     if !websocket_manger_guard.is_websocket_manager_ready() {
@@ -1940,31 +2880,210 @@ pub async fn reconnect(app_handle: tauri::AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Sends are the one operation where a blind retry costs the user money, so callers can
+/// pass an `idempotency_key` to get the original result back instead of sending twice
 #[tauri::command]
 pub async fn send_one_sided_to_stealth_address(
     state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
     amount: String,
     destination: String,
     payment_id: Option<String>,
-) -> Result<(), String> {
-    let timer = Instant::now();
-    info!(target: LOG_TARGET, "[send_one_sided_to_stealth_address] called with args: (amount: {:?}, destination: {:?}, payment_id: {:?})", amount, destination, payment_id);
-    let state_clone = state.clone();
-    let mut spend_wallet_manager = state_clone.spend_wallet_manager.write().await;
-    spend_wallet_manager
-        .send_one_sided_to_stealth_address(amount, destination, payment_id, state.clone())
+    sweep: Option<bool>,
+    idempotency_key: Option<String>,
+) -> Result<(), SpendWalletError> {
+    EmergencyStop::ensure_inactive().map_err(SpendWalletError::OperationsHalted)?;
+    let args = (
+        amount.clone(),
+        destination.clone(),
+        payment_id.clone(),
+        sweep,
+    );
+    if AccessControl::role().await == Role::Owner {
+        return SEND_IDEMPOTENCY_CACHE
+            .run(idempotency_key, &args, || {
+                send_one_sided(state, amount, destination, payment_id, sweep)
+            })
+            .await?;
+    }
+
+    // Guests can only spend from an allowance granted by the owner, and a sweep has no
+    // amount to check against it up front
+    if sweep.unwrap_or(false) {
+        return Err(SpendWalletError::PermissionDenied(
+            "Sweeping the wallet requires the admin PIN".to_string(),
+        ));
+    }
+    let allowance_amount = parse_send_amount(&amount)?;
+    let review_minutes = *ConfigWallet::content().await.outbound_review_minutes();
+    SEND_IDEMPOTENCY_CACHE
+        .run(idempotency_key, &args, || async move {
+            if review_minutes > 0 {
+                // Checked now so the guest hears about a bad send straight away rather
+                // than once the review window is over
+                check_send_preconditions(&state, allowance_amount, &destination).await?;
+                SpendAllowances::consume(allowance_amount).await?;
+                queue_outbound_send(
+                    app_handle,
+                    allowance_amount,
+                    destination,
+                    payment_id,
+                    Duration::from_secs(review_minutes * 60),
+                )
+                .await;
+                return Ok(());
+            }
+            SpendAllowances::consume(allowance_amount).await?;
+            let result = send_one_sided(state, amount, destination, payment_id, sweep).await;
+            if result.is_err() {
+                SpendAllowances::refund(allowance_amount).await;
+            }
+            result
+        })
+        .await?
+}
+
+/// Holds a guest send for the review window and broadcasts it afterwards unless it was
+/// cancelled. The allowance is already consumed and is refunded if the send never happens.
+async fn queue_outbound_send(
+    app_handle: tauri::AppHandle,
+    amount: MicroMinotari,
+    destination: String,
+    payment_id: Option<String>,
+    review_window: Duration,
+) {
+    let pending = OutboundQueue::enqueue(amount, destination, payment_id, review_window).await;
+    let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+    TasksTrackers::current()
+        .common
+        .get_task_tracker()
         .await
-        .map_err(|e| e.to_string())?;
+        .spawn(async move {
+            tokio::select! {
+                _ = shutdown_signal.wait() => return,
+                _ = tokio::time::sleep(review_window) => {}
+            }
+            let Some(pending) = OutboundQueue::take_for_broadcast(&pending.id).await else {
+                return;
+            };
+            let result = match EmergencyStop::ensure_inactive() {
+                Ok(()) => {
+                    send_one_sided(
+                        app_handle.state::<UniverseAppState>(),
+                        format_send_amount(pending.amount),
+                        pending.destination.clone(),
+                        pending.payment_id.clone(),
+                        None,
+                    )
+                    .await
+                }
+                Err(e) => Err(SpendWalletError::OperationsHalted(e)),
+            };
+            if let Err(e) = result {
+                SpendAllowances::refund(pending.amount).await;
+                OutboundQueue::report_failure(pending, e.to_string()).await;
+            }
+        });
+}
 
-    let balance = state.wallet_manager.get_balance().await;
-    if let Ok(balance) = balance {
-        EventsEmitter::emit_wallet_balance_update(balance).await;
+/// Rejects sends the wallet would refuse anyway. The balance check is skipped when the
+/// wallet cannot report one, the send itself fails in that case.
+async fn check_send_preconditions(
+    state: &tauri::State<'_, UniverseAppState>,
+    amount: MicroMinotari,
+    destination: &str,
+) -> Result<(), SpendWalletError> {
+    if normalize_tari_address(destination).is_none() {
+        return Err(SpendWalletError::InvalidAddress(destination.to_string()));
     }
-
-    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
-        warn!(target: LOG_TARGET, "send_one_sided_to_stealth_address took too long: {:?}", timer.elapsed());
+    if DemoMode::is_enabled() {
+        let balance = DemoMode::balance().await;
+        let fee = estimate_send_fee(&[balance.available_balance], Some(amount));
+        return check_sufficient_funds(&balance, amount, fee);
+    }
+    let balance = state.wallet_manager.get_balance().await;
+    let unspent_amounts = state.wallet_manager.get_unspent_amounts().await;
+    match (balance, unspent_amounts) {
+        (Ok(balance), Ok(unspent_amounts)) => check_sufficient_funds(
+            &balance,
+            amount,
+            estimate_send_fee(&unspent_amounts, Some(amount)),
+        ),
+        (Err(e), _) | (_, Err(e)) => {
+            warn!(target: LOG_TARGET, "Could not check balance before sending: {:?}", e);
+            Ok(())
+        }
     }
-    Ok(())
+}
+
+async fn send_one_sided(
+    state: tauri::State<'_, UniverseAppState>,
+    amount: String,
+    destination: String,
+    payment_id: Option<String>,
+    sweep: Option<bool>,
+) -> Result<(), SpendWalletError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET, "[send_one_sided_to_stealth_address] called with args: (amount: {:?}, destination: {:?}, payment_id: {:?})", amount, destination, payment_id);
+    if DemoMode::is_enabled() {
+        return DemoMode::send(&amount, destination, payment_id, sweep.unwrap_or(false)).await;
+    }
+    let amount = if sweep.unwrap_or(false) {
+        // Sweeping needs the exact balance and outputs, so it cannot go ahead without them
+        let balance = state
+            .wallet_manager
+            .get_balance()
+            .await
+            .map_err(|e| SpendWalletError::WalletUnavailable(e.to_string()))?;
+        let unspent_amounts = state
+            .wallet_manager
+            .get_unspent_amounts()
+            .await
+            .map_err(|e| SpendWalletError::WalletUnavailable(e.to_string()))?;
+        let swept_amount = sweep_amount(&balance, estimate_send_fee(&unspent_amounts, None))?;
+        info!(target: LOG_TARGET, "[send_one_sided_to_stealth_address] sweeping {} to {}", swept_amount, destination);
+        format_send_amount(swept_amount)
+    } else {
+        check_send_preconditions(&state, parse_send_amount(&amount)?, &destination).await?;
+        amount
+    };
+
+    let state_clone = state.clone();
+    let mut spend_wallet_manager = state_clone.spend_wallet_manager.write().await;
+    spend_wallet_manager
+        .send_one_sided_to_stealth_address(
+            amount.clone(),
+            destination.clone(),
+            payment_id,
+            state.clone(),
+        )
+        .await?;
+
+    let balance = state.wallet_manager.get_balance().await;
+    if let Ok(balance) = balance {
+        EventsEmitter::emit_wallet_balance_update(balance).await;
+    }
+
+    let config_wallet = ConfigWallet::content().await;
+    if let Some(watched_address) = normalize_tari_address(&destination)
+        .filter(|address| config_wallet.watched_addresses().contains(address))
+    {
+        let label = config_wallet
+            .address_labels()
+            .get(&watched_address)
+            .cloned();
+        EventsEmitter::emit_watched_address_received(watched_address, label, amount).await;
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "send_one_sided_to_stealth_address took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_websocket_stats() -> Result<WebsocketStatsSnapshot, InvokeError> {
+    Ok(WebsocketStats::current().snapshot().await)
 }
 
 #[tauri::command]
@@ -2048,6 +3167,9 @@ pub async fn set_node_type(
     app_handle: tauri::AppHandle,
     state: tauri::State<'_, UniverseAppState>,
 ) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
     // map LocalAfterRemote or unknown value to Local
     if node_type != NodeType::Local
         && node_type != NodeType::RemoteUntilLocal
@@ -2110,60 +3232,225 @@ pub async fn set_warmup_seen(warmup_seen: bool) -> Result<(), String> {
 
 #[tauri::command]
 pub async fn launch_builtin_tapplet() -> Result<ActiveTapplet, String> {
-    let tapplet_resolver = TappletResolver::current().read().await;
-
-    let tapp_dest_dir = tapplet_resolver
-        .resolve_path_to_tapplet_files(Tapplets::Bridge)
+    let status = TappletRegistry::launch(Tapplets::Bridge)
         .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "❌ Error handling tapplet start: {:?}", e))
         .map_err(|e| e.to_string())?;
 
-    let handle_start =
-        tauri::async_runtime::spawn(async move { start_tapplet(tapp_dest_dir).await });
-
-    let (addr, _cancel_token) = match handle_start.await {
-        Ok(result) => result.map_err(|e| e.to_string())?,
-        Err(e) => {
-            error!(target: LOG_TARGET, "❌ Error handling tapplet start: {:?}", e);
-            return Err(e.to_string());
-        }
-    };
-
     Ok(ActiveTapplet {
         tapplet_id: 0,
         display_name: "Bridge-wXTM".to_string(),
-        source: format!("http://{}", addr),
-        version: "1.0.0".to_string(),
+        source: status.source.unwrap_or_default(),
+        version: status.version.unwrap_or_default(),
     })
 }
 
+#[tauri::command]
+pub async fn get_tapplets_status() -> Result<Vec<TappletStatus>, String> {
+    Ok(TappletRegistry::statuses().await)
+}
+
+#[tauri::command]
+pub async fn report_tapplet_crash(tapplet: String, reason: String) -> Result<(), String> {
+    let tapplet =
+        Tapplets::from_name(&tapplet).ok_or_else(|| format!("Unknown tapplet: {}", tapplet))?;
+    TappletRegistry::record_crash(tapplet, reason).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn reload_tapplet(tapplet: String) -> Result<TappletStatus, String> {
+    let tapplet =
+        Tapplets::from_name(&tapplet).ok_or_else(|| format!("Unknown tapplet: {}", tapplet))?;
+    TappletRegistry::reload(tapplet)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Reported by the frame hosting the tapplet when its page fails to load
+#[tauri::command]
+pub async fn report_tapplet_load_failure(source: String, error: String) -> Result<(), String> {
+    TappletRegistry::record_load_failure(&source, error).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_tapplet_storage_usage(
+    tapplet: String,
+    app_handle: tauri::AppHandle,
+) -> Result<TappletStorageUsage, String> {
+    let tapplet =
+        Tapplets::from_name(&tapplet).ok_or_else(|| format!("Unknown tapplet: {}", tapplet))?;
+    let base_dir = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| e.to_string())?;
+    let quota_bytes = *ConfigCore::content().await.tapplet_storage_quota_bytes();
+    tapplet_storage::usage(&base_dir, tapplet, quota_bytes).map_err(|e| e.to_string())
+}
+
+/// Tapplet storage is keyed by the tapplet serving the invoking frame, never by a name the
+/// caller passes in, so a tapplet can only reach its own data.
+async fn invoking_tapplet(request: &tauri::ipc::Request<'_>) -> Result<Tapplets, String> {
+    let origin = request
+        .headers()
+        .get(ORIGIN)
+        .and_then(|origin| origin.to_str().ok())
+        .unwrap_or_default();
+    TappletRegistry::tapplet_for_origin(origin)
+        .await
+        .ok_or_else(|| "Tapplet data can only be accessed from a running tapplet".to_string())
+}
+
+#[tauri::command]
+pub async fn clear_tapplet_data(
+    request: tauri::ipc::Request<'_>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    AccessControl::require_owner().await?;
+    let tapplet = invoking_tapplet(&request).await?;
+    let base_dir = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| e.to_string())?;
+    tapplet_storage::clear(&base_dir, tapplet).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn read_tapplet_data(
+    request: tauri::ipc::Request<'_>,
+    key: String,
+    app_handle: tauri::AppHandle,
+) -> Result<Option<String>, String> {
+    let tapplet = invoking_tapplet(&request).await?;
+    let base_dir = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| e.to_string())?;
+    let value = tapplet_storage::read_entry(&base_dir, tapplet, &key).map_err(|e| e.to_string())?;
+    Ok(value.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()))
+}
+
+#[tauri::command]
+pub async fn write_tapplet_data(
+    request: tauri::ipc::Request<'_>,
+    key: String,
+    value: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let tapplet = invoking_tapplet(&request).await?;
+    let base_dir = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| e.to_string())?;
+    let quota_bytes = *ConfigCore::content().await.tapplet_storage_quota_bytes();
+    tapplet_storage::write_entry(&base_dir, tapplet, &key, value.as_bytes(), quota_bytes)
+        .map_err(|e| e.to_string())
+}
+
+/// Stops all mining and blocks mining starts and outgoing transactions until
+/// `resume_operations` is called
+#[tauri::command]
+pub async fn emergency_stop(
+    reason: Option<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<EmergencyStopStatus, String> {
+    Ok(EmergencyStop::trigger(&app_handle, reason).await)
+}
+
+#[tauri::command]
+pub async fn resume_operations(confirm: bool) -> Result<EmergencyStopStatus, String> {
+    AccessControl::require_owner().await?;
+    if !EmergencyStop::is_active() {
+        return Ok(EmergencyStop::status().await);
+    }
+    if !confirm {
+        return Err("Resuming after an emergency stop must be explicitly confirmed".to_string());
+    }
+    Ok(EmergencyStop::resume().await)
+}
+
+#[tauri::command]
+pub async fn get_emergency_stop_status() -> Result<EmergencyStopStatus, String> {
+    Ok(EmergencyStop::status().await)
+}
+
+/// Installs a tapplet on demand, e.g. after the user agreed to it from a deep link
+#[tauri::command]
+pub async fn install_tapplet(tapplet: String, app: tauri::AppHandle) -> Result<(), String> {
+    AccessControl::require_owner().await?;
+    let tapplet =
+        Tapplets::from_name(&tapplet).ok_or_else(|| format!("Unknown tapplet: {}", tapplet))?;
+    TappletResolver::current()
+        .read()
+        .await
+        .update_tapplet(tapplet, ProgressTracker::new(app, None))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_tapplet_storage_quota(quota_bytes: u64) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    ConfigCore::update_field(
+        ConfigCoreContent::set_tapplet_storage_quota_bytes,
+        quota_bytes.min(MAX_TAPPLET_STORAGE_QUOTA_BYTES),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn get_tari_wallet_address(
     state: tauri::State<'_, UniverseAppState>,
 ) -> Result<String, String> {
+    if DemoMode::is_enabled() {
+        return Ok(DemoMode::tari_address().to_base58());
+    }
     let tari_address = state.tari_address.clone();
     let addr = tari_address.read().await.to_base58();
     Ok(addr)
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct WalletBalanceResponse {
+    initialization_state: InitializationState,
+    #[serde(flatten)]
+    balance: WalletBalance,
+}
+
 #[tauri::command]
 pub async fn get_tari_wallet_balance(
     state: tauri::State<'_, UniverseAppState>,
-) -> Result<WalletBalance, String> {
-    let balance = state
-        .wallet_state_watch_rx
-        .borrow()
-        .clone()
-        .and_then(|state| state.balance);
+) -> Result<WalletBalanceResponse, String> {
+    let balance = if DemoMode::is_enabled() {
+        Some(DemoMode::balance().await)
+    } else {
+        state
+            .wallet_state_watch_rx
+            .borrow()
+            .clone()
+            .and_then(|state| state.balance)
+    };
 
-    match balance {
-        Some(balance) => Ok(balance),
-        None => Ok(WalletBalance {
+    Ok(WalletBalanceResponse {
+        initialization_state: SetupManager::get_instance()
+            .get_initialization_state(&SetupPhase::Wallet),
+        balance: balance.unwrap_or(WalletBalance {
             available_balance: MicroMinotari(0),
             timelocked_balance: MicroMinotari(0),
             pending_incoming_balance: MicroMinotari(0),
             pending_outgoing_balance: MicroMinotari(0),
         }),
-    }
+    })
+}
+
+#[tauri::command]
+pub async fn get_setup_progress() -> Result<SetupProgress, String> {
+    Ok(SetupManager::get_instance().get_setup_progress().await)
 }
 
 #[tauri::command]
@@ -2219,3 +3506,524 @@ pub async fn refresh_wallet_history(
 
     Ok(())
 }
+
+#[tauri::command]
+pub async fn backup_wallet_database(
+    destination_dir: String,
+    passphrase: Option<String>,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let destination_dir = PathBuf::from(destination_dir);
+    if !destination_dir.is_dir() {
+        return Err(InvokeError::from(
+            "Backup destination must be an existing directory",
+        ));
+    }
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|_| InvokeError::from("Could not find wallet data dir"))?;
+
+    let wallet_address = state.tari_address.read().await.to_base58();
+
+    SetupManager::get_instance()
+        .shutdown_phases(app_handle.clone(), vec![SetupPhase::Wallet])
+        .await;
+    let result = wallet_backup::create_backup(
+        wallet_backup::wallet_data_dir(&base_path),
+        wallet_address,
+        &destination_dir,
+        passphrase,
+    )
+    .await;
+    SetupManager::get_instance()
+        .resume_phases(app_handle, vec![SetupPhase::Wallet])
+        .await;
+
+    let backup_file = result.map_err(InvokeError::from_anyhow)?;
+    if let Err(e) = wallet_backup::record_database_backup().await {
+        warn!(target: LOG_TARGET, "Could not record wallet database backup: {:?}", e);
+    }
+    Ok(backup_file.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub async fn restore_wallet_database(
+    backup_file: String,
+    passphrase: Option<String>,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let backup_file = PathBuf::from(backup_file);
+    if !backup_file.is_file() {
+        return Err(InvokeError::from("Backup file not found"));
+    }
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|_| InvokeError::from("Could not find wallet data dir"))?;
+
+    let wallet_address = state.tari_address.read().await.to_base58();
+
+    SetupManager::get_instance()
+        .shutdown_phases(app_handle.clone(), vec![SetupPhase::Wallet])
+        .await;
+    let result = wallet_backup::restore_backup(
+        &backup_file,
+        &wallet_backup::wallet_data_dir(&base_path),
+        &wallet_address,
+        passphrase,
+    )
+    .await;
+    SetupManager::get_instance()
+        .resume_phases(app_handle, vec![SetupPhase::Wallet])
+        .await;
+
+    result.map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn export_chain_snapshot(
+    destination_dir: String,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<ChainSnapshotInfo, InvokeError> {
+    let destination_dir = PathBuf::from(destination_dir);
+    if !destination_dir.is_dir() {
+        return Err(InvokeError::from(
+            "Snapshot destination must be an existing directory",
+        ));
+    }
+    if !state
+        .node_manager
+        .is_local()
+        .await
+        .map_err(InvokeError::from_anyhow)?
+    {
+        return Err(InvokeError::from("Chain snapshots require a local node"));
+    }
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|_| InvokeError::from("Could not find node data dir"))?;
+    let block_height = state.node_status_watch_rx.borrow().block_height;
+
+    export_chain_snapshot_with_node_paused(app_handle, base_path, destination_dir, block_height)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+async fn export_chain_snapshot_with_node_paused(
+    app_handle: tauri::AppHandle,
+    base_path: PathBuf,
+    destination_dir: PathBuf,
+    block_height: u64,
+) -> Result<ChainSnapshotInfo, anyhow::Error> {
+    SetupManager::get_instance()
+        .shutdown_phases(app_handle.clone(), vec![SetupPhase::Node])
+        .await;
+    let result = chain_snapshot::export_snapshot(&base_path, &destination_dir, block_height).await;
+    SetupManager::get_instance()
+        .resume_phases(app_handle, vec![SetupPhase::Node])
+        .await;
+    result
+}
+
+/// Same as `export_chain_snapshot` but returns a job id straight away. The job can't be
+/// cancelled because the node has to be resumed once the export finishes.
+#[tauri::command]
+pub async fn start_chain_snapshot_export_job(
+    destination_dir: String,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, InvokeError> {
+    let destination_dir = PathBuf::from(destination_dir);
+    if !destination_dir.is_dir() {
+        return Err(InvokeError::from(
+            "Snapshot destination must be an existing directory",
+        ));
+    }
+    if !state
+        .node_manager
+        .is_local()
+        .await
+        .map_err(InvokeError::from_anyhow)?
+    {
+        return Err(InvokeError::from("Chain snapshots require a local node"));
+    }
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|_| InvokeError::from("Could not find node data dir"))?;
+    let block_height = state.node_status_watch_rx.borrow().block_height;
+
+    Ok(JobManager::current()
+        .spawn(
+            "chain_snapshot_export",
+            false,
+            export_chain_snapshot_with_node_paused(
+                app_handle,
+                base_path,
+                destination_dir,
+                block_height,
+            ),
+        )
+        .await)
+}
+
+#[tauri::command]
+pub async fn start_orphan_chain_check_job(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<String, InvokeError> {
+    let node_manager = state.node_manager.clone();
+    Ok(JobManager::current()
+        .spawn("orphan_chain_check", true, async move {
+            node_manager.check_if_is_orphan_chain().await
+        })
+        .await)
+}
+
+#[tauri::command]
+pub async fn get_job_status(job_id: String) -> Result<JobStatus, InvokeError> {
+    JobManager::current()
+        .status(&job_id)
+        .await
+        .ok_or_else(|| InvokeError::from(format!("Unknown job: {}", job_id)))
+}
+
+#[tauri::command]
+pub async fn list_jobs(
+    cursor: Option<String>,
+    limit: Option<u32>,
+) -> Result<Page<JobStatus>, InvokeError> {
+    Page::from_all(JobManager::current().list().await, cursor.as_deref(), limit)
+        .map_err(InvokeError::from)
+}
+
+#[tauri::command]
+pub async fn cancel_job(job_id: String) -> Result<(), InvokeError> {
+    JobManager::current()
+        .cancel(&job_id)
+        .await
+        .map_err(InvokeError::from)
+}
+
+#[tauri::command]
+pub async fn import_chain_snapshot(
+    snapshot_file: String,
+    expected_checksum: Option<String>,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    let snapshot_file = PathBuf::from(snapshot_file);
+    if !snapshot_file.is_file() {
+        return Err(InvokeError::from("Snapshot file not found"));
+    }
+    if !state
+        .node_manager
+        .is_local()
+        .await
+        .map_err(InvokeError::from_anyhow)?
+    {
+        return Err(InvokeError::from("Chain snapshots require a local node"));
+    }
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|_| InvokeError::from("Could not find node data dir"))?;
+
+    SetupManager::get_instance()
+        .shutdown_phases(app_handle.clone(), vec![SetupPhase::Node])
+        .await;
+    let result =
+        chain_snapshot::import_snapshot(&base_path, &snapshot_file, expected_checksum).await;
+    SetupManager::get_instance()
+        .resume_phases(app_handle, vec![SetupPhase::Node])
+        .await;
+
+    result.map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn rescan_hardware(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<GpuDevice>, InvokeError> {
+    let timer = Instant::now();
+    if state.gpu_miner.read().await.is_running().await {
+        return Err(InvokeError::from(
+            "Stop gpu mining before rescanning hardware",
+        ));
+    }
+    let config_dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|_| InvokeError::from("Could not find config dir"))?;
+    let engine = ConfigMining::content().await.gpu_engine().clone();
+
+    let mut gpu_miner = state.gpu_miner.write().await;
+    let _unused = gpu_miner
+        .detect(config_dir.clone(), engine.clone(), true)
+        .await
+        .inspect_err(|e| warn!(target: LOG_TARGET, "Gpu rescan found no usable devices: {:?}", e));
+    let gpu_devices = gpu_miner
+        .get_gpu_devices()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    drop(gpu_miner);
+    GpuDriverCompat::current()
+        .refresh(&config_dir, &engine)
+        .await;
+
+    HardwareStatusMonitor::current()
+        .initialize()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "rescan_hardware took too long: {:?}", timer.elapsed());
+    }
+    Ok(gpu_devices)
+}
+
+#[tauri::command]
+pub async fn get_tuning_recommendations() -> Result<TuningRecommendations, InvokeError> {
+    Ok(MiningTuner::current().recommendations().await)
+}
+
+#[tauri::command]
+pub async fn get_wallet_connection_health(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<WalletConnectionHealth, InvokeError> {
+    Ok(state.wallet_manager.get_connection_health().await)
+}
+
+#[tauri::command]
+pub async fn get_access_role() -> Result<AccessStatus, String> {
+    Ok(AccessControl::status().await)
+}
+
+#[tauri::command]
+pub async fn set_admin_pin(new_pin: Option<String>) -> Result<AccessStatus, String> {
+    AccessControl::set_pin(new_pin).await
+}
+
+#[tauri::command]
+pub async fn unlock_admin(pin: String) -> Result<AccessStatus, String> {
+    AccessControl::unlock(&pin).await
+}
+
+#[tauri::command]
+pub async fn lock_admin() -> Result<AccessStatus, String> {
+    Ok(AccessControl::lock().await)
+}
+
+#[tauri::command]
+pub async fn grant_spend_allowance(
+    amount: String,
+    hours: u64,
+) -> Result<SpendAllowanceStatus, SpendWalletError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(SpendWalletError::PermissionDenied)?;
+    SpendAllowances::grant(&amount, hours).await
+}
+
+#[tauri::command]
+pub async fn revoke_spend_allowance() -> Result<(), String> {
+    AccessControl::require_owner().await?;
+    SpendAllowances::revoke().await;
+    OutboundQueue::cancel_all().await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_spend_allowance() -> Result<Option<SpendAllowanceStatus>, String> {
+    Ok(SpendAllowances::status().await)
+}
+
+#[tauri::command]
+pub async fn get_pending_outbound(
+    cursor: Option<String>,
+    limit: Option<u32>,
+) -> Result<Page<PendingOutbound>, String> {
+    Page::from_all(OutboundQueue::list().await, cursor.as_deref(), limit)
+}
+
+#[tauri::command]
+pub async fn cancel_pending_outbound(id: String) -> Result<(), String> {
+    let pending = OutboundQueue::cancel(&id)
+        .await
+        .ok_or_else(|| format!("No queued send with id {}", id))?;
+    SpendAllowances::refund(pending.amount).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_outbound_review_minutes(minutes: u64) -> Result<(), InvokeError> {
+    AccessControl::require_owner()
+        .await
+        .map_err(InvokeError::from)?;
+    if minutes > MAX_OUTBOUND_REVIEW_MINUTES {
+        return Err(InvokeError::from(format!(
+            "Review window cannot be longer than {} minutes",
+            MAX_OUTBOUND_REVIEW_MINUTES
+        )));
+    }
+    ConfigWallet::update_field(ConfigWalletContent::set_outbound_review_minutes, minutes)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_config_migrations() -> Result<Vec<MigrationReport>, String> {
+    Ok(migration_reports())
+}
+
+/// Dry run of the config migrations against the files on disk, nothing is written
+#[tauri::command]
+pub async fn get_pending_config_migrations() -> Result<Vec<PendingMigrationReport>, String> {
+    let reports = [
+        ConfigCore::pending_migrations_report(),
+        ConfigMining::pending_migrations_report(),
+        ConfigUI::pending_migrations_report(),
+        ConfigWallet::pending_migrations_report(),
+    ];
+    Ok(reports
+        .into_iter()
+        .filter_map(|report| {
+            report
+                .inspect_err(|e| {
+                    warn!(target: LOG_TARGET, "Could not read config for migration dry run: {:?}", e);
+                })
+                .ok()
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_runtime_paths(app_handle: tauri::AppHandle) -> Result<RuntimePaths, String> {
+    Ok(RuntimePaths::collect(&app_handle).await)
+}
+
+#[tauri::command]
+pub async fn restart_node(app_handle: tauri::AppHandle) -> Result<(), String> {
+    AccessControl::require_owner().await?;
+    info!(target: LOG_TARGET, "[restart_node] Restarting node and dependent phases");
+    let phases = vec![SetupPhase::Node, SetupPhase::Wallet, SetupPhase::Mining];
+    SetupManager::get_instance()
+        .shutdown_phases(app_handle.clone(), phases.clone())
+        .await;
+    SetupManager::get_instance()
+        .resume_phases(app_handle, phases)
+        .await;
+
+    Ok(())
+}
+
+/// Deletes the local node database and syncs the chain again from scratch
+#[tauri::command]
+pub async fn resync_node(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    AccessControl::require_owner().await?;
+    if !state
+        .node_manager
+        .is_local()
+        .await
+        .map_err(|e| e.to_string())?
+    {
+        return Err("Resync is only available for a local node".to_string());
+    }
+
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|_| "Could not find node data dir".to_string())?;
+
+    info!(target: LOG_TARGET, "[resync_node] Clearing node database and resyncing");
+    let phases = vec![SetupPhase::Node, SetupPhase::Wallet, SetupPhase::Mining];
+    SetupManager::get_instance()
+        .shutdown_phases(app_handle.clone(), phases.clone())
+        .await;
+    let cleanup_result = state.node_manager.clean_data_folder(&base_path).await;
+    // Bring the phases back even if cleanup failed, so the node isn't left stopped
+    SetupManager::get_instance()
+        .resume_phases(app_handle, phases)
+        .await;
+
+    cleanup_result.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_gpu_driver_compatibility() -> Result<GpuDriverCompatibility, String> {
+    let engine = ConfigMining::content().await.gpu_engine().clone();
+    Ok(GpuDriverCompat::current().status(&engine).await)
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TorConnectionStatus {
+    pub use_tor: bool,
+    pub is_running: bool,
+    /// Bootstrap and circuit state as last reported by the Tor control port
+    pub status: TorStatus,
+}
+
+#[tauri::command]
+pub async fn get_tor_status(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<TorConnectionStatus, String> {
+    Ok(TorConnectionStatus {
+        use_tor: *ConfigCore::content().await.use_tor(),
+        is_running: state.tor_manager.is_running().await,
+        status: state.tor_manager.get_status(),
+    })
+}
+
+#[tauri::command]
+pub async fn get_power_assertion_status() -> Result<PowerAssertionStatus, String> {
+    Ok(PowerAssertions::current().status().await)
+}
+
+#[tauri::command]
+pub async fn set_prevent_sleep_while_mining(
+    enabled: bool,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<PowerAssertionStatus, InvokeError> {
+    ConfigMining::update_field(ConfigMiningContent::set_prevent_sleep_while_mining, enabled)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    let is_mining = state.cpu_miner_status_watch_rx.borrow().is_mining
+        || state.gpu_latest_status.borrow().is_mining;
+    PowerAssertions::current().update(is_mining).await;
+    Ok(PowerAssertions::current().status().await)
+}
+
+#[tauri::command]
+pub async fn set_disable_app_nap(
+    disable_app_nap: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<PowerAssertionStatus, InvokeError> {
+    ConfigMining::update_field(ConfigMiningContent::set_disable_app_nap, disable_app_nap)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    PowerAssertions::apply_app_nap_setting(&app_handle).await;
+    Ok(PowerAssertions::current().status().await)
+}
+
+#[tauri::command]
+pub async fn get_wallet_backup_status() -> Result<WalletBackupStatus, String> {
+    Ok(wallet_backup::backup_status().await)
+}
@@ -21,15 +21,19 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use getset::{Getters, Setters};
+use log::warn;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::{sync::LazyLock, time::SystemTime};
 use tari_common::configuration::Network;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 use tokio::sync::RwLock;
 
+use crate::airdrop::AirdropTokensStore;
 use crate::events_emitter::EventsEmitter;
 use crate::node::node_manager::NodeType;
+use crate::tapplets::tapplet_storage::DEFAULT_TAPPLET_STORAGE_QUOTA_BYTES;
+use crate::utils::doh_resolver::default_doh_providers;
 use crate::{ab_test_selector::ABTestSelector, internal_wallet::generate_password};
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
@@ -40,6 +44,42 @@ pub struct AirdropTokens {
     pub refresh_token: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct AutoLaunchOptions {
+    pub start_hidden: bool,
+    pub start_node_only: bool,
+    pub delay_minutes: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct DnsOverHttps {
+    pub enabled: bool,
+    /// Queried in order, the system resolver is used when none of them answer
+    pub providers: Vec<String>,
+}
+
+impl Default for DnsOverHttps {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            providers: default_doh_providers(),
+        }
+    }
+}
+
+/// Snapshots are only accepted when signed by `trusted_public_key`, a base64 ed25519 key
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct FastSync {
+    pub enabled: bool,
+    pub mirrors: Vec<String>,
+    pub trusted_public_key: String,
+}
+
+static LOG_TARGET: &str = "tari::universe::config_core";
+
 static INSTANCE: LazyLock<RwLock<ConfigCore>> = LazyLock::new(|| RwLock::new(ConfigCore::new()));
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Serialize, Deserialize, Clone)]
@@ -53,6 +93,7 @@ pub struct ConfigCoreContent {
     is_p2pool_enabled: bool,
     use_tor: bool,
     allow_telemetry: bool,
+    allow_airdrop_participation: bool,
     allow_notifications: bool,
     last_binaries_update_timestamp: SystemTime,
     anon_id: String,
@@ -68,6 +109,15 @@ pub struct ConfigCoreContent {
     remote_base_node_address: String,
     node_type: NodeType,
     universal_miner_initialized_exchange_id: Option<String>,
+    timezone: Option<String>,
+    auto_launch_options: AutoLaunchOptions,
+    allow_exchange_swaps: bool,
+    clipboard_protection_enabled: bool,
+    dns_over_https: DnsOverHttps,
+    fast_sync: FastSync,
+    tapplet_storage_quota_bytes: u64,
+    /// Whether an admin PIN is set. The hash itself lives in the credential store
+    has_admin_pin: bool,
 }
 
 fn default_monero_nodes() -> Vec<String> {
@@ -107,6 +157,7 @@ impl Default for ConfigCoreContent {
             is_p2pool_enabled: true,
             use_tor: true,
             allow_telemetry: true,
+            allow_airdrop_participation: true,
             allow_notifications: false,
             last_binaries_update_timestamp: SystemTime::now(),
             anon_id,
@@ -122,6 +173,14 @@ impl Default for ConfigCoreContent {
             remote_base_node_address,
             node_type: NodeType::Local,
             universal_miner_initialized_exchange_id: None,
+            timezone: None,
+            auto_launch_options: AutoLaunchOptions::default(),
+            allow_exchange_swaps: false,
+            clipboard_protection_enabled: false,
+            dns_over_https: DnsOverHttps::default(),
+            fast_sync: FastSync::default(),
+            tapplet_storage_quota_bytes: DEFAULT_TAPPLET_STORAGE_QUOTA_BYTES,
+            has_admin_pin: false,
         }
     }
 }
@@ -134,10 +193,18 @@ pub struct ConfigCore {
 
 impl ConfigCore {
     pub async fn initialize(app_handle: AppHandle) {
-        let mut config = Self::current().write().await;
-        config.load_app_handle(app_handle.clone()).await;
+        Self::current()
+            .write()
+            .await
+            .load_app_handle(app_handle.clone())
+            .await;
+
+        match app_handle.path().app_config_dir() {
+            Ok(config_dir) => AirdropTokensStore::initialize(config_dir).await,
+            Err(e) => warn!(target: LOG_TARGET, "Could not open the airdrop tokens store: {:?}", e),
+        }
 
-        EventsEmitter::emit_core_config_loaded(config.content.clone()).await;
+        EventsEmitter::emit_core_config_loaded(Self::content().await).await;
     }
 }
 
@@ -155,6 +222,13 @@ impl ConfigImpl for ConfigCore {
         }
     }
 
+    fn _prepare_for_save(mut config_content: Self::Config) -> Self::Config {
+        if AirdropTokensStore::are_tokens_in_store() {
+            config_content.airdrop_tokens = None;
+        }
+        config_content
+    }
+
     async fn _get_app_handle(&self) -> Option<AppHandle> {
         self.app_handle.read().await.clone()
     }
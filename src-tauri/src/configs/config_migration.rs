@@ -0,0 +1,206 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::{LazyLock, Mutex};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Stored next to the config fields, configs written before versioning started are version 0
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+static MIGRATION_LOG: LazyLock<Mutex<Vec<MigrationReport>>> = LazyLock::new(|| Mutex::new(vec![]));
+
+/// One step in a config's schema history. It works on the raw JSON so it can still read
+/// fields the current content struct no longer has.
+pub struct ConfigMigration {
+    /// Schema version of the config once this step has run
+    pub version: u32,
+    pub description: &'static str,
+    pub migrate: fn(&mut Map<String, Value>),
+}
+
+/// What loading a stored config would change, worked out without writing it
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingMigrationReport {
+    pub config: String,
+    pub stored_version: u32,
+    pub latest_version: u32,
+    pub pending: Vec<String>,
+    /// Written by a newer app, loading it migrates nothing and keeps its unknown fields
+    pub is_from_newer_app: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationReport {
+    pub config: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub applied: Vec<String>,
+    pub backup_path: Option<String>,
+}
+
+pub fn stored_schema_version(value: &Value) -> u32 {
+    value
+        .get(SCHEMA_VERSION_KEY)
+        .and_then(Value::as_u64)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+pub fn latest_schema_version(migrations: &[ConfigMigration]) -> u32 {
+    migrations.iter().map(|m| m.version).max().unwrap_or(0)
+}
+
+/// Steps that still have to run for a stored config, without touching it
+pub fn pending_migrations<'a>(
+    value: &Value,
+    migrations: &'a [ConfigMigration],
+) -> Vec<&'a ConfigMigration> {
+    let stored_version = stored_schema_version(value);
+    let mut pending: Vec<_> = migrations
+        .iter()
+        .filter(|m| m.version > stored_version)
+        .collect();
+    pending.sort_by_key(|m| m.version);
+    pending
+}
+
+/// Runs the pending steps in order and stamps the new schema version. Configs that are not
+/// JSON objects are left alone so deserializing them fails the usual way.
+pub fn apply_migrations(value: &mut Value, migrations: &[ConfigMigration]) -> Vec<String> {
+    let pending = pending_migrations(value, migrations);
+    let Some(fields) = value.as_object_mut() else {
+        return vec![];
+    };
+    let mut applied = vec![];
+    for migration in pending {
+        (migration.migrate)(fields);
+        fields.insert(
+            SCHEMA_VERSION_KEY.to_string(),
+            Value::from(migration.version),
+        );
+        applied.push(migration.description.to_string());
+    }
+    applied
+}
+
+/// Stamps the schema version on a config about to be written. A config last written by a
+/// newer app keeps its version and the fields this version does not know about, so running
+/// an older build does not throw away settings the newer one relies on.
+pub fn stamp_schema_version(mut value: Value, stored: Option<Value>, latest_version: u32) -> Value {
+    let Some(fields) = value.as_object_mut() else {
+        return value;
+    };
+    match stored.filter(|stored| stored_schema_version(stored) > latest_version) {
+        Some(Value::Object(mut stored_fields)) => {
+            stored_fields.extend(std::mem::take(fields));
+            Value::Object(stored_fields)
+        }
+        _ => {
+            fields.insert(SCHEMA_VERSION_KEY.to_string(), latest_version.into());
+            value
+        }
+    }
+}
+
+pub fn record_migration(report: MigrationReport) {
+    if let Ok(mut log) = MIGRATION_LOG.lock() {
+        log.push(report);
+    }
+}
+
+/// Migrations run while loading configs in this session
+pub fn migration_reports() -> Vec<MigrationReport> {
+    MIGRATION_LOG
+        .lock()
+        .map(|log| log.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn migrations() -> Vec<ConfigMigration> {
+        vec![
+            ConfigMigration {
+                version: 1,
+                description: "rename name to title",
+                migrate: |fields| {
+                    if let Some(name) = fields.remove("name") {
+                        fields.insert("title".to_string(), name);
+                    }
+                },
+            },
+            ConfigMigration {
+                version: 2,
+                description: "drop legacy flag",
+                migrate: |fields| {
+                    fields.remove("legacy");
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn unversioned_config_runs_every_step() {
+        let mut value = json!({ "name": "miner", "legacy": true });
+        let applied = apply_migrations(&mut value, &migrations());
+
+        assert_eq!(applied.len(), 2);
+        assert_eq!(value, json!({ "title": "miner", "schema_version": 2 }));
+    }
+
+    #[test]
+    fn only_newer_steps_run() {
+        let value = json!({ "title": "miner", "legacy": true, "schema_version": 1 });
+        let migrations = migrations();
+        let pending = pending_migrations(&value, &migrations);
+
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending.first().map(|m| m.version), Some(2));
+        assert_eq!(latest_schema_version(&migrations), 2);
+    }
+
+    #[test]
+    fn config_from_newer_app_is_untouched() {
+        let mut value = json!({ "title": "miner", "schema_version": 5 });
+        assert!(apply_migrations(&mut value, &migrations()).is_empty());
+        assert_eq!(stored_schema_version(&value), 5);
+    }
+
+    #[test]
+    fn saving_over_newer_config_keeps_unknown_fields() {
+        let stored = json!({ "title": "old", "added_later": true, "schema_version": 5 });
+        let saved = stamp_schema_version(json!({ "title": "miner" }), Some(stored), 2);
+        assert_eq!(
+            saved,
+            json!({ "title": "miner", "added_later": true, "schema_version": 5 })
+        );
+
+        let stored = json!({ "title": "old", "legacy": true, "schema_version": 1 });
+        let saved = stamp_schema_version(json!({ "title": "miner" }), Some(stored), 2);
+        assert_eq!(saved, json!({ "title": "miner", "schema_version": 2 }));
+    }
+}
@@ -20,16 +20,24 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::{events_emitter::EventsEmitter, gpu_miner::EngineType, UniverseAppState};
+use crate::{
+    auto_launcher::AutoLauncher, events_emitter::EventsEmitter, gpu_miner::EngineType,
+    mining_tuner::CpuTuning, UniverseAppState,
+};
 use std::{sync::LazyLock, time::SystemTime};
 
 use getset::{Getters, Setters};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tari_common::configuration::Network;
 use tauri::{AppHandle, Manager};
 use tokio::sync::RwLock;
 
-use super::trait_config::{ConfigContentImpl, ConfigImpl};
+use super::{
+    config_core::ConfigCore,
+    config_migration::ConfigMigration,
+    trait_config::{ConfigContentImpl, ConfigImpl},
+};
 
 static INSTANCE: LazyLock<RwLock<ConfigMining>> =
     LazyLock::new(|| RwLock::new(ConfigMining::new()));
@@ -58,6 +66,35 @@ pub struct GpuThreads {
     pub max_gpu_threads: u32,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MiningSchedule {
+    pub enabled: bool,
+    /// Local time in HH:MM format
+    pub start_time: String,
+    /// Local time in HH:MM format
+    pub end_time: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct LanCoordination {
+    pub enabled: bool,
+    /// Only instances announcing the same group take part in the election
+    pub group_name: String,
+    /// Key used to authenticate announcements. Instances with a different secret are ignored
+    pub shared_secret: String,
+}
+
+impl Default for LanCoordination {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            group_name: "home".to_string(),
+            shared_secret: String::new(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 #[serde(default)]
@@ -84,6 +121,11 @@ pub struct ConfigMiningContent {
     cpu_mining_pool_status_url: Option<String>,
     gpu_mining_pool_url: Option<String>,
     mining_time: u128,
+    mining_schedule: Option<MiningSchedule>,
+    lan_coordination: LanCoordination,
+    cpu_tuning: CpuTuning,
+    prevent_sleep_while_mining: bool,
+    disable_app_nap: bool,
 }
 
 impl Default for ConfigMiningContent {
@@ -108,6 +150,11 @@ impl Default for ConfigMiningContent {
             cpu_mining_pool_status_url: default_cpu_mining_pool_status_url(),
             gpu_mining_pool_url: None,
             mining_time: 0,
+            mining_schedule: None,
+            lan_coordination: LanCoordination::default(),
+            cpu_tuning: CpuTuning::default(),
+            prevent_sleep_while_mining: true,
+            disable_app_nap: true,
         }
     }
 }
@@ -152,7 +199,17 @@ impl ConfigMining {
         cpu_config.load_from_config_mining(config._get_content());
         drop(cpu_config);
 
-        EventsEmitter::emit_mining_config_loaded(config.content.clone()).await;
+        let mut content = config.content.clone();
+        if AutoLauncher::current().is_auto_launched()
+            && ConfigCore::content()
+                .await
+                .auto_launch_options()
+                .start_node_only
+        {
+            // Only for this session, the stored preference stays untouched
+            content.set_mine_on_app_start(false);
+        }
+        EventsEmitter::emit_mining_config_loaded(content).await;
     }
 }
 
@@ -182,6 +239,20 @@ impl ConfigImpl for ConfigMining {
         "config_mining".to_string()
     }
 
+    fn _migrations() -> Vec<ConfigMigration> {
+        vec![ConfigMigration {
+            version: 1,
+            description: "Clear zero CPU thread overrides so the detected default is used",
+            migrate: |fields| {
+                for key in ["eco_mode_cpu_threads", "ludicrous_mode_cpu_threads"] {
+                    if fields.get(key).and_then(Value::as_u64) == Some(0) {
+                        fields.insert(key.to_string(), Value::Null);
+                    }
+                }
+            },
+        }]
+    }
+
     fn _get_content(&self) -> &Self::Config {
         &self.content
     }
@@ -20,7 +20,7 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{sync::LazyLock, time::SystemTime};
+use std::{collections::HashMap, sync::LazyLock, time::SystemTime};
 
 use getset::{Getters, Setters};
 use log::error;
@@ -56,6 +56,24 @@ pub struct ConfigWalletContent {
     keyring_accessed: bool,
     #[getset(get = "pub", set = "pub")]
     wallet_migration_nonce: u64,
+    /// User notes keyed by transaction id
+    #[getset(get = "pub")]
+    tx_labels: HashMap<String, String>,
+    /// User notes keyed by base58 Tari address
+    #[getset(get = "pub")]
+    address_labels: HashMap<String, String>,
+    /// External base58 addresses, e.g. cold storage, whose received funds are tracked
+    #[getset(get = "pub")]
+    watched_addresses: Vec<String>,
+    /// Guest sends wait this long before broadcast so they can be cancelled, 0 disables it
+    #[getset(get = "pub", set = "pub")]
+    outbound_review_minutes: u64,
+    /// Unix time the seed words were last revealed so the user could write them down
+    #[getset(get = "pub", set = "pub")]
+    seed_words_revealed_at: Option<u64>,
+    /// Unix time of the last successful wallet database backup
+    #[getset(get = "pub", set = "pub")]
+    database_backed_up_at: Option<u64>,
 }
 
 impl Default for ConfigWalletContent {
@@ -67,6 +85,12 @@ impl Default for ConfigWalletContent {
             monero_address_is_generated: false,
             keyring_accessed: false,
             wallet_migration_nonce: 0,
+            tx_labels: HashMap::new(),
+            address_labels: HashMap::new(),
+            watched_addresses: vec![],
+            outbound_review_minutes: 0,
+            seed_words_revealed_at: None,
+            database_backed_up_at: None,
         }
     }
 }
@@ -87,6 +111,42 @@ impl ConfigWalletContent {
 
         self
     }
+
+    /// Setting an empty label removes it
+    pub fn set_tx_label(&mut self, (tx_id, label): (String, String)) -> &mut Self {
+        if label.trim().is_empty() {
+            self.tx_labels.remove(&tx_id);
+        } else {
+            self.tx_labels.insert(tx_id, label);
+        }
+
+        self
+    }
+
+    pub fn add_watched_address(&mut self, address: String) -> &mut Self {
+        if !self.watched_addresses.contains(&address) {
+            self.watched_addresses.push(address);
+        }
+
+        self
+    }
+
+    pub fn remove_watched_address(&mut self, address: String) -> &mut Self {
+        self.watched_addresses.retain(|watched| *watched != address);
+
+        self
+    }
+
+    /// Setting an empty label removes it
+    pub fn set_address_label(&mut self, (address, label): (String, String)) -> &mut Self {
+        if label.trim().is_empty() {
+            self.address_labels.remove(&address);
+        } else {
+            self.address_labels.insert(address, label);
+        }
+
+        self
+    }
 }
 
 pub struct ConfigWallet {
@@ -23,6 +23,7 @@
 mod trait_config_test;
 
 pub mod config_core;
+pub mod config_migration;
 pub mod config_mining;
 pub mod config_ui;
 pub mod config_wallet;
@@ -20,7 +20,14 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{env::temp_dir, fmt::Debug, fs, path::PathBuf};
+use std::{
+    env::temp_dir,
+    fmt::Debug,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use anyhow::Error;
 use dirs::config_dir;
@@ -31,12 +38,48 @@ use tari_common::configuration::Network;
 use tauri::{AppHandle, Manager};
 use tokio::sync::RwLock;
 
+use super::config_migration::{
+    apply_migrations, latest_schema_version, pending_migrations, record_migration,
+    stamp_schema_version, stored_schema_version, ConfigMigration, MigrationReport,
+    PendingMigrationReport,
+};
 use crate::{
     events_emitter::EventsEmitter,
     setup::setup_manager::{SetupManager, SetupPhase},
     UniverseAppState, APPLICATION_FOLDER_ID,
 };
 
+/// Writes to a temporary file next to the target and renames it over the target, so a crash
+/// or shutdown mid-write leaves either the old or the new file but never a truncated one
+fn write_atomically(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let tmp_path = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&tmp_path, path)
+}
+
+/// Copies of unreadable configs kept per config, older ones are removed
+const MAX_UNREADABLE_BACKUPS: usize = 5;
+
+/// Timestamped backups with the given prefix beyond the newest `keep`, oldest first
+fn backups_to_prune(file_names: &[String], prefix: &str, keep: usize) -> Vec<String> {
+    let mut backups: Vec<(u64, &String)> = file_names
+        .iter()
+        .filter_map(|name| {
+            let timestamp = name.strip_prefix(prefix)?.strip_suffix(".backup.json")?;
+            Some((timestamp.parse().ok()?, name))
+        })
+        .collect();
+    backups.sort();
+    let prune_count = backups.len().saturating_sub(keep);
+    backups
+        .into_iter()
+        .take(prune_count)
+        .map(|(_, name)| name.clone())
+        .collect()
+}
+
 #[allow(dead_code)]
 pub trait ConfigContentImpl: Clone + Default + Serialize + for<'de> Deserialize<'de> {}
 
@@ -81,13 +124,67 @@ pub trait ConfigImpl {
         Ok(())
     }
 
+    /// Schema history of the stored config, ordered by version
+    fn _migrations() -> Vec<ConfigMigration> {
+        vec![]
+    }
+
+    /// Copies the config file aside before it is migrated or replaced
+    fn _backup_config(config_path: &Path, label: &str) -> Option<PathBuf> {
+        let backup_path = config_path.with_extension(format!("{}.backup.json", label));
+        fs::copy(config_path, &backup_path)
+            .inspect_err(|error| {
+                warn!(target: LOG_TARGET, "[{}] [backup_config] error: {:?}", Self::_get_name(), error);
+            })
+            .ok()
+            .map(|_| backup_path)
+    }
+
+    fn _prune_unreadable_backups(config_path: &Path) {
+        let (Some(dir), Some(stem)) = (config_path.parent(), config_path.file_stem()) else {
+            return;
+        };
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        let file_names: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect();
+        let prefix = format!("{}.unreadable-", stem.to_string_lossy());
+        for file_name in backups_to_prune(&file_names, &prefix, MAX_UNREADABLE_BACKUPS) {
+            let _unused = fs::remove_file(dir.join(&file_name)).inspect_err(|error| {
+                warn!(target: LOG_TARGET, "[{}] [backup_config] could not remove old backup {}: {:?}", Self::_get_name(), file_name, error);
+            });
+        }
+    }
+
     fn _load_or_create() -> Self::Config {
         match Self::_load_config() {
             Ok(config_content) => {
                 info!(target: LOG_TARGET, "[{}] [load_config] loaded config content", Self::_get_name());
                 config_content
             }
-            Err(_) => {
+            Err(error) => {
+                let config_path = Self::_get_config_path();
+                if config_path.exists() {
+                    // Keep the unreadable file around instead of silently losing the user's settings
+                    let timestamp = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs();
+                    warn!(target: LOG_TARGET, "[{}] [load_config] could not read config: {:?}", Self::_get_name(), error);
+                    Self::_backup_config(&config_path, &format!("unreadable-{}", timestamp));
+                    Self::_prune_unreadable_backups(&config_path);
+
+                    if let Ok(config_content) = Self::_load_config_from(&Self::_last_good_path()) {
+                        warn!(target: LOG_TARGET, "[{}] [load_config] recovered config from the last good copy", Self::_get_name());
+                        let _unused = Self::_save_config(config_content.clone()).inspect_err(|error| {
+                            warn!(target: LOG_TARGET, "[{}] [save_config] error: {:?}", Self::_get_name(), error);
+                        });
+                        return config_content;
+                    }
+                }
                 debug!(target: LOG_TARGET, "[{}] [load_config] creating new config content", Self::_get_name());
                 let config_content = Self::Config::default();
                 let _unused = Self::_save_config(config_content.clone()).inspect_err(|error| {
@@ -98,21 +195,90 @@ pub trait ConfigImpl {
         }
     }
 
+    /// Hook to take values out of the content before it is written to disk
+    fn _prepare_for_save(config_content: Self::Config) -> Self::Config {
+        config_content
+    }
+
     fn _save_config(config_content: Self::Config) -> Result<(), Error> {
         let config_path = Self::_get_config_path();
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent)?;
         }
-        let config_content_serialized = serde_json::to_string_pretty(&config_content)?;
-        fs::write(config_path, config_content_serialized)?;
+        let config_content = Self::_prepare_for_save(config_content);
+        let stored_value = fs::read_to_string(&config_path)
+            .ok()
+            .and_then(|stored| serde_json::from_str(&stored).ok());
+        let config_value = stamp_schema_version(
+            serde_json::to_value(&config_content)?,
+            stored_value,
+            latest_schema_version(&Self::_migrations()),
+        );
+        let config_content_serialized = serde_json::to_string_pretty(&config_value)?;
+        write_atomically(&config_path, config_content_serialized.as_bytes())?;
+        // Only used for recovery, the config itself is already safely on disk
+        let _unused = write_atomically(&Self::_last_good_path(), config_content_serialized.as_bytes())
+            .inspect_err(|error| {
+                warn!(target: LOG_TARGET, "[{}] [save_config] could not update last good copy: {:?}", Self::_get_name(), error);
+            });
         Ok(())
     }
+    /// Copy of the last config that was written successfully, used when the config is unreadable
+    fn _last_good_path() -> PathBuf {
+        Self::_get_config_path().with_extension("last-good.json")
+    }
     fn _load_config() -> Result<Self::Config, Error> {
-        let config_path = Self::_get_config_path();
+        Self::_load_config_from(&Self::_get_config_path())
+    }
+    fn _load_config_from(config_path: &Path) -> Result<Self::Config, Error> {
         let config_content_serialized = fs::read_to_string(config_path)?;
-        let config_content: Self::Config = serde_json::from_str(&config_content_serialized)?;
+        let mut config_value: serde_json::Value = serde_json::from_str(&config_content_serialized)?;
+
+        let migrations = Self::_migrations();
+        let from_version = stored_schema_version(&config_value);
+        let to_version = latest_schema_version(&migrations);
+        if from_version > to_version {
+            warn!(target: LOG_TARGET, "[{}] [load_config] config has schema version {} but this version of the app only knows {}", Self::_get_name(), from_version, to_version);
+            Self::_backup_config(config_path, &format!("newer-v{}", from_version));
+        }
+        let applied = apply_migrations(&mut config_value, &migrations);
+        if !applied.is_empty() {
+            let backup_path = Self::_backup_config(config_path, &format!("v{}", from_version));
+            info!(target: LOG_TARGET, "[{}] [load_config] migrated config from schema version {} to {}: {:?}", Self::_get_name(), from_version, to_version, applied);
+            write_atomically(
+                config_path,
+                serde_json::to_string_pretty(&config_value)?.as_bytes(),
+            )?;
+            record_migration(MigrationReport {
+                config: Self::_get_name(),
+                from_version,
+                to_version,
+                applied,
+                backup_path: backup_path.map(|path| path.to_string_lossy().to_string()),
+            });
+        }
+
+        let config_content: Self::Config = serde_json::from_value(config_value)?;
         Ok(config_content)
     }
+    /// What loading the stored config would migrate, without writing anything
+    fn pending_migrations_report() -> Result<PendingMigrationReport, Error> {
+        let config_value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(Self::_get_config_path())?)?;
+        let migrations = Self::_migrations();
+        let stored_version = stored_schema_version(&config_value);
+        let latest_version = latest_schema_version(&migrations);
+        Ok(PendingMigrationReport {
+            config: Self::_get_name(),
+            stored_version,
+            latest_version,
+            pending: pending_migrations(&config_value, &migrations)
+                .iter()
+                .map(|migration| migration.description.to_string())
+                .collect(),
+            is_from_newer_app: stored_version > latest_version,
+        })
+    }
     async fn content() -> Self::Config
     where
         Self: 'static,
@@ -120,20 +286,26 @@ pub trait ConfigImpl {
         Self::current().read().await._get_content().clone()
     }
     async fn load_app_handle(&mut self, app_handle: AppHandle);
-    async fn update_field<F, I>(setter_callback: F, value: I) -> Result<(), Error>
+    /// Same as `update_field` but the value is never logged or sent with telemetry
+    async fn update_sensitive_field<F, I>(setter_callback: F, value: I) -> Result<(), Error>
     where
-        I: Serialize + Clone + Debug,
         F: FnOnce(&mut Self::Config, I) -> &mut Self::Config,
         Self: 'static,
     {
-        debug!(target: LOG_TARGET, "[{}] [update_field] with function: {:?} and value: {:?}", Self::_get_name(), std::any::type_name::<F>(), value);
-        setter_callback(
-            Self::current().write().await._get_content_mut(),
-            value.clone(),
-        );
+        setter_callback(Self::current().write().await._get_content_mut(), value);
         Self::_save_config(Self::current().read().await._get_content().clone()).inspect_err(|error|
             debug!(target: LOG_TARGET, "[{}] [update_field] error: {:?}", Self::_get_name(), error)
         )?;
+        Ok(())
+    }
+    async fn update_field<F, I>(setter_callback: F, value: I) -> Result<(), Error>
+    where
+        I: Serialize + Clone + Debug,
+        F: FnOnce(&mut Self::Config, I) -> &mut Self::Config,
+        Self: 'static,
+    {
+        debug!(target: LOG_TARGET, "[{}] [update_field] with function: {:?} and value: {:?}", Self::_get_name(), std::any::type_name::<F>(), value);
+        Self::update_sensitive_field(setter_callback, value.clone()).await?;
         Self::current()
             .read()
             .await
@@ -165,3 +337,32 @@ pub trait ConfigImpl {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prunes_oldest_unreadable_backups() {
+        let file_names: Vec<String> = [
+            "config_core.unreadable-300.backup.json",
+            "config_core.unreadable-100.backup.json",
+            "config_core.unreadable-1000.backup.json",
+            "config_core.v0.backup.json",
+            "config_mining.unreadable-50.backup.json",
+            "config_core.json",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+
+        assert_eq!(
+            backups_to_prune(&file_names, "config_core.unreadable-", 1),
+            vec![
+                "config_core.unreadable-100.backup.json".to_string(),
+                "config_core.unreadable-300.backup.json".to_string(),
+            ]
+        );
+        assert!(backups_to_prune(&file_names, "config_core.unreadable-", 5).is_empty());
+    }
+}
@@ -171,6 +171,21 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_recovering_from_last_good_copy() {
+        let config = TestConfig::current().read().await;
+        before_each();
+
+        let mut saved_content = config._get_content().clone();
+        saved_content.some_test_string = "last good".to_string();
+        TestConfig::_save_config(saved_content.clone()).unwrap();
+        fs::write(TestConfig::_get_config_path(), "{\"some_test_str").unwrap();
+
+        let recovered = TestConfig::_load_or_create();
+        assert_eq!(recovered.some_test_string, saved_content.some_test_string);
+        assert!(TestConfig::_load_config().is_ok());
+    }
+
     #[tokio::test]
     async fn test_if_loading_with_missing_files_is_handled() {
         before_each();
@@ -25,13 +25,14 @@ use crate::commands::{CpuMinerConnection, CpuMinerConnectionStatus, CpuMinerStat
 use crate::configs::config_mining::{ConfigMiningContent, MiningMode};
 use crate::configs::config_wallet::ConfigWalletContent;
 use crate::events_emitter::EventsEmitter;
+use crate::mining_tuner::MiningTuner;
 use crate::pool_status_watcher::SupportXmrStyleAdapter;
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::math_utils::estimate_earning;
 use crate::xmrig::http_api::models::Summary;
-use crate::xmrig_adapter::{XmrigAdapter, XmrigNodeConnection};
+use crate::xmrig_adapter::{XmrigAdapter, XmrigNodeConnection, XmrigStatusMonitor};
 use crate::{mm_proxy_manager, BaseNodeStatus, PoolStatusWatcher};
 use log::{debug, error, warn};
 use std::path::PathBuf;
@@ -215,8 +216,19 @@ impl CpuMiner {
             }
         };
 
+        // Explicit user settings win over presets learned from this machine's history, which are
+        // only used once the tuner has measured every thread count it may pick from
+        let tuner = MiningTuner::current();
+        let learned_presets = tuner.learned_cpu_presets().await;
+        let explored_threads = match mode {
+            MiningMode::Eco if cpu_miner_config.eco_mode_cpu_percentage.is_some() => None,
+            MiningMode::Eco | MiningMode::Ludicrous => tuner.cpu_threads_to_explore(&mode).await,
+            MiningMode::Custom => None,
+        };
         let eco_mode_threads = cpu_miner_config
             .eco_mode_cpu_percentage
+            .or(explored_threads)
+            .or(learned_presets.eco_threads)
             .unwrap_or((ECO_MODE_CPU_USAGE * max_cpu_available) / 100u32);
 
         let cpu_max_percentage = match mode {
@@ -228,8 +240,11 @@ impl CpuMiner {
                     custom_cpu_threads
                 }
             }
-            MiningMode::Ludicrous => None,
+            MiningMode::Ludicrous => explored_threads
+                .or(learned_presets.ludicrous_threads)
+                .filter(|threads| *threads < max_cpu_available),
         };
+        let mining_threads = cpu_max_percentage.unwrap_or(max_cpu_available);
         {
             let mut lock = self.watcher.write().await;
 
@@ -258,7 +273,8 @@ impl CpuMiner {
             .await?;
         }
 
-        self.initialize_status_updates(app_shutdown).await;
+        self.initialize_status_updates(app_shutdown, mining_threads)
+            .await;
 
         Ok(())
     }
@@ -374,13 +390,36 @@ impl CpuMiner {
         let lock = self.watcher.read().await;
         lock.is_running()
     }
+
+    /// Stops hashing but keeps xmrig running, so resuming skips the process start and pool login
+    pub async fn pause(&self) -> Result<(), anyhow::Error> {
+        self.running_status_monitor().await?.pause().await
+    }
+
+    pub async fn resume(&self) -> Result<(), anyhow::Error> {
+        self.running_status_monitor().await?.resume().await
+    }
+
+    async fn running_status_monitor(&self) -> Result<XmrigStatusMonitor, anyhow::Error> {
+        let lock = self.watcher.read().await;
+        if !lock.is_running() {
+            return Err(anyhow::anyhow!("CPU miner is not running"));
+        }
+        lock.status_monitor
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("CPU miner is not running"))
+    }
     #[allow(dead_code)]
     pub async fn is_pid_file_exists(&self, base_path: PathBuf) -> bool {
         let lock = self.watcher.read().await;
         lock.is_pid_file_exists(base_path)
     }
 
-    async fn initialize_status_updates(&self, mut app_shutdown: ShutdownSignal) {
+    async fn initialize_status_updates(
+        &self,
+        mut app_shutdown: ShutdownSignal,
+        mining_threads: u32,
+    ) {
         let cpu_miner_status_watch_tx = self.cpu_miner_status_watch_tx.clone();
         let mut summary_watch_rx = self.summary_watch_rx.clone();
         let node_status_watch_rx = self.node_status_watch_rx.clone();
@@ -426,11 +465,15 @@ impl CpuMiner {
                                 //     .iter()
                                 //     .fold(0.0, |acc, x| acc + x.unwrap_or(0.0));
                                 let is_connected = xmrig_status.connection.uptime > 0;
+                                if is_connected && !xmrig_status.paused {
+                                    MiningTuner::current().record_cpu_sample(mining_threads, hash_rate).await;
+                                }
                                 // dbg!(&last_pool_status);
 
 
                                 CpuMinerStatus {
                                     is_mining: true,
+                                    is_paused: xmrig_status.paused,
                                     hash_rate,
                                     estimated_earnings: MicroMinotari(estimated_earnings).as_u64(),
                                     connection: CpuMinerConnectionStatus { is_connected },
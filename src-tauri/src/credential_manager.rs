@@ -25,7 +25,12 @@ use crate::configs::trait_config::ConfigImpl;
 use crate::APPLICATION_FOLDER_ID;
 use keyring::{Entry, Error as KeyringError};
 use log::info;
-use serde::{Deserialize, Serialize};
+use ring::{
+    aead::{self, Aad, LessSafeKey, Nonce, UnboundKey, NONCE_LEN},
+    hkdf,
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::fs::OpenOptions;
 use std::io::{self, Read, Write};
 use std::path::PathBuf;
@@ -54,22 +59,46 @@ pub enum CredentialError {
     NoEntry(String),
     #[error("Data previously stored in Keychain.\nKeychain access is now required to continue")]
     PreviouslyUsedKeyring,
+    #[error("Fallback file encryption failed: {0}")]
+    Encryption(String),
 }
 
 const FALLBACK_FILE_PATH: &str = "credentials_backup.bin";
 
 const KEYCHAIN_USERNAME: &str = "inner_wallet_credentials";
 
+const SEALED_FALLBACK_MAGIC: &[u8; 5] = b"TUCR1";
+const SEALED_FALLBACK_SALT: &[u8] = b"tari-universe-credential-fallback";
+
 pub struct CredentialManager {
     service_name: String,
     username: String,
     fallback_mode: AtomicBool,
     fallback_dir: PathBuf,
+    fallback_file_name: String,
+    /// Encrypt the fallback file with a key bound to this machine
+    seal_fallback: bool,
 }
 
 impl CredentialManager {
     fn new(service_name: String, username: String, fallback_dir: PathBuf) -> Self {
-        let file_path = fallback_dir.join(FALLBACK_FILE_PATH);
+        Self::with_fallback_file(
+            service_name,
+            username,
+            fallback_dir,
+            FALLBACK_FILE_PATH.to_string(),
+            false,
+        )
+    }
+
+    fn with_fallback_file(
+        service_name: String,
+        username: String,
+        fallback_dir: PathBuf,
+        fallback_file_name: String,
+        seal_fallback: bool,
+    ) -> Self {
+        let file_path = fallback_dir.join(&fallback_file_name);
 
         let fallback_mode = AtomicBool::new(file_path.exists());
 
@@ -78,9 +107,24 @@ impl CredentialManager {
             username,
             fallback_mode,
             fallback_dir,
+            fallback_file_name,
+            seal_fallback,
         }
     }
 
+    /// Manager for a record other than the wallet credentials, e.g. the admin PIN. The record
+    /// is shared across networks and falls back to `<record_name>_backup.bin` in `fallback_dir`,
+    /// encrypted with a key derived from the machine id so it is never stored in plaintext.
+    pub fn for_record(record_name: &str, fallback_dir: PathBuf) -> Self {
+        CredentialManager::with_fallback_file(
+            APPLICATION_FOLDER_ID.into(),
+            record_name.to_string(),
+            fallback_dir,
+            format!("{}_backup.bin", record_name),
+            true,
+        )
+    }
+
     pub fn default_with_dir(fallback_dir: PathBuf) -> Self {
         let network_specific_name = format!(
             "{}_{}",
@@ -142,6 +186,14 @@ impl CredentialManager {
     }
 
     pub async fn set_credentials(&self, credential: &Credential) -> Result<(), CredentialError> {
+        self.set_record(credential).await
+    }
+
+    pub async fn get_credentials(&self) -> Result<Credential, CredentialError> {
+        self.get_record().await
+    }
+
+    pub async fn set_record<T: Serialize>(&self, credential: &T) -> Result<(), CredentialError> {
         if self.use_fallback() {
             self.save_to_file(credential)?;
             return Ok(());
@@ -166,7 +218,7 @@ impl CredentialManager {
         }
     }
 
-    pub async fn get_credentials(&self) -> Result<Credential, CredentialError> {
+    pub async fn get_record<T: DeserializeOwned>(&self) -> Result<T, CredentialError> {
         if self.use_fallback() {
             return self.load_from_file();
         }
@@ -189,7 +241,21 @@ impl CredentialManager {
         }
     }
 
-    fn save_to_keyring(&self, credential: &Credential) -> Result<(), CredentialError> {
+    /// Removes the record from the keyring and the fallback file
+    pub fn delete_record(&self) -> Result<(), CredentialError> {
+        if !self.use_fallback() {
+            match Entry::new(&self.service_name, &self.username)?.delete_credential() {
+                Ok(()) | Err(KeyringError::NoEntry) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        match std::fs::remove_file(self.fallback_file()) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn save_to_keyring<T: Serialize>(&self, credential: &T) -> Result<(), CredentialError> {
         let entry = Entry::new(&self.service_name, &self.username)?;
 
         let _unused = entry.delete_credential();
@@ -199,7 +265,7 @@ impl CredentialManager {
         Ok(())
     }
 
-    fn load_from_keyring(&self) -> Result<Credential, CredentialError> {
+    fn load_from_keyring<T: DeserializeOwned>(&self) -> Result<T, CredentialError> {
         let entry = Entry::new(&self.service_name, &self.username)?;
         let encoded = match entry.get_secret() {
             Ok(secret) => secret,
@@ -208,12 +274,15 @@ impl CredentialManager {
             }
             Err(e) => return Err(e.into()),
         };
-        let credential: Credential = serde_cbor::from_slice(&encoded)?;
+        let credential: T = serde_cbor::from_slice(&encoded)?;
         Ok(credential)
     }
 
-    fn save_to_file(&self, credential: &Credential) -> Result<(), CredentialError> {
-        let serialized = serde_cbor::to_vec(credential)?;
+    fn save_to_file<T: Serialize>(&self, credential: &T) -> Result<(), CredentialError> {
+        let mut serialized = serde_cbor::to_vec(credential)?;
+        if self.seal_fallback {
+            serialized = seal(&serialized, &fallback_key(&machine_id()?, &self.username))?;
+        }
         if let Some(parent) = self.fallback_file().parent() {
             if !parent.exists() {
                 std::fs::create_dir_all(parent)?;
@@ -228,15 +297,134 @@ impl CredentialManager {
         Ok(())
     }
 
-    fn load_from_file(&self) -> Result<Credential, CredentialError> {
+    fn load_from_file<T: DeserializeOwned>(&self) -> Result<T, CredentialError> {
         let mut file = OpenOptions::new().read(true).open(self.fallback_file())?;
         let mut buffer = Vec::new();
         file.read_to_end(&mut buffer)?;
-        let credential: Credential = serde_cbor::from_slice(&buffer)?;
+        if self.seal_fallback {
+            buffer = open(&buffer, &fallback_key(&machine_id()?, &self.username))?;
+        }
+        let credential: T = serde_cbor::from_slice(&buffer)?;
         Ok(credential)
     }
 
     fn fallback_file(&self) -> PathBuf {
-        self.fallback_dir.join(FALLBACK_FILE_PATH)
+        self.fallback_dir.join(&self.fallback_file_name)
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn machine_id() -> Result<String, CredentialError> {
+    use winreg::{enums::HKEY_LOCAL_MACHINE, RegKey};
+
+    let id: String = RegKey::predef(HKEY_LOCAL_MACHINE)
+        .open_subkey("SOFTWARE\\Microsoft\\Cryptography")?
+        .get_value("MachineGuid")?;
+    non_empty_machine_id(&id)
+}
+
+#[cfg(target_os = "macos")]
+fn machine_id() -> Result<String, CredentialError> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-rd1", "-c", "IOPlatformExpertDevice"])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let id = stdout
+        .lines()
+        .find(|line| line.contains("IOPlatformUUID"))
+        .and_then(|line| line.split('"').nth(3))
+        .unwrap_or_default();
+    non_empty_machine_id(id)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn machine_id() -> Result<String, CredentialError> {
+    let id = std::fs::read_to_string("/etc/machine-id")
+        .or_else(|_| std::fs::read_to_string("/var/lib/dbus/machine-id"))?;
+    non_empty_machine_id(&id)
+}
+
+fn non_empty_machine_id(id: &str) -> Result<String, CredentialError> {
+    let id = id.trim();
+    if id.is_empty() {
+        return Err(CredentialError::Encryption(
+            "Machine id is not available".to_string(),
+        ));
+    }
+    Ok(id.to_string())
+}
+
+fn fallback_key(machine_id: &str, record_name: &str) -> LessSafeKey {
+    let info = [record_name.as_bytes()];
+    let okm = hkdf::Salt::new(hkdf::HKDF_SHA256, SEALED_FALLBACK_SALT)
+        .extract(machine_id.as_bytes())
+        .expand(&info, &aead::AES_256_GCM)
+        .expect("AES-256 key length is a valid HKDF output length");
+    LessSafeKey::new(UnboundKey::from(okm))
+}
+
+/// Output is the magic, a random nonce and the AES-256-GCM ciphertext with its tag
+fn seal(plaintext: &[u8], key: &LessSafeKey) -> Result<Vec<u8>, CredentialError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| CredentialError::Encryption("Could not generate a nonce".to_string()))?;
+    let mut ciphertext = plaintext.to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(SEALED_FALLBACK_MAGIC),
+        &mut ciphertext,
+    )
+    .map_err(|_| CredentialError::Encryption("Could not encrypt the record".to_string()))?;
+
+    let mut sealed = SEALED_FALLBACK_MAGIC.to_vec();
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+fn open(sealed: &[u8], key: &LessSafeKey) -> Result<Vec<u8>, CredentialError> {
+    let invalid =
+        || CredentialError::Encryption("Record can not be decrypted on this machine".to_string());
+    let rest = sealed
+        .strip_prefix(SEALED_FALLBACK_MAGIC.as_slice())
+        .filter(|rest| rest.len() >= NONCE_LEN)
+        .ok_or_else(invalid)?;
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| invalid())?;
+    let mut in_out = ciphertext.to_vec();
+    let plaintext = key
+        .open_in_place(nonce, Aad::from(SEALED_FALLBACK_MAGIC), &mut in_out)
+        .map_err(|_| invalid())?;
+    Ok(plaintext.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::config_core::AirdropTokens;
+
+    #[test]
+    fn sealed_fallback_does_not_contain_the_token() {
+        let tokens = AirdropTokens {
+            token: "eyJhbGciOiJIUzI1NiJ9.access-token".to_string(),
+            refresh_token: "refresh-token-value".to_string(),
+        };
+        let serialized = serde_cbor::to_vec(&tokens).expect("tokens serialize");
+        let key = fallback_key("machine-a", "airdrop_tokens_mainnet");
+        let sealed = seal(&serialized, &key).expect("record is sealed");
+
+        for secret in [&tokens.token, &tokens.refresh_token] {
+            assert!(!sealed
+                .windows(secret.len())
+                .any(|window| window == secret.as_bytes()));
+        }
+
+        let opened = open(&sealed, &key).expect("record opens with the same key");
+        let restored: AirdropTokens = serde_cbor::from_slice(&opened).expect("tokens deserialize");
+        assert_eq!(restored, tokens);
+
+        let other_machine = fallback_key("machine-b", "airdrop_tokens_mainnet");
+        assert!(open(&sealed, &other_machine).is_err());
     }
 }
@@ -0,0 +1,377 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::info;
+use monero_address_creator::Seed as MoneroSeed;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tari_common::configuration::Network;
+use tari_common_types::{
+    tari_address::{TariAddress, TariAddressFeatures},
+    types::{CompressedPublicKey, PrivateKey},
+};
+use tari_core::transactions::tari_amount::MicroMinotari;
+use tari_crypto::keys::SecretKey;
+use tari_key_manager::mnemonic_wordlists::MNEMONIC_ENGLISH_WORDS;
+use tokio::{select, sync::RwLock, time};
+
+use crate::{
+    commands::{CpuMinerConnectionStatus, CpuMinerStatus},
+    events_emitter::EventsEmitter,
+    gpu_miner_adapter::GpuMinerStatus,
+    node::node_adapter::BaseNodeStatus,
+    spend_wallet_manager::{
+        check_sufficient_funds, estimate_send_fee, parse_send_amount, sweep_amount,
+        SpendWalletError,
+    },
+    tasks_tracker::TasksTrackers,
+    utils::wallet_utils::normalize_tari_address,
+    wallet_adapter::{TransactionInfo, WalletBalance},
+};
+
+const LOG_TARGET: &str = "tari::universe::demo_mode";
+/// Same seed on every run so demo sessions are reproducible
+const DEMO_SEED: u64 = 0x7a71_0e5e;
+/// Separate seed for the synthetic keys so they don't shift the simulated blocks
+const DEMO_WALLET_SEED: u64 = 0x7a71_0e5f;
+const DEMO_SEED_WORD_COUNT: usize = 24;
+const DEMO_BLOCK_INTERVAL: Duration = Duration::from_secs(10);
+const DEMO_START_BLOCK_HEIGHT: u64 = 42_000;
+const DEMO_BLOCK_REWARD: u64 = 13_900_000_000;
+const DEMO_CPU_HASH_RATE: f64 = 4_200.0;
+const DEMO_GPU_HASH_RATE: f64 = 1_850_000_000.0;
+/// Chance of a simulated block being won while mining
+const DEMO_WIN_PROBABILITY: f64 = 0.25;
+// Values mirror the minotari wallet gRPC enums
+const TX_STATUS_BROADCAST: i32 = 1;
+const TX_STATUS_COINBASE_CONFIRMED: i32 = 13;
+const TX_DIRECTION_INBOUND: i32 = 1;
+const TX_DIRECTION_OUTBOUND: i32 = 2;
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+static DEMO_STATE: LazyLock<RwLock<DemoState>> = LazyLock::new(|| RwLock::new(DemoState::new()));
+
+struct DemoState {
+    rng: StdRng,
+    block_height: u64,
+    balance: u64,
+    transactions: Vec<TransactionInfo>,
+    is_cpu_mining: bool,
+    is_gpu_mining: bool,
+}
+
+impl DemoState {
+    fn new() -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(DEMO_SEED),
+            block_height: DEMO_START_BLOCK_HEIGHT,
+            balance: 0,
+            transactions: vec![],
+            is_cpu_mining: false,
+            is_gpu_mining: false,
+        }
+    }
+
+    fn jitter(&mut self, value: f64) -> f64 {
+        value * self.rng.gen_range(0.9..1.1)
+    }
+
+    fn balance(&self) -> WalletBalance {
+        WalletBalance {
+            available_balance: MicroMinotari(self.balance),
+            timelocked_balance: MicroMinotari(0),
+            pending_incoming_balance: MicroMinotari(0),
+            pending_outgoing_balance: MicroMinotari(0),
+        }
+    }
+
+    fn record_send(
+        &mut self,
+        amount: MicroMinotari,
+        fee: MicroMinotari,
+        destination: String,
+        payment_id: Option<String>,
+    ) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let send = TransactionInfo {
+            tx_id: self.rng.gen::<u64>().to_string(),
+            source_address: DemoMode::tari_address().to_base58(),
+            dest_address: destination,
+            status: TX_STATUS_BROADCAST,
+            amount,
+            is_cancelled: false,
+            direction: TX_DIRECTION_OUTBOUND,
+            excess_sig: vec![],
+            fee: fee.as_u64(),
+            timestamp,
+            payment_id: payment_id.unwrap_or_default(),
+            mined_in_block_height: 0,
+            payment_reference: None,
+            label: None,
+            counterparty_label: None,
+        };
+        self.balance -= (amount + fee).as_u64();
+        self.transactions.insert(0, send);
+    }
+
+    fn next_block(&mut self) -> Option<TransactionInfo> {
+        self.block_height += 1;
+        let is_mining = self.is_cpu_mining || self.is_gpu_mining;
+        if !is_mining || !self.rng.gen_bool(DEMO_WIN_PROBABILITY) {
+            return None;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let coinbase = TransactionInfo {
+            tx_id: self.rng.gen::<u64>().to_string(),
+            source_address: String::new(),
+            dest_address: String::new(),
+            status: TX_STATUS_COINBASE_CONFIRMED,
+            amount: MicroMinotari(DEMO_BLOCK_REWARD),
+            is_cancelled: false,
+            direction: TX_DIRECTION_INBOUND,
+            excess_sig: vec![],
+            fee: 0,
+            timestamp,
+            payment_id: String::new(),
+            mined_in_block_height: self.block_height,
+            payment_reference: None,
+            label: None,
+            counterparty_label: None,
+        };
+        self.balance += DEMO_BLOCK_REWARD;
+        self.transactions.insert(0, coinbase.clone());
+        Some(coinbase)
+    }
+}
+
+/// Simulated wallet, node and miners used instead of the real binaries when the app is
+/// started with `--demo`.
+pub struct DemoMode;
+
+impl DemoMode {
+    pub fn enable_for_session() {
+        info!(target: LOG_TARGET, "Demo mode enabled for this session");
+        IS_ENABLED.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_enabled() -> bool {
+        IS_ENABLED.load(Ordering::SeqCst)
+    }
+
+    pub async fn set_cpu_mining(is_mining: bool) {
+        DEMO_STATE.write().await.is_cpu_mining = is_mining;
+        Self::emit_mining_status().await;
+    }
+
+    pub async fn set_gpu_mining(is_mining: bool) {
+        DEMO_STATE.write().await.is_gpu_mining = is_mining;
+        Self::emit_mining_status().await;
+    }
+
+    pub async fn balance() -> WalletBalance {
+        DEMO_STATE.read().await.balance()
+    }
+
+    /// Synthetic address so demo sessions never show the real wallet's
+    pub fn tari_address() -> TariAddress {
+        static ADDRESS: LazyLock<TariAddress> = LazyLock::new(|| {
+            let mut rng = StdRng::seed_from_u64(DEMO_WALLET_SEED);
+            let view_key = CompressedPublicKey::from_secret_key(&PrivateKey::random(&mut rng));
+            let spend_key = CompressedPublicKey::from_secret_key(&PrivateKey::random(&mut rng));
+            TariAddress::new_dual_address(
+                view_key,
+                spend_key,
+                Network::get_current_or_user_setting_or_default(),
+                TariAddressFeatures::create_one_sided_only(),
+                None,
+            )
+            .expect("Demo address is built from valid keys")
+        });
+        ADDRESS.clone()
+    }
+
+    pub fn seed_words() -> Vec<String> {
+        let mut rng = StdRng::seed_from_u64(DEMO_WALLET_SEED);
+        (0..DEMO_SEED_WORD_COUNT)
+            .map(|_| {
+                MNEMONIC_ENGLISH_WORDS[rng.gen_range(0..MNEMONIC_ENGLISH_WORDS.len())].to_string()
+            })
+            .collect()
+    }
+
+    pub fn monero_seed_words() -> Result<Vec<String>, String> {
+        let seed = StdRng::seed_from_u64(DEMO_WALLET_SEED).gen::<[u8; 32]>();
+        MoneroSeed::new(seed)
+            .seed_words()
+            .map_err(|e| e.to_string())
+    }
+
+    /// Takes the send out of the simulated balance and adds it to the demo history, it is
+    /// checked the same way a real send is
+    pub async fn send(
+        amount: &str,
+        destination: String,
+        payment_id: Option<String>,
+        sweep: bool,
+    ) -> Result<(), SpendWalletError> {
+        if normalize_tari_address(&destination).is_none() {
+            return Err(SpendWalletError::InvalidAddress(destination));
+        }
+        let balance = {
+            let mut state = DEMO_STATE.write().await;
+            let balance = state.balance();
+            let amount = if sweep {
+                None
+            } else {
+                Some(parse_send_amount(amount)?)
+            };
+            let fee = estimate_send_fee(&[balance.available_balance], amount);
+            let amount = match amount {
+                Some(amount) => {
+                    check_sufficient_funds(&balance, amount, fee)?;
+                    amount
+                }
+                None => sweep_amount(&balance, fee)?,
+            };
+            state.record_send(amount, fee, destination, payment_id);
+            state.balance()
+        };
+        EventsEmitter::emit_wallet_balance_update(balance).await;
+        Ok(())
+    }
+
+    pub async fn transaction_count() -> usize {
+        DEMO_STATE.read().await.transactions.len()
+    }
+
+    pub async fn get_transactions(offset: Option<i32>, limit: Option<i32>) -> Vec<TransactionInfo> {
+        let offset = usize::try_from(offset.unwrap_or(0)).unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        DEMO_STATE
+            .read()
+            .await
+            .transactions
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    async fn emit_mining_status() {
+        let (cpu_status, gpu_status) = {
+            let mut state = DEMO_STATE.write().await;
+            let cpu_hash_rate = if state.is_cpu_mining {
+                state.jitter(DEMO_CPU_HASH_RATE)
+            } else {
+                0.0
+            };
+            let gpu_hash_rate = if state.is_gpu_mining {
+                state.jitter(DEMO_GPU_HASH_RATE)
+            } else {
+                0.0
+            };
+            (
+                CpuMinerStatus {
+                    is_mining: state.is_cpu_mining,
+                    is_paused: false,
+                    hash_rate: cpu_hash_rate,
+                    estimated_earnings: 0,
+                    connection: CpuMinerConnectionStatus {
+                        is_connected: state.is_cpu_mining,
+                    },
+                    pool_status: None,
+                },
+                GpuMinerStatus {
+                    is_mining: state.is_gpu_mining,
+                    hash_rate: gpu_hash_rate,
+                    estimated_earnings: 0,
+                },
+            )
+        };
+        EventsEmitter::emit_cpu_mining_update(cpu_status).await;
+        EventsEmitter::emit_gpu_mining_update(gpu_status).await;
+    }
+
+    async fn tick() {
+        let (node_status, coinbase, balance) = {
+            let mut state = DEMO_STATE.write().await;
+            let coinbase = state.next_block();
+            let node_status = BaseNodeStatus {
+                block_reward: MicroMinotari(DEMO_BLOCK_REWARD),
+                block_height: state.block_height,
+                block_time: DEMO_BLOCK_INTERVAL.as_secs(),
+                is_synced: true,
+                num_connections: 8,
+                ..BaseNodeStatus::default()
+            };
+            (node_status, coinbase, state.balance())
+        };
+        let block_height = node_status.block_height;
+
+        EventsEmitter::emit_base_node_update(node_status).await;
+        Self::emit_mining_status().await;
+
+        if coinbase.is_some() {
+            EventsEmitter::emit_wallet_balance_update(balance.clone()).await;
+        }
+        EventsEmitter::emit_new_block_mined(block_height, coinbase, Some(balance)).await;
+    }
+
+    pub async fn spawn() {
+        let mut interval = time::interval(DEMO_BLOCK_INTERVAL);
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received. Stopping demo simulation.");
+                            break;
+                        }
+                        _ = interval.tick() => {
+                            DemoMode::tick().await;
+                        }
+                    }
+                }
+            });
+    }
+}
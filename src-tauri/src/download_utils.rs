@@ -23,15 +23,21 @@
 use anyhow::{anyhow, Error};
 use async_zip::base::read::seek::ZipFileReader;
 use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use tar::Archive;
+use tar::{Archive, Builder, Header};
 use tokio::fs;
 use tokio::fs::{File, OpenOptions};
 use tokio::io::AsyncReadExt;
 use tokio::io::BufReader;
 use tokio_util::compat::{TokioAsyncReadCompatExt, TokioAsyncWriteCompatExt};
 
+/// Name of the metadata entry at the front of the archives the app exports
+pub const ARCHIVE_MANIFEST_NAME: &str = "tari-universe-manifest.json";
+
 pub async fn extract(file_path: &Path, dest_dir: &Path) -> Result<(), anyhow::Error> {
     match file_path.extension() {
         Some(ext) => match ext.to_str() {
@@ -65,6 +71,65 @@ pub async fn extract_gz(gz_path: &Path, dest_dir: &Path) -> std::io::Result<()>
     Ok(())
 }
 
+pub async fn compress_dir_to_gz(
+    source_dir: &Path,
+    manifest: Option<Vec<u8>>,
+    gz_path: &Path,
+) -> Result<(), Error> {
+    let source_dir = source_dir.to_path_buf();
+    let gz_file = std::fs::File::create(gz_path)?;
+    tokio::task::spawn_blocking(move || -> Result<(), Error> {
+        write_dir_as_gz(
+            &source_dir,
+            manifest.as_deref(),
+            std::io::BufWriter::new(gz_file),
+        )?
+        .flush()?;
+        Ok(())
+    })
+    .await??;
+    Ok(())
+}
+
+/// Streams `source_dir` into `writer` as a gzipped tarball, with `manifest` as its first entry
+pub fn write_dir_as_gz<W: Write>(
+    source_dir: &Path,
+    manifest: Option<&[u8]>,
+    writer: W,
+) -> Result<W, Error> {
+    let mut builder = Builder::new(GzEncoder::new(writer, Compression::default()));
+    if let Some(manifest) = manifest {
+        let mut header = Header::new_gnu();
+        header.set_size(manifest.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, ARCHIVE_MANIFEST_NAME, manifest)?;
+    }
+    builder.append_dir_all(".", source_dir)?;
+    Ok(builder.into_inner()?.finish()?)
+}
+
+/// Unpacks a tarball written by [`write_dir_as_gz`] into `dest_dir` and returns its manifest, if it has one
+pub fn unpack_gz_with_manifest<R: Read>(
+    reader: R,
+    dest_dir: &Path,
+) -> Result<Option<Vec<u8>>, Error> {
+    std::fs::create_dir_all(dest_dir)?;
+    let mut archive = Archive::new(GzDecoder::new(reader));
+    let mut manifest = None;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.path()?.as_ref() == Path::new(ARCHIVE_MANIFEST_NAME) {
+            let mut contents = Vec::new();
+            entry.read_to_end(&mut contents)?;
+            manifest = Some(contents);
+        } else {
+            entry.unpack_in(dest_dir)?;
+        }
+    }
+    Ok(manifest)
+}
+
 // Taken from async_zip example
 
 fn sanitize_file_path(path: &str) -> PathBuf {
@@ -161,18 +226,26 @@ pub async fn set_permissions(_file_path: &Path) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+pub async fn calculate_checksum(file_path: &Path) -> Result<String, Error> {
+    let mut file = File::open(file_path).await?;
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut hasher = Sha256::new();
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    let hash = hasher.finalize();
+    Ok(format!("{:x}", hash))
+}
+
 pub async fn validate_checksum(
     file_path: PathBuf,
     expected_checksum: String,
 ) -> Result<bool, Error> {
-    let mut file = File::open(file_path.clone()).await?;
-    let mut buffer = Vec::new();
-    file.read_to_end(&mut buffer).await?;
-
-    let mut hasher = Sha256::new();
-    hasher.update(&buffer);
-    let hash = hasher.finalize();
-    let hash_hex = format!("{:x}", hash);
+    let hash_hex = calculate_checksum(&file_path).await?;
 
     Ok(hash_hex == expected_checksum)
 }
@@ -0,0 +1,385 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tokio::{select, time};
+
+use crate::{
+    events::EarningsAnomalyPayload, events_emitter::EventsEmitter, tasks_tracker::TasksTrackers,
+    UniverseAppState, APPLICATION_FOLDER_ID,
+};
+
+const LOG_TARGET: &str = "tari::universe::earnings_monitor";
+const HISTORY_FILE_NAME: &str = "earnings_history.json";
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// Gaps longer than this, like the app being closed, are not counted as mining time
+const MAX_SAMPLE_GAP: Duration = Duration::from_secs(2 * 10 * 60);
+/// Share counts are compared over this window to spot a pool that stopped accepting them
+const RECENT_WINDOW: Duration = Duration::from_secs(3 * 60 * 60);
+const BASELINE_WINDOW: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// Hours of history needed before mined rewards are compared with the estimate
+const MIN_BASELINE_SAMPLES: usize = 12 * 6;
+/// Blocks the estimate has to add up to before a shortfall is more than bad luck
+const MIN_EXPECTED_BLOCKS: u64 = 4;
+const ANOMALY_THRESHOLD_PERCENTAGE: f64 = 50.0;
+const NETWORK_HASHRATE_SPIKE_FACTOR: f64 = 1.5;
+const ALERT_COOLDOWN: Duration = Duration::from_secs(6 * 60 * 60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+static IS_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EarningsAnomalyCause {
+    CpuNoHashrate,
+    CpuPoolDisconnected,
+    PoolNotAcceptingShares,
+    GpuNoHashrate,
+    NodeNotSynced,
+    NetworkHashrateIncreased,
+    BlockRewardDecreased,
+}
+
+/// One sampling interval. `expected_earnings` is what the solo miners' estimate says the
+/// interval should have earned, `mined_earnings` the coinbase rewards the wallet received in it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct EarningsSample {
+    taken_at: u64,
+    expected_earnings: u64,
+    mined_earnings: u64,
+    network_hashrate: u64,
+    block_reward: u64,
+    accepted_shares: Option<u64>,
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn average(samples: &[&EarningsSample], value: impl Fn(&EarningsSample) -> u64) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    samples.iter().map(|s| value(s) as f64).sum::<f64>() / samples.len() as f64
+}
+
+/// Returns how far, in percent, mined rewards fell below the estimate when the drop counts as an anomaly
+pub fn earnings_drop_percentage(mined: f64, expected: f64) -> Option<f64> {
+    if expected <= 0.0 {
+        return None;
+    }
+    let drop_percentage = (1.0 - mined / expected) * 100.0;
+    (drop_percentage >= ANOMALY_THRESHOLD_PERCENTAGE).then_some(drop_percentage)
+}
+
+/// Kept on disk so the baseline survives restarts instead of taking half a day to rebuild
+#[derive(Default, Serialize, Deserialize)]
+struct EarningsHistory {
+    samples: VecDeque<EarningsSample>,
+    last_alert_at: Option<u64>,
+}
+
+impl EarningsHistory {
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APPLICATION_FOLDER_ID).join(HISTORY_FILE_NAME))
+    }
+
+    fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| {
+                serde_json::from_str(&content)
+                    .inspect_err(|e| {
+                        warn!(target: LOG_TARGET, "Ignoring unreadable earnings history: {}", e)
+                    })
+                    .ok()
+            })
+            .unwrap_or_default()
+    }
+
+    fn save(&self, path: &Path) {
+        let result = serde_json::to_string(self)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(std::fs::write(path, content)?));
+        if let Err(e) = result {
+            warn!(target: LOG_TARGET, "Could not save earnings history: {}", e);
+        }
+    }
+
+    fn last_taken_at(&self) -> Option<u64> {
+        self.samples.back().map(|s| s.taken_at)
+    }
+
+    fn push(&mut self, sample: EarningsSample) {
+        self.samples.push_back(sample);
+        while self
+            .samples
+            .front()
+            .is_some_and(|s| sample.taken_at.saturating_sub(s.taken_at) > BASELINE_WINDOW.as_secs())
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    fn recent(&self, now: u64) -> Vec<&EarningsSample> {
+        self.samples
+            .iter()
+            .filter(|s| now.saturating_sub(s.taken_at) <= RECENT_WINDOW.as_secs())
+            .collect()
+    }
+}
+
+pub struct EarningsMonitor;
+
+impl EarningsMonitor {
+    async fn take_sample(
+        app_handle: &AppHandle,
+        previous_taken_at: Option<u64>,
+    ) -> Option<(EarningsSample, Vec<EarningsAnomalyCause>)> {
+        let state = app_handle.state::<UniverseAppState>();
+        let cpu_status = state.cpu_miner_status_watch_rx.borrow().clone();
+        let gpu_status = state.gpu_latest_status.borrow().clone();
+        let node_status = *state.node_status_watch_rx.borrow();
+        if !cpu_status.is_mining && !gpu_status.is_mining {
+            return None;
+        }
+        if cpu_status.is_paused && !gpu_status.is_mining {
+            return None;
+        }
+
+        let mut causes = vec![];
+        if cpu_status.is_mining && !cpu_status.is_paused {
+            if cpu_status.hash_rate <= 0.0 {
+                causes.push(EarningsAnomalyCause::CpuNoHashrate);
+            }
+            if !cpu_status.connection.is_connected {
+                causes.push(EarningsAnomalyCause::CpuPoolDisconnected);
+            }
+        }
+        if gpu_status.is_mining && gpu_status.hash_rate <= 0.0 {
+            causes.push(EarningsAnomalyCause::GpuNoHashrate);
+        }
+        if !node_status.is_synced {
+            causes.push(EarningsAnomalyCause::NodeNotSynced);
+        }
+
+        let taken_at = unix_now();
+        let interval = previous_taken_at
+            .map(|previous| taken_at.saturating_sub(previous))
+            .filter(|interval| *interval <= MAX_SAMPLE_GAP.as_secs())
+            .unwrap_or(SAMPLE_INTERVAL.as_secs());
+        // Pool mining is paid out by the pool rather than through coinbases, it is watched
+        // through its accepted shares instead
+        let solo_daily_estimate = if cpu_status.pool_status.is_some() {
+            gpu_status.estimated_earnings
+        } else {
+            cpu_status
+                .estimated_earnings
+                .saturating_add(gpu_status.estimated_earnings)
+        };
+        let expected_earnings = u64::try_from(
+            u128::from(solo_daily_estimate) * u128::from(interval) / u128::from(DAY.as_secs()),
+        )
+        .unwrap_or(u64::MAX);
+
+        let since = taken_at.saturating_sub(interval);
+        let mined_earnings = match state
+            .wallet_manager
+            .get_coinbase_transactions(false, None)
+            .await
+        {
+            Ok(transactions) => transactions
+                .iter()
+                .filter(|tx| !tx.is_cancelled && tx.timestamp > since && tx.timestamp <= taken_at)
+                .map(|tx| tx.amount.as_u64())
+                .fold(0u64, u64::saturating_add),
+            Err(e) => {
+                // Without the wallet the sample would read as nothing mined
+                warn!(target: LOG_TARGET, "Skipping earnings sample, could not read coinbase transactions: {}", e);
+                return None;
+            }
+        };
+
+        let sample = EarningsSample {
+            taken_at,
+            expected_earnings,
+            mined_earnings,
+            network_hashrate: node_status
+                .monero_randomx_network_hashrate
+                .saturating_add(node_status.sha_network_hashrate),
+            block_reward: node_status.block_reward.as_u64(),
+            accepted_shares: cpu_status.pool_status.map(|pool| pool.accepted_shares),
+        };
+        Some((sample, causes))
+    }
+
+    async fn check(app_handle: &AppHandle, history: &mut EarningsHistory) -> bool {
+        let Some((sample, mut causes)) =
+            Self::take_sample(app_handle, history.last_taken_at()).await
+        else {
+            return false;
+        };
+        history.push(sample);
+
+        let samples: Vec<&EarningsSample> = history.samples.iter().collect();
+        let recent = history.recent(sample.taken_at);
+        let first_shares = recent.first().and_then(|s| s.accepted_shares);
+        let is_pool_stalled =
+            first_shares.is_some() && first_shares == sample.accepted_shares && recent.len() > 1;
+        if samples.len() < MIN_BASELINE_SAMPLES {
+            return true;
+        }
+
+        let expected: u64 = samples.iter().map(|s| s.expected_earnings).sum();
+        let mined: u64 = samples.iter().map(|s| s.mined_earnings).sum();
+        let is_expectation_meaningful =
+            expected >= sample.block_reward.saturating_mul(MIN_EXPECTED_BLOCKS);
+        let drop_percentage = if is_expectation_meaningful {
+            earnings_drop_percentage(mined as f64, expected as f64)
+        } else {
+            None
+        };
+        let Some(drop_percentage) = drop_percentage.or(is_pool_stalled.then_some(100.0)) else {
+            return true;
+        };
+        if history
+            .last_alert_at
+            .is_some_and(|last| sample.taken_at.saturating_sub(last) < ALERT_COOLDOWN.as_secs())
+        {
+            return true;
+        }
+
+        let older: Vec<&EarningsSample> = samples
+            .iter()
+            .copied()
+            .filter(|s| sample.taken_at.saturating_sub(s.taken_at) > RECENT_WINDOW.as_secs())
+            .collect();
+        if average(&recent, |s| s.network_hashrate)
+            > average(&older, |s| s.network_hashrate) * NETWORK_HASHRATE_SPIKE_FACTOR
+        {
+            causes.push(EarningsAnomalyCause::NetworkHashrateIncreased);
+        }
+        if average(&recent, |s| s.block_reward) < average(&older, |s| s.block_reward) {
+            causes.push(EarningsAnomalyCause::BlockRewardDecreased);
+        }
+        if is_pool_stalled {
+            causes.push(EarningsAnomalyCause::PoolNotAcceptingShares);
+        }
+
+        warn!(target: LOG_TARGET, "Mined earnings are {:.0}% below the estimate, probable causes: {:?}", drop_percentage, causes);
+        history.last_alert_at = Some(sample.taken_at);
+        let covered_days = (samples.len() as f64 * SAMPLE_INTERVAL.as_secs_f64()
+            / DAY.as_secs_f64())
+        .max(1.0 / DAY.as_secs_f64());
+        #[allow(clippy::cast_possible_truncation)]
+        let (expected_daily_earnings, actual_daily_earnings) = (
+            (expected as f64 / covered_days) as u64,
+            (mined as f64 / covered_days) as u64,
+        );
+        EventsEmitter::emit_earnings_anomaly(EarningsAnomalyPayload {
+            expected_daily_earnings,
+            actual_daily_earnings,
+            drop_percentage,
+            probable_causes: causes,
+        })
+        .await;
+        true
+    }
+
+    pub async fn spawn(app_handle: AppHandle) {
+        if IS_RUNNING.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let mut interval = time::interval(SAMPLE_INTERVAL);
+        let mut shutdown_signal = TasksTrackers::current().mining_phase.get_signal().await;
+        TasksTrackers::current()
+            .mining_phase
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let history_path = EarningsHistory::path();
+                let mut history = history_path
+                    .as_deref()
+                    .map(EarningsHistory::load)
+                    .unwrap_or_default();
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received. Stopping earnings monitor.");
+                            break;
+                        }
+                        _ = interval.tick() => {
+                            let has_new_sample = EarningsMonitor::check(&app_handle, &mut history).await;
+                            if let Some(path) = history_path.as_deref().filter(|_| has_new_sample) {
+                                history.save(path);
+                            }
+                        }
+                    }
+                }
+                IS_RUNNING.store(false, Ordering::SeqCst);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_large_drop() {
+        assert_eq!(earnings_drop_percentage(40.0, 100.0), Some(60.0));
+    }
+
+    #[test]
+    fn ignores_small_drop_and_missing_baseline() {
+        assert_eq!(earnings_drop_percentage(80.0, 100.0), None);
+        assert_eq!(earnings_drop_percentage(0.0, 0.0), None);
+    }
+
+    #[test]
+    fn history_drops_samples_older_than_baseline() {
+        let sample = |taken_at| EarningsSample {
+            taken_at,
+            expected_earnings: 10,
+            mined_earnings: 0,
+            network_hashrate: 0,
+            block_reward: 0,
+            accepted_shares: None,
+        };
+        let mut history = EarningsHistory::default();
+        history.push(sample(0));
+        history.push(sample(BASELINE_WINDOW.as_secs()));
+        history.push(sample(BASELINE_WINDOW.as_secs() + 1));
+        assert_eq!(history.samples.len(), 2);
+        assert_eq!(history.recent(BASELINE_WINDOW.as_secs() + 1).len(), 2);
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::{info, warn};
+use serde::Serialize;
+use tauri::AppHandle;
+use tokio::sync::RwLock;
+
+use crate::{
+    events_emitter::EventsEmitter, outbound_queue::OutboundQueue, spend_allowance::SpendAllowances,
+    utils::mining_utils::stop_all_miners,
+};
+
+const LOG_TARGET: &str = "tari::universe::emergency_stop";
+const OPERATIONS_HALTED_MESSAGE: &str =
+    "Operations are halted by an emergency stop. Resume operations first";
+
+static IS_ACTIVE: AtomicBool = AtomicBool::new(false);
+static STATUS: LazyLock<RwLock<EmergencyStopStatus>> =
+    LazyLock::new(|| RwLock::new(EmergencyStopStatus::default()));
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EmergencyStopStatus {
+    pub is_active: bool,
+    pub triggered_at: Option<u64>,
+    pub reason: Option<String>,
+}
+
+/// Panic button that halts mining and blocks anything that would start it again or move
+/// funds until the user explicitly resumes operations. Spend allowances and queued sends
+/// are dropped rather than paused.
+pub struct EmergencyStop;
+
+impl EmergencyStop {
+    pub fn is_active() -> bool {
+        IS_ACTIVE.load(Ordering::SeqCst)
+    }
+
+    pub fn ensure_inactive() -> Result<(), String> {
+        if Self::is_active() {
+            return Err(OPERATIONS_HALTED_MESSAGE.to_string());
+        }
+        Ok(())
+    }
+
+    pub async fn status() -> EmergencyStopStatus {
+        STATUS.read().await.clone()
+    }
+
+    pub async fn trigger(app_handle: &AppHandle, reason: Option<String>) -> EmergencyStopStatus {
+        // Raise the flag before stopping anything so a scheduler or tray action racing
+        // with the stop cannot start the miners again
+        let was_active = IS_ACTIVE.swap(true, Ordering::SeqCst);
+        warn!(target: LOG_TARGET, "Emergency stop triggered: {:?}", reason);
+
+        let status = {
+            let mut status = STATUS.write().await;
+            if !was_active {
+                *status = EmergencyStopStatus {
+                    is_active: true,
+                    triggered_at: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .ok()
+                        .map(|d| d.as_secs()),
+                    reason,
+                };
+            }
+            status.clone()
+        };
+
+        stop_all_miners(app_handle).await;
+        // A guest allowance or a send waiting out its review window must not survive the
+        // stop, resuming operations does not bring them back
+        SpendAllowances::revoke().await;
+        OutboundQueue::cancel_all().await;
+        EventsEmitter::emit_emergency_stop_changed(status.clone()).await;
+        status
+    }
+
+    pub async fn resume() -> EmergencyStopStatus {
+        info!(target: LOG_TARGET, "Resuming operations after emergency stop");
+        let status = {
+            let mut status = STATUS.write().await;
+            *status = EmergencyStopStatus::default();
+            IS_ACTIVE.store(false, Ordering::SeqCst);
+            status.clone()
+        };
+        EventsEmitter::emit_emergency_stop_changed(status.clone()).await;
+        status
+    }
+}
@@ -27,10 +27,14 @@ use std::{
 };
 
 use crate::{
+    airdrop::AirdropUserPoints,
     app_in_memory_config::AppInMemoryConfig,
+    configs::config_mining::MiningMode,
+    earnings_monitor::EarningsAnomalyCause,
     gpu_status_file::GpuDevice,
     node::{node_adapter::NodeIdentity, node_manager::NodeType},
     setup::setup_manager::SetupPhase,
+    utils::time_sync::SystemTimeStatus,
     wallet_adapter::{TransactionInfo, WalletBalance},
 };
 
@@ -82,6 +86,26 @@ pub enum EventType {
     AppInMemoryConfigChanged,
     DisabledPhasesChanged,
     UniversalMinerInitializedExchangeIdChanged,
+    SystemTimeDrift,
+    MiningScheduleStateChanged,
+    AirdropGemsEarned,
+    AirdropQuestCompleted,
+    AirdropReferralApplied,
+    MiningCoordinationChanged,
+    WebsocketDeliveryLag,
+    WatchedAddressReceived,
+    ClipboardAddressReplaced,
+    BinaryIntegrityViolation,
+    SetupPhaseStatusChanged,
+    ProgressDetailUpdate,
+    EarningsAnomaly,
+    TappletLifecycle,
+    TappletDeepLink,
+    EmergencyStopChanged,
+    PendingOutboundChanged,
+    PendingOutboundFailed,
+    MiningModeChanged,
+    GpuDriverCompatibility,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -203,3 +227,78 @@ pub struct DisabledPhasesPayload {
 pub struct UniversalMinerInitializedExchangeIdChangedPayload {
     pub universal_miner_initialized_exchange_id: String,
 }
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SystemTimeDriftPayload {
+    pub status: SystemTimeStatus,
+    pub max_allowed_drift_ms: i64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct MiningScheduleStateChangedPayload {
+    pub is_within_schedule: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct MiningModeChangedPayload {
+    pub previous_mode: MiningMode,
+    pub new_mode: MiningMode,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AirdropGemsEarnedPayload {
+    pub gems_earned: f64,
+    pub total_gems: f64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AirdropQuestCompletedPayload {
+    pub quest_name: String,
+    pub user_points: Option<AirdropUserPoints>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct AirdropReferralAppliedPayload {
+    pub referral_install_rewards: u32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct MiningCoordinationChangedPayload {
+    pub is_leader: bool,
+    pub leader_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WebsocketDeliveryLagPayload {
+    pub event: String,
+    pub latency_ms: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WatchedAddressReceivedPayload {
+    pub address: String,
+    pub label: Option<String>,
+    pub amount: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ClipboardAddressReplacedPayload {
+    pub original_address: String,
+    pub replaced_with: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BinaryIntegrityViolationPayload {
+    pub binary_name: String,
+    pub version: String,
+    pub expected_checksum: String,
+    pub actual_checksum: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct EarningsAnomalyPayload {
+    pub expected_daily_earnings: u64,
+    pub actual_daily_earnings: u64,
+    pub drop_percentage: f64,
+    pub probable_causes: Vec<EarningsAnomalyCause>,
+}
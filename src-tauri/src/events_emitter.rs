@@ -24,19 +24,28 @@ use crate::app_in_memory_config::AppInMemoryConfig;
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 use crate::events::{
-    AppInMemoryConfigChangedPayload, ConnectionStatusPayload, CriticalProblemPayload,
-    DisabledPhasesPayload, InitWalletScanningProgressPayload,
-    UniversalMinerInitializedExchangeIdChangedPayload,
+    AirdropGemsEarnedPayload, AirdropQuestCompletedPayload, AirdropReferralAppliedPayload,
+    AppInMemoryConfigChangedPayload, BinaryIntegrityViolationPayload,
+    ClipboardAddressReplacedPayload, ConnectionStatusPayload, CriticalProblemPayload,
+    DisabledPhasesPayload, EarningsAnomalyPayload, InitWalletScanningProgressPayload,
+    MiningCoordinationChangedPayload, MiningModeChangedPayload, MiningScheduleStateChangedPayload,
+    SystemTimeDriftPayload, UniversalMinerInitializedExchangeIdChangedPayload,
 };
 #[cfg(target_os = "windows")]
 use crate::external_dependencies::RequiredExternalDependency;
+use crate::hardware::gpu_driver_compat::GpuDriverCompatibility;
 use crate::pool_status_watcher::PoolStatus;
+use crate::progress_tracker_old::ProgressUpdate;
 use crate::{
+    airdrop::AirdropUserPoints,
     commands::CpuMinerStatus,
     configs::{
-        config_core::ConfigCoreContent, config_mining::ConfigMiningContent,
-        config_ui::ConfigUIContent, config_wallet::ConfigWalletContent,
+        config_core::ConfigCoreContent,
+        config_mining::{ConfigMiningContent, MiningMode},
+        config_ui::ConfigUIContent,
+        config_wallet::ConfigWalletContent,
     },
+    emergency_stop::EmergencyStopStatus,
     events::{
         DetectedAvailableGpuEnginesPayload, DetectedDevicesPayload, Event, EventType,
         NetworkStatusPayload, NewBlockHeightPayload, NodeTypeUpdatePayload, ProgressEvents,
@@ -44,8 +53,13 @@ use crate::{
     },
     gpu_status_file::GpuDevice,
     hardware::hardware_status_monitor::PublicDeviceProperties,
-    setup::setup_manager::SetupPhase,
-    utils::app_flow_utils::FrontendReadyChannel,
+    outbound_queue::{PendingOutbound, PendingOutboundFailure},
+    setup::setup_manager::{SetupPhase, SetupPhaseProgress},
+    tapplets::{tapplet_deep_link::TappletDeepLink, tapplet_registry::TappletStatus},
+    utils::{
+        app_flow_utils::FrontendReadyChannel,
+        time_sync::{SystemTimeStatus, MAX_ALLOWED_CLOCK_DRIFT_MS},
+    },
     wallet_adapter::{TransactionInfo, WalletBalance},
     BaseNodeStatus, GpuMinerStatus,
 };
@@ -779,4 +793,324 @@ impl EventsEmitter {
             error!(target: LOG_TARGET, "Failed to emit UniversalMinerInitializedExchangeIdChanged event: {:?}", e);
         }
     }
+
+    pub async fn emit_system_time_drift(status: SystemTimeStatus) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::SystemTimeDrift,
+            payload: SystemTimeDriftPayload {
+                status,
+                max_allowed_drift_ms: MAX_ALLOWED_CLOCK_DRIFT_MS,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit SystemTimeDrift event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_mining_schedule_state_changed(is_within_schedule: bool) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::MiningScheduleStateChanged,
+            payload: MiningScheduleStateChangedPayload { is_within_schedule },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit MiningScheduleStateChanged event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_mining_mode_changed(previous_mode: MiningMode, new_mode: MiningMode) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::MiningModeChanged,
+            payload: MiningModeChangedPayload {
+                previous_mode,
+                new_mode,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit MiningModeChanged event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_gpu_driver_compatibility(payload: GpuDriverCompatibility) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::GpuDriverCompatibility,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit GpuDriverCompatibility event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_airdrop_gems_earned(gems_earned: f64, total_gems: f64) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::AirdropGemsEarned,
+            payload: AirdropGemsEarnedPayload {
+                gems_earned,
+                total_gems,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit AirdropGemsEarned event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_airdrop_quest_completed(
+        quest_name: String,
+        user_points: Option<AirdropUserPoints>,
+    ) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::AirdropQuestCompleted,
+            payload: AirdropQuestCompletedPayload {
+                quest_name,
+                user_points,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit AirdropQuestCompleted event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_airdrop_referral_applied(referral_install_rewards: u32) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::AirdropReferralApplied,
+            payload: AirdropReferralAppliedPayload {
+                referral_install_rewards,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit AirdropReferralApplied event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_mining_coordination_changed(is_leader: bool, leader_id: Option<String>) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::MiningCoordinationChanged,
+            payload: MiningCoordinationChangedPayload {
+                is_leader,
+                leader_id,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit MiningCoordinationChanged event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_websocket_delivery_lag(event: String, latency_ms: u64) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::WebsocketDeliveryLag,
+            payload: WebsocketDeliveryLagPayload { event, latency_ms },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit WebsocketDeliveryLag event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_watched_address_received(
+        address: String,
+        label: Option<String>,
+        amount: String,
+    ) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::WatchedAddressReceived,
+            payload: WatchedAddressReceivedPayload {
+                address,
+                label,
+                amount,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit WatchedAddressReceived event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_clipboard_address_replaced(original_address: String, replaced_with: String) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::ClipboardAddressReplaced,
+            payload: ClipboardAddressReplacedPayload {
+                original_address,
+                replaced_with,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit ClipboardAddressReplaced event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_binary_integrity_violation(
+        binary_name: String,
+        version: String,
+        expected_checksum: String,
+        actual_checksum: String,
+    ) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::BinaryIntegrityViolation,
+            payload: BinaryIntegrityViolationPayload {
+                binary_name,
+                version,
+                expected_checksum,
+                actual_checksum,
+            },
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit BinaryIntegrityViolation event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_setup_phase_status_changed(payload: SetupPhaseProgress) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::SetupPhaseStatusChanged,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit SetupPhaseStatusChanged event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_progress_detail_update(payload: ProgressUpdate) {
+        let event = Event {
+            event_type: EventType::ProgressDetailUpdate,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit ProgressDetailUpdate event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_earnings_anomaly(payload: EarningsAnomalyPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::EarningsAnomaly,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit EarningsAnomaly event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_tapplet_lifecycle(payload: TappletStatus) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::TappletLifecycle,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit TappletLifecycle event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_tapplet_deep_link(payload: TappletDeepLink) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::TappletDeepLink,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit TappletDeepLink event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_emergency_stop_changed(payload: EmergencyStopStatus) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::EmergencyStopChanged,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit EmergencyStopChanged event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_pending_outbound_changed(payload: Vec<PendingOutbound>) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::PendingOutboundChanged,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit PendingOutboundChanged event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_pending_outbound_failed(payload: PendingOutboundFailure) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::PendingOutboundFailed,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit PendingOutboundFailed event: {:?}", e);
+        }
+    }
 }
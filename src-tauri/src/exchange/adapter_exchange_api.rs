@@ -0,0 +1,137 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use log::{error, info};
+use reqwest::{RequestBuilder, Response};
+use serde::Serialize;
+
+use crate::configs::{config_core::ConfigCore, trait_config::ConfigImpl};
+
+use super::{ExchangeProvider, SwapAsset, SwapInitiation, SwapQuote, SwapQuoteRequest};
+
+const LOG_TARGET: &str = "tari::universe::exchange::adapter_exchange_api";
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InitiateSwapRequest<'a> {
+    exchange_id: &'a str,
+    quote_id: &'a str,
+    destination_address: String,
+}
+
+/// Talks to the `/swaps` routes of the airdrop API, the same service that hands out the
+/// swap widget url. Every call is made on behalf of the signed in user, and a swap is
+/// started from the quote id alone so the server checks its own copy of the quote.
+pub struct ExchangeApiAdapter {
+    pub exchange_id: String,
+    pub api_base_url: String,
+}
+
+impl ExchangeApiAdapter {
+    fn swaps_url(&self, path: &str) -> String {
+        format!("{}/swaps{}", self.api_base_url.trim_end_matches('/'), path)
+    }
+
+    async fn send_authenticated(request: RequestBuilder) -> Result<Response, Error> {
+        let token = ConfigCore::content()
+            .await
+            .airdrop_tokens()
+            .clone()
+            .map(|tokens| tokens.token)
+            .ok_or_else(|| anyhow!("Sign in to the airdrop to use swaps"))?;
+        Ok(request
+            .header("Authorization", format!("Bearer {}", token))
+            .send()
+            .await?)
+    }
+}
+
+#[async_trait]
+impl ExchangeProvider for ExchangeApiAdapter {
+    fn exchange_id(&self) -> &str {
+        &self.exchange_id
+    }
+
+    fn supports_pair(&self, from_asset: SwapAsset, to_asset: SwapAsset) -> bool {
+        matches!(
+            (from_asset, to_asset),
+            (SwapAsset::Xtm, SwapAsset::Btc) | (SwapAsset::Btc, SwapAsset::Xtm)
+        )
+    }
+
+    async fn get_quote(&self, request: &SwapQuoteRequest) -> Result<SwapQuote, Error> {
+        let url = self.swaps_url("/quote");
+        info!(target: LOG_TARGET, "Requesting swap quote {} -> {} from {}", request.from_asset.symbol(), request.to_asset.symbol(), url);
+
+        let response = Self::send_authenticated(reqwest::Client::new().get(url).query(&[
+            ("exchangeId", self.exchange_id.clone()),
+            ("from", request.from_asset.symbol().to_string()),
+            ("to", request.to_asset.symbol().to_string()),
+            ("amount", request.amount.to_string()),
+        ]))
+        .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            error!(target: LOG_TARGET, "Failed to get swap quote: {} {}", status, body);
+            return Err(anyhow!("Failed to get swap quote: {}", status));
+        }
+
+        let quote: SwapQuote = response.json().await?;
+        if quote.from_asset != request.from_asset || quote.to_asset != request.to_asset {
+            return Err(anyhow!("Exchange returned a quote for a different pair"));
+        }
+        if quote.exchange_id != self.exchange_id {
+            return Err(anyhow!("Quote was issued by a different exchange"));
+        }
+        Ok(quote)
+    }
+
+    async fn initiate_swap(
+        &self,
+        quote: &SwapQuote,
+        destination_address: String,
+    ) -> Result<SwapInitiation, Error> {
+        let url = self.swaps_url("");
+        info!(target: LOG_TARGET, "Initiating swap for quote {}", quote.quote_id);
+
+        let response =
+            Self::send_authenticated(reqwest::Client::new().post(url).json(&InitiateSwapRequest {
+                exchange_id: &self.exchange_id,
+                quote_id: &quote.quote_id,
+                destination_address,
+            }))
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            error!(target: LOG_TARGET, "Failed to initiate swap: {} {}", status, body);
+            return Err(anyhow!("Failed to initiate swap: {}", status));
+        }
+
+        Ok(response.json().await?)
+    }
+}
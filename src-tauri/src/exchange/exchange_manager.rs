@@ -0,0 +1,134 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    sync::LazyLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Error};
+use log::warn;
+use tokio::sync::Mutex;
+
+use crate::{
+    app_in_memory_config::DynamicMemoryConfig,
+    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+};
+
+use super::{
+    adapter_exchange_api::ExchangeApiAdapter, ExchangeProvider, SwapInitiation, SwapQuote,
+    SwapQuoteRequest,
+};
+
+const LOG_TARGET: &str = "tari::universe::exchange::exchange_manager";
+
+/// Quotes as the exchange returned them. A swap is only started from one of these, never
+/// from a quote handed back by the frontend, so its amounts and expiry cannot be altered.
+static ISSUED_QUOTES: LazyLock<Mutex<HashMap<String, SwapQuote>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+pub struct ExchangeManager {
+    provider: Box<dyn ExchangeProvider>,
+}
+
+impl ExchangeManager {
+    pub fn from_config(config: &DynamicMemoryConfig) -> Self {
+        Self {
+            provider: Box::new(ExchangeApiAdapter {
+                exchange_id: config.exchange_id.clone(),
+                api_base_url: config.airdrop_api_url.clone(),
+            }),
+        }
+    }
+
+    pub async fn get_swap_quote(&self, request: SwapQuoteRequest) -> Result<SwapQuote, Error> {
+        if request.amount <= 0.0 || !request.amount.is_finite() {
+            return Err(anyhow!("Swap amount must be greater than zero"));
+        }
+        if !self
+            .provider
+            .supports_pair(request.from_asset, request.to_asset)
+        {
+            return Err(anyhow!(
+                "Exchange {} does not support {} -> {} swaps",
+                self.provider.exchange_id(),
+                request.from_asset.symbol(),
+                request.to_asset.symbol()
+            ));
+        }
+        let quote = self.provider.get_quote(&request).await?;
+        let mut issued_quotes = ISSUED_QUOTES.lock().await;
+        let now = unix_now();
+        issued_quotes.retain(|_, issued| issued.expires_at > now);
+        issued_quotes.insert(quote.quote_id.clone(), quote.clone());
+        Ok(quote)
+    }
+
+    /// The exchange validates the quote again when the swap is started, this only saves a
+    /// round trip for quotes that are known to be unusable
+    pub async fn initiate_swap(
+        &self,
+        quote_id: &str,
+        destination_address: String,
+    ) -> Result<SwapInitiation, Error> {
+        if !*ConfigCore::content().await.allow_exchange_swaps() {
+            warn!(target: LOG_TARGET, "Swap rejected, swaps are not allowed in settings");
+            return Err(anyhow!("Swaps are disabled. Enable them in settings first"));
+        }
+        let quote = ISSUED_QUOTES
+            .lock()
+            .await
+            .remove(quote_id)
+            .ok_or_else(|| anyhow!("Unknown swap quote, request a new one"))?;
+        if quote.exchange_id != self.provider.exchange_id() {
+            return Err(anyhow!("Quote was issued by a different exchange"));
+        }
+        if quote.expires_at <= unix_now() {
+            return Err(anyhow!("Swap quote has expired"));
+        }
+
+        let swap = self
+            .provider
+            .initiate_swap(&quote, destination_address)
+            .await?;
+        if swap.quote_id != quote.quote_id {
+            return Err(anyhow!("Exchange started a swap for a different quote"));
+        }
+        if (swap.deposit_amount - quote.from_amount).abs()
+            > f64::EPSILON * quote.from_amount.abs().max(1.0)
+        {
+            warn!(target: LOG_TARGET, "Swap {} asks for a deposit of {} but the quote was for {}", swap.swap_id, swap.deposit_amount, quote.from_amount);
+            return Err(anyhow!(
+                "Exchange asked for a different deposit than quoted"
+            ));
+        }
+        Ok(swap)
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+pub mod adapter_exchange_api;
+pub mod exchange_manager;
+
+use anyhow::Error;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SwapAsset {
+    #[serde(rename = "XTM")]
+    Xtm,
+    #[serde(rename = "BTC")]
+    Btc,
+}
+
+impl SwapAsset {
+    pub fn symbol(&self) -> &str {
+        match self {
+            SwapAsset::Xtm => "XTM",
+            SwapAsset::Btc => "BTC",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapQuoteRequest {
+    pub from_asset: SwapAsset,
+    pub to_asset: SwapAsset,
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapQuote {
+    pub quote_id: String,
+    pub exchange_id: String,
+    pub from_asset: SwapAsset,
+    pub to_asset: SwapAsset,
+    pub from_amount: f64,
+    pub to_amount: f64,
+    pub rate: f64,
+    pub fee: f64,
+    pub expires_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapInitiation {
+    pub swap_id: String,
+    pub quote_id: String,
+    /// Address the user has to send `from_asset` funds to
+    pub deposit_address: String,
+    pub deposit_amount: f64,
+    pub status: String,
+}
+
+#[async_trait]
+pub trait ExchangeProvider: Send + Sync + 'static {
+    fn exchange_id(&self) -> &str;
+
+    fn supports_pair(&self, from_asset: SwapAsset, to_asset: SwapAsset) -> bool;
+
+    async fn get_quote(&self, request: &SwapQuoteRequest) -> Result<SwapQuote, Error>;
+
+    async fn initiate_swap(
+        &self,
+        quote: &SwapQuote,
+        destination_address: String,
+    ) -> Result<SwapInitiation, Error>;
+}
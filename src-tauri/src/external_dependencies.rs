@@ -34,6 +34,8 @@ use winreg::enums::HKEY_LOCAL_MACHINE;
 #[cfg(target_os = "windows")]
 use winreg::RegKey;
 
+use crate::hardware::gpu_driver_compat::GpuDriverCompatibility;
+
 #[allow(dead_code)]
 const LOG_TARGET: &str = "tari::universe::external_dependencies";
 static INSTANCE: LazyLock<ExternalDependencies> = LazyLock::new(ExternalDependencies::new);
@@ -73,6 +75,9 @@ pub struct ExternalDependency {
 pub struct RequiredExternalDependency {
     pub additional_runtime: ExternalDependency,
     pub minimum_runtime: ExternalDependency,
+    /// Installed gpu drivers and any known incompatibility with the gpu miner
+    #[serde(default)]
+    pub gpu_drivers: Option<GpuDriverCompatibility>,
 }
 
 #[cfg(target_os = "windows")]
@@ -131,6 +136,7 @@ impl ExternalDependencies {
                     status: ExternalDependencyStatus::Unknown,
                     version: None,
                 },
+                gpu_drivers: None,
             }
         } else {
             RequiredExternalDependency {
@@ -169,6 +175,7 @@ impl ExternalDependencies {
                     status: ExternalDependencyStatus::Unknown,
                     version: None,
                 },
+                gpu_drivers: None,
             }
         }
     }
@@ -269,6 +276,10 @@ impl ExternalDependencies {
         self.external_dependencies.read().await.clone()
     }
 
+    pub async fn set_gpu_driver_compatibility(&self, gpu_drivers: GpuDriverCompatibility) {
+        self.external_dependencies.write().await.gpu_drivers = Some(gpu_drivers);
+    }
+
     #[allow(dead_code)]
     pub async fn check_if_some_dependency_is_not_installed(&self) -> bool {
         let registry_application_entry = self.get_external_dependencies().await;
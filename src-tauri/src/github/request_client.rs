@@ -23,13 +23,15 @@
 use futures::StreamExt;
 use std::path::Path;
 use std::path::PathBuf;
-use std::sync::LazyLock;
-use std::time::Duration;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 use tokio::fs;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
 
 use super::Release;
+use crate::progress_tracker_old::{ProgressTracker, ProgressUpdate};
+use crate::utils::doh_resolver::DohResolver;
 use anyhow::{anyhow, Error};
 use log::debug;
 use log::info;
@@ -41,6 +43,7 @@ use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 const LOG_TARGET: &str = "tari::universe::request_client";
 const MAX_DOWNLOAD_FILE_RETRIES: u8 = 3;
 const TIME_BETWEEN_FILE_DOWNLOADS: Duration = Duration::from_secs(15);
+const DOWNLOAD_PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
 #[allow(dead_code)]
 pub enum CloudFlareCacheStatus {
     Hit,
@@ -146,7 +149,12 @@ impl RequestClient {
         debug!(target: LOG_TARGET, "[build_retry_reqwest_client]");
         let retry_policy = ExponentialBackoff::builder().build_with_max_retries(2);
 
-        ClientBuilder::new(Client::new())
+        let client = Client::builder()
+            .dns_resolver(Arc::new(DohResolver))
+            .build()
+            .unwrap_or_default();
+
+        ClientBuilder::new(client)
             .with(RetryTransientMiddleware::new_with_policy(retry_policy))
             .build()
     }
@@ -317,6 +325,35 @@ impl RequestClient {
         url: &str,
         destination: &Path,
         check_cache: bool,
+    ) -> Result<(), anyhow::Error> {
+        self.download_file_inner(url, destination, check_cache, None)
+            .await
+    }
+
+    /// Reports transfer speed and ETA as `<step_parent>/download` progress while downloading
+    pub async fn download_file_with_progress(
+        &self,
+        url: &str,
+        destination: &Path,
+        check_cache: bool,
+        progress_tracker: &ProgressTracker,
+        step_parent: &str,
+    ) -> Result<(), anyhow::Error> {
+        self.download_file_inner(
+            url,
+            destination,
+            check_cache,
+            Some((progress_tracker, step_parent)),
+        )
+        .await
+    }
+
+    async fn download_file_inner(
+        &self,
+        url: &str,
+        destination: &Path,
+        check_cache: bool,
+        progress: Option<(&ProgressTracker, &str)>,
     ) -> Result<(), anyhow::Error> {
         if check_cache {
             //TODO (2/2) bring it back once cloudflare stops returning dynamic status
@@ -338,8 +375,41 @@ impl RequestClient {
         let mut destination_path = File::create(destination).await?;
         // Stream the response body directly to the file
         let mut stream = get_response.bytes_stream();
+        let bytes_total = Some(head_reponse_content_length).filter(|length| *length > 0);
+        let mut bytes_done: u64 = 0;
+        let mut bytes_at_last_report: u64 = 0;
+        let mut last_report = Instant::now();
         while let Some(item) = stream.next().await {
-            destination_path.write_all(&item?).await?;
+            let chunk = item?;
+            destination_path.write_all(&chunk).await?;
+            bytes_done += chunk.len() as u64;
+
+            if let Some((progress_tracker, step_parent)) = progress {
+                let elapsed = last_report.elapsed();
+                if elapsed >= DOWNLOAD_PROGRESS_INTERVAL {
+                    let bytes_per_second = u64::try_from(
+                        u128::from(bytes_done - bytes_at_last_report) * 1000
+                            / elapsed.as_millis().max(1),
+                    )
+                    .unwrap_or(u64::MAX);
+                    progress_tracker
+                        .send_progress(
+                            ProgressUpdate::new(
+                                step_parent,
+                                "download",
+                                format!("Downloading: {}", url),
+                            )
+                            .with_transfer(
+                                bytes_done,
+                                bytes_total,
+                                bytes_per_second,
+                            ),
+                        )
+                        .await;
+                    bytes_at_last_report = bytes_done;
+                    last_report = Instant::now();
+                }
+            }
         }
 
         let destination_file_size = self
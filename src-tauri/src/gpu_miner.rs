@@ -38,6 +38,8 @@ use crate::configs::config_mining::{GpuThreads, MiningMode};
 use crate::events_emitter::EventsEmitter;
 use crate::gpu_miner_adapter::GpuNodeSource;
 use crate::gpu_status_file::{GpuDevice, GpuStatusFile};
+use crate::hardware::gpu_detection_cache::{hardware_fingerprint, GpuDetectionCache};
+use crate::hardware::hardware_status_monitor::HardwareStatusMonitor;
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::math_utils::estimate_earning;
@@ -177,13 +179,57 @@ impl GpuMiner {
         lock.is_pid_file_exists(base_path)
     }
 
+    /// Reuses the previous probe result unless the hardware fingerprint changed or `force_rescan` is set
     pub async fn detect(
         &mut self,
         config_dir: PathBuf,
         engine: EngineType,
+        force_rescan: bool,
     ) -> Result<(), anyhow::Error> {
-        info!(target: LOG_TARGET, "Verify if gpu miner can work on the system");
         self.curent_selected_engine = engine;
+        let gpu_miner_version = BinaryResolver::current()
+            .read()
+            .await
+            .get_binary_version_string(Binaries::GpuMiner)
+            .await;
+        let gpu_names: Vec<String> = HardwareStatusMonitor::current()
+            .get_gpu_devices()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|device| device.public_properties.name)
+            .collect();
+        let fingerprint = tokio::task::spawn_blocking(move || {
+            hardware_fingerprint(&gpu_miner_version, &gpu_names)
+        })
+        .await?;
+        let engine_name = self.curent_selected_engine.to_string();
+
+        if force_rescan {
+            GpuDetectionCache::invalidate(&config_dir);
+        } else if let Some(cache) = GpuDetectionCache::load(&config_dir)
+            .filter(|cache| cache.matches(&fingerprint, &engine_name))
+        {
+            info!(target: LOG_TARGET, "Hardware unchanged since last gpu detection, using cached result");
+            return self
+                .apply_detection_result(config_dir, cache.is_available)
+                .await;
+        }
+
+        let result = self.probe(config_dir.clone()).await;
+        let cache = GpuDetectionCache {
+            fingerprint,
+            engine: engine_name,
+            is_available: result.is_ok(),
+        };
+        if let Err(e) = cache.save(&config_dir) {
+            warn!(target: LOG_TARGET, "Could not save gpu detection cache: {:?}", e);
+        }
+        result
+    }
+
+    async fn probe(&mut self, config_dir: PathBuf) -> Result<(), anyhow::Error> {
+        info!(target: LOG_TARGET, "Verify if gpu miner can work on the system");
 
         let config_file = config_dir
             .join("gpuminer")
@@ -217,36 +263,39 @@ impl GpuMiner {
         let output = child.wait_with_output().await?;
         info!(target: LOG_TARGET, "Gpu detect exit code: {:?}", output.status.code().unwrap_or_default());
 
+        let is_available = output.status.code() == Some(0);
+        self.apply_detection_result(config_dir, is_available)
+            .await
+            .map_err(|e| anyhow::anyhow!("{} (exit code: {:?})", e, output.status.code()))
+    }
+
+    async fn apply_detection_result(
+        &mut self,
+        config_dir: PathBuf,
+        is_available: bool,
+    ) -> Result<(), anyhow::Error> {
         let gpu_status_file_name = format!("{}_gpu_status.json", self.curent_selected_engine);
         let gpu_status_file_path =
             get_gpu_engines_statuses_path(&config_dir).join(gpu_status_file_name);
         let gpu_status_file = GpuStatusFile::load(&gpu_status_file_path)?;
 
         self.gpu_devices = gpu_status_file.gpu_devices;
-        match output.status.code() {
-            Some(0) => {
-                self.is_available = true;
-                EventsEmitter::emit_detected_available_gpu_engines(
-                    self.get_available_gpu_engines(config_dir)
-                        .await?
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect(),
-                    self.curent_selected_engine.to_string(),
-                )
-                .await;
-
-                EventsEmitter::emit_detected_devices(self.gpu_devices.clone()).await;
-                Ok(())
-            }
-            _ => {
-                self.is_available = false;
-                Err(anyhow::anyhow!(
-                    "Non-zero exit code: {:?}",
-                    output.status.code()
-                ))
-            }
+        self.is_available = is_available;
+        if !is_available {
+            return Err(anyhow::anyhow!("No gpu available for mining"));
         }
+
+        EventsEmitter::emit_detected_available_gpu_engines(
+            self.get_available_gpu_engines(config_dir)
+                .await?
+                .iter()
+                .map(|x| x.to_string())
+                .collect(),
+            self.curent_selected_engine.to_string(),
+        )
+        .await;
+        EventsEmitter::emit_detected_devices(self.gpu_devices.clone()).await;
+        Ok(())
     }
 
     pub async fn get_available_gpu_engines(
@@ -347,16 +396,44 @@ impl GpuMiner {
             gpu_device.settings.is_excluded = excluded;
         }
 
-        let path = get_gpu_engines_statuses_path(&config_dir)
+        self.save_device_settings(&config_dir)
+    }
+
+    /// Mines only on the given devices by excluding every other detected device
+    pub async fn select_devices(
+        &mut self,
+        config_dir: PathBuf,
+        device_indices: &[u32],
+    ) -> Result<(), anyhow::Error> {
+        if device_indices.is_empty() {
+            return Err(anyhow::anyhow!("At least one GPU device must be selected"));
+        }
+        if let Some(unknown) = device_indices.iter().find(|index| {
+            !self
+                .gpu_devices
+                .iter()
+                .any(|gpu_device| gpu_device.device_index == **index)
+        }) {
+            return Err(anyhow::anyhow!("Unknown GPU device index: {}", unknown));
+        }
+
+        for gpu_device in &mut self.gpu_devices {
+            gpu_device.settings.is_excluded = !device_indices.contains(&gpu_device.device_index);
+        }
+        self.save_device_settings(&config_dir)?;
+        EventsEmitter::emit_detected_devices(self.gpu_devices.clone()).await;
+        Ok(())
+    }
+
+    fn save_device_settings(&self, config_dir: &Path) -> Result<(), anyhow::Error> {
+        let path = get_gpu_engines_statuses_path(config_dir)
             .join(format!("{}_gpu_status.json", self.curent_selected_engine));
         GpuStatusFile::save(
             GpuStatusFile {
                 gpu_devices: self.gpu_devices.clone(),
             },
             &path,
-        )?;
-
-        Ok(())
+        )
     }
 
     pub async fn set_selected_engine(
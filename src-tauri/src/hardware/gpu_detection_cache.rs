@@ -0,0 +1,170 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+
+use super::gpu_driver_compat::detect_drivers;
+
+const LOG_TARGET: &str = "tari::universe::gpu_detection_cache";
+const CACHE_FILE_NAME: &str = "detection_cache.json";
+
+/// Result of the last GPU probe and the hardware it was taken on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GpuDetectionCache {
+    pub fingerprint: String,
+    pub engine: String,
+    pub is_available: bool,
+}
+
+impl GpuDetectionCache {
+    fn path(config_dir: &Path) -> PathBuf {
+        config_dir.join("gpuminer").join(CACHE_FILE_NAME)
+    }
+
+    pub fn load(config_dir: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::path(config_dir)).ok()?;
+        serde_json::from_str(&content)
+            .inspect_err(
+                |e| warn!(target: LOG_TARGET, "Ignoring unreadable gpu detection cache: {}", e),
+            )
+            .ok()
+    }
+
+    pub fn save(&self, config_dir: &Path) -> Result<(), anyhow::Error> {
+        let path = Self::path(config_dir);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn invalidate(config_dir: &Path) {
+        let path = Self::path(config_dir);
+        if path.exists() {
+            info!(target: LOG_TARGET, "Invalidating gpu detection cache");
+            let _unused = std::fs::remove_file(path);
+        }
+    }
+
+    pub fn matches(&self, fingerprint: &str, engine: &str) -> bool {
+        self.fingerprint == fingerprint && self.engine == engine
+    }
+}
+
+/// Card nodes come and go as GPUs are added or removed, even before a probe has named them
+#[cfg(target_os = "linux")]
+fn platform_gpu_markers() -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir("/sys/class/drm") else {
+        return vec![];
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("card"))
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn platform_gpu_markers() -> Vec<String> {
+    vec![]
+}
+
+/// Installed drivers with their versions, the GPUs the hardware monitor knows about and,
+/// where the platform has them, the card nodes. Sorted so enumeration order does not matter.
+fn gpu_markers(gpu_names: &[String]) -> Vec<String> {
+    let mut markers: Vec<String> = detect_drivers()
+        .into_iter()
+        .map(|driver| {
+            format!(
+                "{}|{}|{}",
+                driver.vendor, driver.description, driver.version
+            )
+        })
+        .chain(gpu_names.iter().cloned())
+        .chain(platform_gpu_markers())
+        .collect();
+    markers.sort();
+    markers
+}
+
+/// Cheap to compute compared to a probe, changes when the machine's hardware, OS, drivers
+/// or the gpu miner binary change
+pub fn hardware_fingerprint(gpu_miner_version: &str, gpu_names: &[String]) -> String {
+    let system = System::new_with_specifics(
+        RefreshKind::new()
+            .with_cpu(CpuRefreshKind::new())
+            .with_memory(MemoryRefreshKind::new().with_ram()),
+    );
+    let mut hasher = Sha256::new();
+    hasher.update(gpu_miner_version.as_bytes());
+    hasher.update(System::long_os_version().unwrap_or_default().as_bytes());
+    hasher.update(System::kernel_version().unwrap_or_default().as_bytes());
+    if let Some(cpu) = system.cpus().first() {
+        hasher.update(cpu.brand().as_bytes());
+    }
+    hasher.update(system.cpus().len().to_le_bytes());
+    hasher.update(system.total_memory().to_le_bytes());
+    for marker in gpu_markers(gpu_names) {
+        hasher.update(marker.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_only_matches_same_fingerprint_and_engine() {
+        let cache = GpuDetectionCache {
+            fingerprint: "abc".to_string(),
+            engine: "OpenCL".to_string(),
+            is_available: true,
+        };
+        assert!(cache.matches("abc", "OpenCL"));
+        assert!(!cache.matches("abc", "Cuda"));
+        assert!(!cache.matches("def", "OpenCL"));
+    }
+
+    #[test]
+    fn fingerprint_depends_on_miner_version() {
+        let gpus = vec!["NVIDIA GeForce RTX 4070".to_string()];
+        assert_eq!(
+            hardware_fingerprint("1.0.0", &gpus),
+            hardware_fingerprint("1.0.0", &gpus)
+        );
+        assert_ne!(
+            hardware_fingerprint("1.0.0", &gpus),
+            hardware_fingerprint("1.0.1", &gpus)
+        );
+        assert_ne!(
+            hardware_fingerprint("1.0.0", &gpus),
+            hardware_fingerprint("1.0.0", &[])
+        );
+    }
+}
@@ -0,0 +1,420 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{path::Path, sync::LazyLock, time::Duration};
+
+use log::{info, warn};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::{
+    binaries::{Binaries, BinaryResolver},
+    events_emitter::EventsEmitter,
+    external_dependencies::ExternalDependencies,
+    github::request_client::RequestClient,
+    gpu_miner::EngineType,
+};
+
+const LOG_TARGET: &str = "tari::universe::gpu_driver_compat";
+const COMPATIBILITY_LIST_URL: &str =
+    "https://cdn-universe.tari.com/tari-project/universe/gpu-driver-compatibility.json";
+const COMPATIBILITY_LIST_FILE_NAME: &str = "driver_compatibility.json";
+/// Hardware setup waits on the fetch, past this the cached list is used instead
+const COMPATIBILITY_LIST_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+/// In-tree kernel drivers bound to DRM devices, keyed by PCI vendor id
+#[cfg(target_os = "linux")]
+const DRM_DRIVERS: [(&str, &str); 2] = [("0x1002", "AMD"), ("0x8086", "Intel")];
+/// Device class of display adapters, each numbered subkey is one installed adapter
+#[cfg(target_os = "windows")]
+const DISPLAY_ADAPTER_CLASS_KEY: &str =
+    "SYSTEM\\CurrentControlSet\\Control\\Class\\{4d36e968-e325-11ce-bfc1-08002be10318}";
+
+static INSTANCE: LazyLock<GpuDriverCompat> = LazyLock::new(GpuDriverCompat::new);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstalledGpuDriver {
+    pub vendor: String,
+    pub description: String,
+    pub version: String,
+}
+
+/// Entry of the compatibility list, a driver matching vendor, version and engine is known
+/// to break the gpu miner
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriverCompatRule {
+    /// Matched against the driver vendor and description, case insensitive
+    pub vendor: String,
+    /// Version prefixes, `31.0.101.4` matches every `31.0.101.4xxx` build
+    pub driver_versions: Vec<String>,
+    /// Engines affected by the driver, empty means all of them
+    #[serde(default)]
+    pub engines: Vec<String>,
+    /// First gpu miner version that works around the driver issue
+    #[serde(default)]
+    pub fixed_in_miner_version: Option<String>,
+    pub reason: String,
+}
+
+impl DriverCompatRule {
+    fn matches(
+        &self,
+        driver: &InstalledGpuDriver,
+        engine: &EngineType,
+        miner_version: Option<&Version>,
+    ) -> bool {
+        let vendor = self.vendor.to_lowercase();
+        let is_vendor = driver.vendor.to_lowercase().contains(&vendor)
+            || driver.description.to_lowercase().contains(&vendor);
+        let is_version = self
+            .driver_versions
+            .iter()
+            .any(|prefix| driver.version.starts_with(prefix));
+        let engine = engine.to_string();
+        let is_engine = self.engines.is_empty()
+            || self
+                .engines
+                .iter()
+                .any(|affected| affected.eq_ignore_ascii_case(&engine));
+        let is_unfixed = match (
+            self.fixed_in_miner_version
+                .as_deref()
+                .and_then(|fixed| Version::parse(fixed).ok()),
+            miner_version,
+        ) {
+            (Some(fixed), Some(current)) => *current < fixed,
+            _ => true,
+        };
+        is_vendor && is_version && is_engine && is_unfixed
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CompatibilityList {
+    rules: Vec<DriverCompatRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpuDriverIssue {
+    pub driver: InstalledGpuDriver,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpuDriverCompatibility {
+    pub engine: EngineType,
+    pub drivers: Vec<InstalledGpuDriver>,
+    pub issues: Vec<GpuDriverIssue>,
+    pub is_gpu_mining_blocked: bool,
+}
+
+fn find_issues(
+    drivers: &[InstalledGpuDriver],
+    rules: &[DriverCompatRule],
+    engine: &EngineType,
+    miner_version: Option<&Version>,
+) -> Vec<GpuDriverIssue> {
+    drivers
+        .iter()
+        .flat_map(|driver| {
+            rules
+                .iter()
+                .filter(|rule| rule.matches(driver, engine, miner_version))
+                .map(|rule| GpuDriverIssue {
+                    driver: driver.clone(),
+                    reason: rule.reason.clone(),
+                })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn detect_drivers() -> Vec<InstalledGpuDriver> {
+    use winreg::{enums::HKEY_LOCAL_MACHINE, RegKey};
+
+    let Ok(class_key) = RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey(DISPLAY_ADAPTER_CLASS_KEY)
+    else {
+        warn!(target: LOG_TARGET, "Could not open display adapter registry key");
+        return vec![];
+    };
+    class_key
+        .enum_keys()
+        .filter_map(Result::ok)
+        .filter_map(|name| class_key.open_subkey(name).ok())
+        .filter_map(|adapter| {
+            Some(InstalledGpuDriver {
+                vendor: adapter.get_value("ProviderName").unwrap_or_default(),
+                description: adapter.get_value("DriverDesc").ok()?,
+                version: adapter.get_value("DriverVersion").ok()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn detect_drivers() -> Vec<InstalledGpuDriver> {
+    let mut drivers = vec![];
+    if let Some(version) = std::fs::read_to_string("/proc/driver/nvidia/version")
+        .ok()
+        .and_then(|content| parse_nvidia_version(&content))
+    {
+        drivers.push(InstalledGpuDriver {
+            vendor: "NVIDIA".to_string(),
+            description: "NVIDIA kernel module".to_string(),
+            version,
+        });
+    }
+    // amdgpu, i915 and xe ship with the kernel and have no module version of their own,
+    // the kernel release identifies the driver build instead
+    let kernel_release = std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .map(|release| release.trim().to_string())
+        .unwrap_or_default();
+    let Ok(cards) = std::fs::read_dir("/sys/class/drm") else {
+        return drivers;
+    };
+    for card in cards.filter_map(Result::ok) {
+        let name = card.file_name().to_string_lossy().to_string();
+        if !name.starts_with("card") || name.contains('-') {
+            continue;
+        }
+        let device = card.path().join("device");
+        let Some(vendor) = std::fs::read_to_string(device.join("vendor"))
+            .ok()
+            .and_then(|id| {
+                DRM_DRIVERS
+                    .iter()
+                    .find(|(vendor_id, _)| id.trim().eq_ignore_ascii_case(vendor_id))
+                    .map(|(_, vendor)| vendor.to_string())
+            })
+        else {
+            continue;
+        };
+        let Some(module) = std::fs::read_link(device.join("driver"))
+            .ok()
+            .and_then(|link| link.file_name().map(|m| m.to_string_lossy().to_string()))
+        else {
+            continue;
+        };
+        let driver = InstalledGpuDriver {
+            vendor,
+            description: format!("{} kernel module", module),
+            version: kernel_release.clone(),
+        };
+        if !drivers.contains(&driver) {
+            drivers.push(driver);
+        }
+    }
+    drivers
+}
+
+/// macOS ships gpu drivers with the OS, there is nothing to check separately
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub(crate) fn detect_drivers() -> Vec<InstalledGpuDriver> {
+    vec![]
+}
+
+/// Picks the version out of `NVRM version: NVIDIA UNIX x86_64 Kernel Module  550.54.14  <build date>`
+#[cfg(any(target_os = "linux", test))]
+fn parse_nvidia_version(content: &str) -> Option<String> {
+    content
+        .lines()
+        .next()?
+        .split_whitespace()
+        .find(|token| token.contains('.') && token.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        .map(str::to_string)
+}
+
+#[derive(Default)]
+struct CompatState {
+    drivers: Vec<InstalledGpuDriver>,
+    rules: Vec<DriverCompatRule>,
+    miner_version: Option<Version>,
+}
+
+/// Blocks gpu mining on driver and miner combinations known to crash or produce bad shares
+pub struct GpuDriverCompat {
+    state: RwLock<CompatState>,
+}
+
+impl GpuDriverCompat {
+    fn new() -> Self {
+        Self {
+            state: RwLock::new(CompatState::default()),
+        }
+    }
+
+    pub fn current() -> &'static GpuDriverCompat {
+        &INSTANCE
+    }
+
+    fn list_cache_path(config_dir: &Path) -> std::path::PathBuf {
+        config_dir
+            .join("gpuminer")
+            .join(COMPATIBILITY_LIST_FILE_NAME)
+    }
+
+    /// Fetches the latest list, falling back to the copy from the last successful fetch
+    async fn load_compatibility_list(config_dir: &Path) -> CompatibilityList {
+        let cache_path = Self::list_cache_path(config_dir);
+        let fetch = async {
+            RequestClient::current()
+                .send_get_request(COMPATIBILITY_LIST_URL)
+                .await?
+                .json::<CompatibilityList>()
+                .await
+                .map_err(anyhow::Error::from)
+        };
+        let fetched = match tokio::time::timeout(COMPATIBILITY_LIST_FETCH_TIMEOUT, fetch).await {
+            Ok(fetched) => fetched,
+            Err(_) => Err(anyhow::anyhow!(
+                "timed out after {:?}",
+                COMPATIBILITY_LIST_FETCH_TIMEOUT
+            )),
+        };
+        match fetched {
+            Ok(list) => {
+                if let Ok(content) = serde_json::to_string_pretty(&list) {
+                    let _unused = std::fs::write(&cache_path, content).inspect_err(|e| {
+                        warn!(target: LOG_TARGET, "Could not cache driver compatibility list: {}", e)
+                    });
+                }
+                list
+            }
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Could not fetch driver compatibility list, using cached copy: {:?}", e);
+                std::fs::read_to_string(&cache_path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str(&content).ok())
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    /// Re-reads installed drivers and the compatibility list, then reports the result for `engine`
+    pub async fn refresh(&self, config_dir: &Path, engine: &EngineType) -> GpuDriverCompatibility {
+        let list = Self::load_compatibility_list(config_dir).await;
+        let drivers = tokio::task::spawn_blocking(detect_drivers)
+            .await
+            .unwrap_or_default();
+        let miner_version = BinaryResolver::current()
+            .read()
+            .await
+            .get_binary_version(Binaries::GpuMiner)
+            .await;
+        info!(target: LOG_TARGET, "Detected gpu drivers: {:?}", drivers);
+
+        *self.state.write().await = CompatState {
+            drivers,
+            rules: list.rules,
+            miner_version,
+        };
+
+        let status = self.status(engine).await;
+        if status.is_gpu_mining_blocked {
+            warn!(target: LOG_TARGET, "Gpu mining blocked by incompatible drivers: {:?}", status.issues);
+        }
+        ExternalDependencies::current()
+            .set_gpu_driver_compatibility(status.clone())
+            .await;
+        EventsEmitter::emit_gpu_driver_compatibility(status.clone()).await;
+        status
+    }
+
+    pub async fn status(&self, engine: &EngineType) -> GpuDriverCompatibility {
+        let state = self.state.read().await;
+        let issues = find_issues(
+            &state.drivers,
+            &state.rules,
+            engine,
+            state.miner_version.as_ref(),
+        );
+        GpuDriverCompatibility {
+            engine: engine.clone(),
+            drivers: state.drivers.clone(),
+            is_gpu_mining_blocked: !issues.is_empty(),
+            issues,
+        }
+    }
+
+    pub async fn ensure_compatible(&self, engine: &EngineType) -> Result<(), String> {
+        let status = self.status(engine).await;
+        match status.issues.first() {
+            Some(issue) => Err(format!(
+                "GPU mining is disabled: {} driver {} is not compatible with the {} miner. {}",
+                issue.driver.description, issue.driver.version, engine, issue.reason
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intel_driver(version: &str) -> InstalledGpuDriver {
+        InstalledGpuDriver {
+            vendor: "Intel Corporation".to_string(),
+            description: "Intel(R) Arc(TM) A770 Graphics".to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    fn intel_rule() -> DriverCompatRule {
+        DriverCompatRule {
+            vendor: "intel".to_string(),
+            driver_versions: vec!["31.0.101.4".to_string()],
+            engines: vec!["OpenCL".to_string()],
+            fixed_in_miner_version: Some("1.2.0".to_string()),
+            reason: "Kernel compilation hangs".to_string(),
+        }
+    }
+
+    #[test]
+    fn matches_vendor_version_and_engine() {
+        let rules = [intel_rule()];
+        let drivers = [intel_driver("31.0.101.4502"), intel_driver("31.0.101.5186")];
+        let issues = find_issues(&drivers, &rules, &EngineType::OpenCL, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].driver.version, "31.0.101.4502");
+        assert!(find_issues(&drivers, &rules, &EngineType::Cuda, None).is_empty());
+    }
+
+    #[test]
+    fn fixed_miner_version_lifts_the_block() {
+        let rules = [intel_rule()];
+        let drivers = [intel_driver("31.0.101.4502")];
+        let old = Version::new(1, 1, 9);
+        let fixed = Version::new(1, 2, 0);
+        assert_eq!(
+            find_issues(&drivers, &rules, &EngineType::OpenCL, Some(&old)).len(),
+            1
+        );
+        assert!(find_issues(&drivers, &rules, &EngineType::OpenCL, Some(&fixed)).is_empty());
+    }
+
+    #[test]
+    fn parses_nvidia_kernel_module_version() {
+        let content = "NVRM version: NVIDIA UNIX x86_64 Kernel Module  550.54.14  Thu Feb 22 01:44:30 UTC 2024\nGCC version:  gcc version 12.2.0";
+        assert_eq!(parse_nvidia_version(content), Some("550.54.14".to_string()));
+    }
+}
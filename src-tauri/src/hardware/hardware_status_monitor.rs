@@ -293,7 +293,6 @@ impl HardwareStatusMonitor {
         Ok((gpu_devices, cpu_devices))
     }
 
-    #[allow(dead_code)]
     pub async fn get_gpu_devices(&self) -> Result<Vec<GpuDeviceProperties>, Error> {
         let gpu_devices = self.gpu_devices.read().await;
         Ok(gpu_devices.clone())
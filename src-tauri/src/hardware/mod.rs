@@ -23,4 +23,6 @@
 mod cpu_readers;
 mod gpu_readers;
 
+pub mod gpu_detection_cache;
+pub mod gpu_driver_compat;
 pub mod hardware_status_monitor;
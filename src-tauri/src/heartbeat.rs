@@ -0,0 +1,206 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::{info, warn};
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use tokio::{fs, select, sync::Mutex, time};
+
+use crate::{emergency_stop::EmergencyStop, tasks_tracker::TasksTrackers, UniverseAppState};
+
+const LOG_TARGET: &str = "tari::universe::heartbeat";
+const HEARTBEAT_FILE_NAME: &str = "heartbeat.json";
+/// Supervisors should treat the app as hung once `updated_at` is a few intervals old
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Process exit codes for external supervisors. 0 is a clean shutdown.
+pub const EXIT_CODE_NODE_START_FAILED: i32 = 3;
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+static LAST_STATUS: LazyLock<Mutex<Option<HeartbeatStatus>>> = LazyLock::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum HeartbeatState {
+    Running,
+    Stopped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct NodeHeartbeat {
+    is_synced: bool,
+    block_height: u64,
+    num_connections: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct WalletHeartbeat {
+    is_healthy: bool,
+    last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct MinerHeartbeat {
+    is_mining: bool,
+    hash_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct HeartbeatStatus {
+    pid: u32,
+    version: String,
+    state: HeartbeatState,
+    exit_code: Option<i32>,
+    started_at: u64,
+    updated_at: u64,
+    is_emergency_stopped: bool,
+    node: NodeHeartbeat,
+    wallet: WalletHeartbeat,
+    cpu_mining: MinerHeartbeat,
+    gpu_mining: MinerHeartbeat,
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Periodically writes a JSON status file in headless mode so systemd, monit or
+/// container probes can check on the app without talking to it.
+pub struct Heartbeat;
+
+impl Heartbeat {
+    pub fn enable_for_session() {
+        IS_ENABLED.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_enabled() -> bool {
+        IS_ENABLED.load(Ordering::SeqCst)
+    }
+
+    fn file_path(app_handle: &AppHandle) -> Option<PathBuf> {
+        app_handle
+            .path()
+            .app_local_data_dir()
+            .ok()
+            .map(|dir| dir.join(HEARTBEAT_FILE_NAME))
+    }
+
+    async fn collect(app_handle: &AppHandle, started_at: u64) -> HeartbeatStatus {
+        let state = app_handle.state::<UniverseAppState>();
+        let node_status = state.node_status_watch_rx.borrow().clone();
+        let cpu_status = state.cpu_miner_status_watch_rx.borrow().clone();
+        let gpu_status = state.gpu_latest_status.borrow().clone();
+        let wallet_health = state.wallet_manager.get_connection_health().await;
+
+        HeartbeatStatus {
+            pid: std::process::id(),
+            version: app_handle.package_info().version.to_string(),
+            state: HeartbeatState::Running,
+            exit_code: None,
+            started_at,
+            updated_at: unix_now(),
+            is_emergency_stopped: EmergencyStop::is_active(),
+            node: NodeHeartbeat {
+                is_synced: node_status.is_synced,
+                block_height: node_status.block_height,
+                num_connections: node_status.num_connections,
+            },
+            wallet: WalletHeartbeat {
+                is_healthy: wallet_health.is_healthy,
+                last_error: wallet_health.last_error,
+            },
+            cpu_mining: MinerHeartbeat {
+                is_mining: cpu_status.is_mining,
+                hash_rate: cpu_status.hash_rate,
+            },
+            gpu_mining: MinerHeartbeat {
+                is_mining: gpu_status.is_mining,
+                hash_rate: gpu_status.hash_rate,
+            },
+        }
+    }
+
+    /// Writes next to the target and renames, so readers never see a half written file
+    async fn write(path: &Path, status: &HeartbeatStatus) -> Result<(), anyhow::Error> {
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, serde_json::to_vec_pretty(status)?).await?;
+        fs::rename(&tmp_path, path).await?;
+        Ok(())
+    }
+
+    pub async fn spawn(app_handle: AppHandle) {
+        let Some(path) = Self::file_path(&app_handle) else {
+            warn!(target: LOG_TARGET, "Could not resolve heartbeat file path");
+            return;
+        };
+        info!(target: LOG_TARGET, "Writing heartbeat to {:?}", path);
+        let started_at = unix_now();
+        let mut interval = time::interval(HEARTBEAT_INTERVAL);
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => break,
+                        _ = interval.tick() => {
+                            let status = Self::collect(&app_handle, started_at).await;
+                            if let Err(e) = Self::write(&path, &status).await {
+                                warn!(target: LOG_TARGET, "Could not write heartbeat: {:?}", e);
+                            }
+                            *LAST_STATUS.lock().await = Some(status);
+                        }
+                    }
+                }
+            });
+    }
+
+    /// Final write on shutdown so a supervisor can tell a clean stop from a crash
+    pub async fn mark_stopped(app_handle: &AppHandle, exit_code: i32) {
+        let Some(path) = Self::file_path(app_handle) else {
+            return;
+        };
+        let Some(mut status) = LAST_STATUS.lock().await.take() else {
+            return;
+        };
+        status.state = HeartbeatState::Stopped;
+        status.exit_code = Some(exit_code);
+        status.updated_at = unix_now();
+        if let Err(e) = Self::write(&path, &status).await {
+            warn!(target: LOG_TARGET, "Could not write final heartbeat: {:?}", e);
+        }
+    }
+}
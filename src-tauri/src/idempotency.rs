@@ -0,0 +1,195 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+/// How long a result is replayed for the same key
+const ENTRY_TTL: Duration = Duration::from_secs(60 * 60 * 24);
+const MAX_ENTRIES: usize = 256;
+
+/// Results that can be replayed for a repeated key
+pub trait IdempotentResult: Clone {
+    /// Whether the outcome is final. Transient failures are not remembered, so retrying
+    /// after e.g. the wallet came back up runs the operation again.
+    fn is_terminal(&self) -> bool;
+}
+
+#[derive(Debug, Error)]
+#[error("The idempotency key was already used with different arguments")]
+pub struct IdempotencyKeyReused;
+
+struct Entry<T> {
+    created_at: Instant,
+    args_hash: u64,
+    result: Arc<Mutex<Option<T>>>,
+}
+
+/// Remembers the outcome of a mutating operation per caller-supplied key, so a caller
+/// that retries after a timeout gets the original result instead of running it twice.
+/// Concurrent calls with the same key wait for the first one to finish.
+pub struct IdempotencyCache<T> {
+    entries: Mutex<HashMap<String, Entry<T>>>,
+}
+
+impl<T> Default for IdempotencyCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+fn hash_args<A: Hash>(args: &A) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    args.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T: IdempotentResult> IdempotencyCache<T> {
+    /// Runs `operation` once per `key`. `args` must describe everything the operation
+    /// depends on; reusing a key with different arguments is rejected.
+    pub async fn run<A, F, Fut>(
+        &self,
+        key: Option<String>,
+        args: &A,
+        operation: F,
+    ) -> Result<T, IdempotencyKeyReused>
+    where
+        A: Hash,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let Some(key) = key else {
+            return Ok(operation().await);
+        };
+        let args_hash = hash_args(args);
+
+        let slot = {
+            let mut entries = self.entries.lock().await;
+            entries.retain(|_, entry| entry.created_at.elapsed() < ENTRY_TTL);
+            if entries.len() >= MAX_ENTRIES && !entries.contains_key(&key) {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.created_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+            let entry = entries.entry(key).or_insert_with(|| Entry {
+                created_at: Instant::now(),
+                args_hash,
+                result: Arc::new(Mutex::new(None)),
+            });
+            if entry.args_hash != args_hash {
+                return Err(IdempotencyKeyReused);
+            }
+            entry.result.clone()
+        };
+
+        // Held while the operation runs so concurrent calls with the same key wait for it
+        let mut slot = slot.lock().await;
+        if let Some(result) = slot.as_ref() {
+            return Ok(result.clone());
+        }
+        let result = operation().await;
+        if result.is_terminal() {
+            *slot = Some(result.clone());
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    impl IdempotentResult for Result<u32, u32> {
+        fn is_terminal(&self) -> bool {
+            self.is_ok()
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_original_result_for_same_key() {
+        let cache = IdempotencyCache::default();
+        let calls = AtomicU32::new(0);
+        let run = |key: Option<&str>| {
+            cache.run(key.map(ToString::to_string), &"args", || async {
+                Ok::<u32, u32>(calls.fetch_add(1, Ordering::SeqCst) + 1)
+            })
+        };
+
+        assert_eq!(run(Some("send-1")).await.ok(), Some(Ok(1)));
+        assert_eq!(run(Some("send-1")).await.ok(), Some(Ok(1)));
+        assert_eq!(run(Some("send-2")).await.ok(), Some(Ok(2)));
+        assert_eq!(run(None).await.ok(), Some(Ok(3)));
+        assert_eq!(run(None).await.ok(), Some(Ok(4)));
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn rejects_key_reuse_with_other_arguments() {
+        let cache = IdempotencyCache::<Result<u32, u32>>::default();
+        let key = Some("send-1".to_string());
+        assert!(cache
+            .run(key.clone(), &("a", 1), || async { Ok(1) })
+            .await
+            .is_ok());
+        assert!(cache.run(key, &("a", 2), || async { Ok(2) }).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn retries_transient_failures() {
+        let cache = IdempotencyCache::<Result<u32, u32>>::default();
+        let key = Some("send-1".to_string());
+        assert_eq!(
+            cache
+                .run(key.clone(), &"args", || async { Err(1) })
+                .await
+                .ok(),
+            Some(Err(1))
+        );
+        assert_eq!(
+            cache
+                .run(key.clone(), &"args", || async { Ok(2) })
+                .await
+                .ok(),
+            Some(Ok(2))
+        );
+        assert_eq!(
+            cache.run(key, &"args", || async { Ok(3) }).await.ok(),
+            Some(Ok(2))
+        );
+    }
+}
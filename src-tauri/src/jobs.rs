@@ -0,0 +1,203 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        LazyLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::{info, warn};
+use serde::Serialize;
+use tokio::{select, sync::RwLock};
+use tokio_util::sync::CancellationToken;
+
+use crate::tasks_tracker::TasksTrackers;
+
+const LOG_TARGET: &str = "tari::universe::jobs";
+/// Finished jobs kept around so callers can still read their result
+const MAX_FINISHED_JOBS: usize = 50;
+
+static INSTANCE: LazyLock<JobManager> = LazyLock::new(JobManager::default);
+static NEXT_JOB_ID: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobStatus {
+    pub id: String,
+    pub kind: String,
+    pub state: JobState,
+    pub is_cancellable: bool,
+    pub created_at: u64,
+    pub finished_at: Option<u64>,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+}
+
+struct JobEntry {
+    status: JobStatus,
+    cancel_token: CancellationToken,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Runs long operations in the background so commands can hand back a job id right away
+/// and callers poll or cancel it later.
+#[derive(Default)]
+pub struct JobManager {
+    jobs: RwLock<HashMap<String, JobEntry>>,
+}
+
+impl JobManager {
+    pub fn current() -> &'static JobManager {
+        &INSTANCE
+    }
+
+    /// Cancelling drops the job's future at its next await point, so only jobs that can be
+    /// abandoned half way without leaving things in a bad state should be cancellable.
+    pub async fn spawn<F, T>(&'static self, kind: &str, is_cancellable: bool, job: F) -> String
+    where
+        F: Future<Output = Result<T, anyhow::Error>> + Send + 'static,
+        T: Serialize,
+    {
+        let id = format!("job-{}", NEXT_JOB_ID.fetch_add(1, Ordering::SeqCst));
+        let cancel_token = CancellationToken::new();
+        let status = JobStatus {
+            id: id.clone(),
+            kind: kind.to_string(),
+            state: JobState::Running,
+            is_cancellable,
+            created_at: now_secs(),
+            finished_at: None,
+            result: None,
+            error: None,
+        };
+        self.jobs.write().await.insert(
+            id.clone(),
+            JobEntry {
+                status,
+                cancel_token: cancel_token.clone(),
+            },
+        );
+        info!(target: LOG_TARGET, "Started {} job {}", kind, id);
+
+        let job_id = id.clone();
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let outcome = select! {
+                    _ = cancel_token.cancelled() => None,
+                    result = job => Some(result),
+                };
+                self.finish(&job_id, outcome).await;
+            });
+        id
+    }
+
+    async fn finish<T: Serialize>(&self, id: &str, outcome: Option<Result<T, anyhow::Error>>) {
+        let mut jobs = self.jobs.write().await;
+        if let Some(entry) = jobs.get_mut(id) {
+            entry.status.finished_at = Some(now_secs());
+            match outcome {
+                None => entry.status.state = JobState::Cancelled,
+                Some(Ok(result)) => {
+                    entry.status.state = JobState::Completed;
+                    entry.status.result = serde_json::to_value(result).ok();
+                }
+                Some(Err(e)) => {
+                    warn!(target: LOG_TARGET, "Job {} failed: {:?}", id, e);
+                    entry.status.state = JobState::Failed;
+                    entry.status.error = Some(e.to_string());
+                }
+            }
+        }
+
+        let mut finished: Vec<(String, u64)> = jobs
+            .values()
+            .filter(|entry| entry.status.state != JobState::Running)
+            .map(|entry| {
+                (
+                    entry.status.id.clone(),
+                    entry.status.finished_at.unwrap_or_default(),
+                )
+            })
+            .collect();
+        if finished.len() > MAX_FINISHED_JOBS {
+            finished.sort_by_key(|(_, finished_at)| *finished_at);
+            for (id, _) in finished.iter().take(finished.len() - MAX_FINISHED_JOBS) {
+                jobs.remove(id);
+            }
+        }
+    }
+
+    pub async fn status(&self, id: &str) -> Option<JobStatus> {
+        self.jobs
+            .read()
+            .await
+            .get(id)
+            .map(|entry| entry.status.clone())
+    }
+
+    pub async fn list(&self) -> Vec<JobStatus> {
+        let mut jobs: Vec<JobStatus> = self
+            .jobs
+            .read()
+            .await
+            .values()
+            .map(|entry| entry.status.clone())
+            .collect();
+        jobs.sort_by_key(|job| std::cmp::Reverse(job.created_at));
+        jobs
+    }
+
+    pub async fn cancel(&self, id: &str) -> Result<(), String> {
+        let jobs = self.jobs.read().await;
+        let entry = jobs.get(id).ok_or_else(|| format!("Unknown job: {}", id))?;
+        if entry.status.state != JobState::Running {
+            return Err(format!("Job {} has already finished", id));
+        }
+        if !entry.status.is_cancellable {
+            return Err(format!("Job {} cannot be cancelled", id));
+        }
+        entry.cancel_token.cancel();
+        Ok(())
+    }
+}
@@ -28,6 +28,7 @@ use cpu_miner::CpuMinerConfig;
 use events_emitter::EventsEmitter;
 use events_manager::EventsManager;
 use gpu_miner_adapter::GpuMinerStatus;
+use heartbeat::Heartbeat;
 use log::{error, info, warn};
 use mining_status_manager::MiningStatusManager;
 use node::local_node_adapter::LocalNodeAdapter;
@@ -35,6 +36,7 @@ use node::node_adapter::BaseNodeStatus;
 use node::node_manager::NodeType;
 use p2pool::models::Connections;
 use pool_status_watcher::{PoolStatus, PoolStatusWatcher};
+use power_assertions::PowerAssertions;
 use process_stats_collector::ProcessStatsCollectorBuilder;
 
 use node::remote_node_adapter::RemoteNodeAdapter;
@@ -42,12 +44,13 @@ use node::remote_node_adapter::RemoteNodeAdapter;
 use setup::setup_manager::SetupManager;
 use std::fs::{remove_dir_all, remove_file};
 use std::path::Path;
-use systemtray_manager::SystemTrayManager;
 use tasks_tracker::TasksTrackers;
 use tauri_plugin_cli::CliExt;
+use tauri_plugin_deep_link::DeepLinkExt;
 use telemetry_service::TelemetryService;
 use tokio::sync::watch::{self};
 use tor_control_client::TorStatus;
+use tray::systemtray_manager::SystemTrayManager;
 use updates_manager::UpdatesManager;
 use utils::system_status::SystemStatus;
 use wallet_adapter::WalletState;
@@ -78,7 +81,11 @@ use telemetry_manager::TelemetryManager;
 
 use crate::cpu_miner::CpuMiner;
 
+use crate::auto_launcher::AutoLauncher;
 use crate::commands::CpuMinerConnection;
+use crate::configs::config_core::ConfigCore;
+use crate::configs::trait_config::ConfigImpl;
+use crate::demo_mode::DemoMode;
 use crate::feedback::Feedback;
 use crate::gpu_miner::GpuMiner;
 use crate::mm_proxy_manager::{MmProxyManager, StartConfig};
@@ -86,10 +93,12 @@ use crate::node::node_manager::NodeManager;
 use crate::p2pool::models::P2poolStats;
 use crate::p2pool_manager::P2poolManager;
 use crate::spend_wallet_manager::SpendWalletManager;
+use crate::tapplets::tapplet_deep_link::handle_deep_link;
 use crate::tor_manager::TorManager;
 use crate::wallet_manager::WalletManager;
 
 mod ab_test_selector;
+mod access_control;
 mod airdrop;
 mod app_in_memory_config;
 mod auto_launcher;
@@ -99,10 +108,14 @@ mod configs;
 mod consts;
 mod cpu_miner;
 mod credential_manager;
+mod demo_mode;
 mod download_utils;
+mod earnings_monitor;
+mod emergency_stop;
 mod events;
 mod events_emitter;
 mod events_manager;
+mod exchange;
 mod external_dependencies;
 mod feedback;
 mod github;
@@ -110,17 +123,26 @@ mod gpu_miner;
 mod gpu_miner_adapter;
 mod gpu_status_file;
 mod hardware;
+mod heartbeat;
+mod idempotency;
 mod internal_wallet;
+mod jobs;
+mod mining_coordinator;
+mod mining_scheduler;
 mod mining_status_manager;
+mod mining_tuner;
 mod mm_proxy_adapter;
 mod mm_proxy_manager;
 mod network_utils;
 mod node;
+mod ootle;
+mod outbound_queue;
 mod p2pool;
 mod p2pool_adapter;
 mod p2pool_manager;
 mod pool_status_watcher;
 mod port_allocator;
+mod power_assertions;
 mod process_adapter;
 mod process_adapter_utils;
 mod process_killer;
@@ -130,10 +152,12 @@ mod process_watcher;
 mod progress_tracker_old;
 mod progress_trackers;
 mod release_notes;
+mod runtime_paths;
+mod service_installer;
 mod setup;
+mod spend_allowance;
 mod spend_wallet_adapter;
 mod spend_wallet_manager;
-mod systemtray_manager;
 mod tapplets;
 mod tasks_tracker;
 mod telemetry_manager;
@@ -142,12 +166,16 @@ mod tests;
 mod tor_adapter;
 mod tor_control_client;
 mod tor_manager;
+mod tray;
 mod updates_manager;
 mod utils;
 mod wallet_adapter;
+mod wallet_backup;
+mod wallet_grpc_connection;
 mod wallet_manager;
 mod websocket_events_manager;
 mod websocket_manager;
+mod websocket_stats;
 mod xmrig;
 mod xmrig_adapter;
 
@@ -489,6 +517,7 @@ fn main() {
     )]
     let app = tauri::Builder::default()
         .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_sentry::init_with_no_injection(&client))
         .plugin(tauri_plugin_os::init())
@@ -511,6 +540,7 @@ fn main() {
                 }
             };
         }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_cli::init())
         .plugin(tauri_plugin_http::init())
@@ -542,8 +572,64 @@ fn main() {
 
             // Do this after logging has started otherwise we can't actually see any errors
             app.manage(app_state_clone);
+
+            #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+            if let Err(e) = app.deep_link().register_all() {
+                error!(target: LOG_TARGET, "Could not register deep link scheme: {:?}", e);
+            }
+            let deep_link_handle = app.handle().clone();
+            app.deep_link().on_open_url(move |event| {
+                for url in event.urls() {
+                    let app_handle = deep_link_handle.clone();
+                    tauri::async_runtime::spawn(async move {
+                        handle_deep_link(&app_handle, url).await;
+                    });
+                }
+            });
+            if let Ok(Some(urls)) = app.deep_link().get_current() {
+                for url in urls {
+                    let app_handle = app.handle().clone();
+                    tauri::async_runtime::spawn(async move {
+                        handle_deep_link(&app_handle, url).await;
+                    });
+                }
+            }
+
             match app.cli().matches() {
                 Ok(matches) => {
+                    let is_auto_launched = matches
+                        .args
+                        .get("auto-launched")
+                        .is_some_and(|arg| arg.value.as_bool() == Some(true));
+                    if is_auto_launched {
+                        AutoLauncher::current().mark_as_auto_launched();
+                    }
+                    if matches
+                        .args
+                        .get("demo")
+                        .is_some_and(|arg| arg.value.as_bool() == Some(true))
+                    {
+                        DemoMode::enable_for_session();
+                    }
+                    let should_start_hidden = is_auto_launched
+                        && block_on(ConfigCore::content())
+                            .auto_launch_options()
+                            .start_hidden;
+                    let is_headless = matches
+                        .args
+                        .get("headless")
+                        .is_some_and(|arg| arg.value.as_bool() == Some(true));
+                    if is_headless {
+                        Heartbeat::enable_for_session();
+                    }
+                    if should_start_hidden || is_headless {
+                        info!(target: LOG_TARGET, "Starting in headless mode");
+                        if let Some(window) = app.get_webview_window("main") {
+                            let _unused = window.hide().inspect_err(|e| {
+                                error!(target: LOG_TARGET, "Could not hide main window: {:?}", e)
+                            });
+                        }
+                    }
                     if let Some(backup_path) = matches.args.get("import-backup") {
                         if let Some(backup_path) = backup_path.value.as_str() {
                             info!(
@@ -647,16 +733,29 @@ fn main() {
             commands::get_app_in_memory_config,
             commands::get_applications_versions,
             commands::get_external_dependencies,
+            commands::get_explorer_urls,
             commands::get_max_consumption_levels,
             commands::get_monero_seed_words,
             commands::get_network,
             commands::get_p2pool_stats,
+            commands::get_ootle_account_balances,
+            commands::call_ootle_template,
+            commands::get_swap_quote,
+            commands::initiate_swap,
+            commands::set_allow_exchange_swaps,
             commands::get_paper_wallet_details,
             commands::get_seed_words,
+            commands::get_setup_progress,
             commands::get_tor_config,
             commands::get_tor_entry_guards,
             commands::get_transactions_history,
             commands::get_coinbase_transactions,
+            commands::get_tx_labels,
+            commands::set_tx_label,
+            commands::set_address_label,
+            commands::get_watched_addresses,
+            commands::add_watched_address,
+            commands::remove_watched_address,
             commands::import_seed_words,
             commands::log_web_message,
             commands::open_log_dir,
@@ -664,6 +763,8 @@ fn main() {
             commands::restart_application,
             commands::send_feedback,
             commands::set_allow_telemetry,
+            commands::set_allow_airdrop_participation,
+            commands::get_airdrop_status,
             commands::send_data_telemetry_service,
             commands::set_application_language,
             commands::set_auto_update,
@@ -682,6 +783,7 @@ fn main() {
             commands::set_show_experimental_settings,
             commands::set_should_always_use_system_language,
             commands::set_should_auto_launch,
+            commands::set_auto_launch_options,
             commands::set_tor_config,
             commands::set_use_tor,
             commands::set_visual_mode,
@@ -690,6 +792,7 @@ fn main() {
             commands::stop_cpu_mining,
             commands::stop_gpu_mining,
             commands::update_applications,
+            commands::check_connectivity,
             commands::get_p2pool_connections,
             commands::set_p2pool_stats_server_port,
             commands::get_used_p2pool_stats_server_port,
@@ -708,6 +811,7 @@ fn main() {
             commands::stop_mining_status,
             commands::websocket_connect,
             commands::websocket_close,
+            commands::get_websocket_stats,
             commands::reconnect,
             commands::send_one_sided_to_stealth_address,
             commands::verify_address_for_send,
@@ -716,6 +820,7 @@ fn main() {
             commands::set_node_type,
             commands::set_warmup_seen,
             commands::set_allow_notifications,
+            commands::set_clipboard_protection_enabled,
             commands::launch_builtin_tapplet,
             commands::get_tari_wallet_address,
             commands::get_tari_wallet_balance,
@@ -723,6 +828,68 @@ fn main() {
             commands::parse_tari_address,
             commands::refresh_wallet_history,
             commands::get_universal_miner_initialized_exchange_id,
+            commands::get_system_time_status,
+            commands::get_timezone_info,
+            commands::set_timezone,
+            commands::set_mining_schedule,
+            commands::get_coordination_status,
+            commands::set_dns_over_https,
+            commands::set_lan_coordination,
+            commands::install_headless_service,
+            commands::uninstall_headless_service,
+            commands::backup_wallet_database,
+            commands::restore_wallet_database,
+            commands::export_chain_snapshot,
+            commands::import_chain_snapshot,
+            commands::set_fast_sync,
+            commands::rescan_hardware,
+            commands::pause_mining,
+            commands::resume_mining,
+            commands::get_tuning_recommendations,
+            commands::get_wallet_connection_health,
+            commands::get_tapplets_status,
+            commands::report_tapplet_crash,
+            commands::reload_tapplet,
+            commands::report_tapplet_load_failure,
+            commands::get_tapplet_storage_usage,
+            commands::clear_tapplet_data,
+            commands::read_tapplet_data,
+            commands::write_tapplet_data,
+            commands::set_tapplet_storage_quota,
+            commands::install_tapplet,
+            commands::emergency_stop,
+            commands::resume_operations,
+            commands::get_emergency_stop_status,
+            commands::start_chain_snapshot_export_job,
+            commands::start_orphan_chain_check_job,
+            commands::get_job_status,
+            commands::list_jobs,
+            commands::cancel_job,
+            commands::get_access_role,
+            commands::set_admin_pin,
+            commands::unlock_admin,
+            commands::lock_admin,
+            commands::grant_spend_allowance,
+            commands::revoke_spend_allowance,
+            commands::get_spend_allowance,
+            commands::get_pending_outbound,
+            commands::cancel_pending_outbound,
+            commands::set_outbound_review_minutes,
+            commands::get_config_migrations,
+            commands::get_pending_config_migrations,
+            commands::set_gpu_engine,
+            commands::get_runtime_paths,
+            commands::set_p2pool_squad,
+            commands::restart_node,
+            commands::resync_node,
+            commands::get_gpu_driver_compatibility,
+            commands::get_tor_status,
+            commands::get_power_assertion_status,
+            commands::set_prevent_sleep_while_mining,
+            commands::set_disable_app_nap,
+            commands::get_wallet_backup_status,
+            commands::get_daily_earnings,
+            commands::export_earnings_report,
         ])
         .build(tauri::generate_context!())
         .inspect_err(|e| {
@@ -755,10 +922,15 @@ fn main() {
                 info!(target: LOG_TARGET, "RunEvent Ready");
                 let handle_clone = app_handle.clone();
                 tauri::async_runtime::spawn(async move {
+                    AutoLauncher::current().wait_for_launch_delay().await;
                     SetupManager::get_instance()
                         .start_setup(handle_clone.clone())
                         .await;
                     SetupManager::spawn_sleep_mode_handler(handle_clone.clone()).await;
+                    if Heartbeat::is_enabled() {
+                        Heartbeat::spawn(handle_clone.clone()).await;
+                    }
+                    PowerAssertions::spawn(handle_clone.clone()).await;
                 });
             }
             tauri::RunEvent::ExitRequested { api: _, code, .. } => {
@@ -778,6 +950,9 @@ fn main() {
                     }
                 }
                 block_on(TasksTrackers::current().stop_all_processes());
+                if Heartbeat::is_enabled() {
+                    block_on(Heartbeat::mark_stopped(app_handle, code.unwrap_or(0)));
+                }
                 info!(target: LOG_TARGET, "App shutdown complete");
             }
             tauri::RunEvent::Exit => {
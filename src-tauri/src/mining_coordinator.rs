@@ -0,0 +1,389 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+    thread::available_parallelism,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use log::{debug, error, info, warn};
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tokio::{net::UdpSocket, select, sync::RwLock, time};
+
+use crate::{
+    configs::{config_core::ConfigCore, config_mining::ConfigMining, trait_config::ConfigImpl},
+    events_emitter::EventsEmitter,
+    hardware::hardware_status_monitor::HardwareStatusMonitor,
+    mining_scheduler::MiningScheduler,
+    tasks_tracker::TasksTrackers,
+    utils::mining_utils::{start_all_miners, stop_all_miners},
+};
+
+const LOG_TARGET: &str = "tari::universe::mining_coordinator";
+const COORDINATION_PORT: u16 = 18_199;
+const ANNOUNCE_INTERVAL: Duration = Duration::from_secs(10);
+const PEER_TIMEOUT: Duration = Duration::from_secs(35);
+const GPU_CAPABILITY_WEIGHT: u32 = 100;
+/// Announcements older or newer than this are dropped so captured packets can't be replayed later
+const MAX_ANNOUNCEMENT_AGE: Duration = Duration::from_secs(60);
+
+static IS_RUNNING: AtomicBool = AtomicBool::new(false);
+static COORDINATION_STATE: LazyLock<RwLock<CoordinationState>> =
+    LazyLock::new(|| RwLock::new(CoordinationState::default()));
+
+/// What each instance broadcasts to the rest of the household
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoordinationPeer {
+    pub instance_id: String,
+    pub group_name: String,
+    pub capability_score: u32,
+    pub is_in_electricity_window: bool,
+}
+
+/// Signed wrapper sent over the wire. The mac covers the timestamp and the serialized peer.
+#[derive(Debug, Serialize, Deserialize)]
+struct CoordinationEnvelope {
+    timestamp: u64,
+    payload: String,
+    mac: String,
+}
+
+impl CoordinationEnvelope {
+    fn signing_input(timestamp: u64, payload: &str) -> Vec<u8> {
+        let mut input = timestamp.to_be_bytes().to_vec();
+        input.extend_from_slice(payload.as_bytes());
+        input
+    }
+
+    fn seal(peer: &CoordinationPeer, key: &hmac::Key) -> Result<Self, serde_json::Error> {
+        let timestamp = unix_timestamp();
+        let payload = serde_json::to_string(peer)?;
+        let tag = hmac::sign(key, &Self::signing_input(timestamp, &payload));
+        Ok(Self {
+            timestamp,
+            payload,
+            mac: hex::encode(tag.as_ref()),
+        })
+    }
+
+    fn open(self, key: &hmac::Key) -> Option<CoordinationPeer> {
+        if unix_timestamp().abs_diff(self.timestamp) > MAX_ANNOUNCEMENT_AGE.as_secs() {
+            return None;
+        }
+        let mac = hex::decode(&self.mac).ok()?;
+        hmac::verify(
+            key,
+            &Self::signing_input(self.timestamp, &self.payload),
+            &mac,
+        )
+        .ok()?;
+        serde_json::from_str(&self.payload).ok()
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[derive(Default)]
+struct CoordinationState {
+    peers: HashMap<String, (CoordinationPeer, Instant)>,
+    leader_id: Option<String>,
+    /// Set while local miners are stopped because another instance was elected. The
+    /// coordinator only ever restarts miners it stopped itself.
+    deferring_to_peer: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CoordinationStatus {
+    pub enabled: bool,
+    pub instance_id: String,
+    pub is_leader: bool,
+    pub leader_id: Option<String>,
+    pub peers: Vec<CoordinationPeer>,
+}
+
+/// Picks the instance that should mine. Instances inside their electricity window win over
+/// the ones outside of it, then the most capable hardware wins. Ties are broken by the
+/// lowest instance id so every instance reaches the same result.
+pub fn elect_leader(candidates: &[CoordinationPeer]) -> Option<&CoordinationPeer> {
+    candidates.iter().min_by(|a, b| {
+        b.is_in_electricity_window
+            .cmp(&a.is_in_electricity_window)
+            .then(b.capability_score.cmp(&a.capability_score))
+            .then(a.instance_id.cmp(&b.instance_id))
+    })
+}
+
+pub struct MiningCoordinator;
+
+impl MiningCoordinator {
+    async fn capability_score() -> u32 {
+        let cpu_threads = available_parallelism()
+            .map(|threads| u32::try_from(threads.get()).unwrap_or(u32::MAX))
+            .unwrap_or(1);
+        let gpu_count = HardwareStatusMonitor::current()
+            .get_gpu_devices()
+            .await
+            .map(|devices| u32::try_from(devices.len()).unwrap_or(u32::MAX))
+            .unwrap_or(0);
+        cpu_threads.saturating_add(gpu_count.saturating_mul(GPU_CAPABILITY_WEIGHT))
+    }
+
+    async fn local_announcement() -> CoordinationPeer {
+        CoordinationPeer {
+            instance_id: ConfigCore::content().await.anon_id().clone(),
+            group_name: ConfigMining::content()
+                .await
+                .lan_coordination()
+                .group_name
+                .clone(),
+            capability_score: Self::capability_score().await,
+            is_in_electricity_window: MiningScheduler::is_within_schedule().await.unwrap_or(true),
+        }
+    }
+
+    pub async fn get_status() -> CoordinationStatus {
+        let enabled = ConfigMining::content().await.lan_coordination().enabled;
+        let instance_id = ConfigCore::content().await.anon_id().clone();
+        let state = COORDINATION_STATE.read().await;
+        CoordinationStatus {
+            enabled,
+            is_leader: state.leader_id.as_ref() == Some(&instance_id),
+            instance_id,
+            leader_id: state.leader_id.clone(),
+            peers: state.peers.values().map(|(peer, _)| peer.clone()).collect(),
+        }
+    }
+
+    /// Whether local miners are currently held off because another instance is mining
+    pub async fn is_deferring_to_peer() -> bool {
+        COORDINATION_STATE.read().await.deferring_to_peer
+    }
+
+    async fn handle_peer_message(message: &[u8], local: &CoordinationPeer, key: &hmac::Key) {
+        match serde_json::from_slice::<CoordinationEnvelope>(message) {
+            Ok(envelope) => {
+                let Some(peer) = envelope.open(key) else {
+                    debug!(target: LOG_TARGET, "Ignoring unauthenticated coordination message");
+                    return;
+                };
+                if peer.group_name != local.group_name || peer.instance_id == local.instance_id {
+                    return;
+                }
+                COORDINATION_STATE
+                    .write()
+                    .await
+                    .peers
+                    .insert(peer.instance_id.clone(), (peer, Instant::now()));
+            }
+            Err(e) => {
+                debug!(target: LOG_TARGET, "Ignoring invalid coordination message: {}", e);
+            }
+        }
+    }
+
+    async fn run_election(app_handle: &AppHandle, local: CoordinationPeer) {
+        let (new_leader_id, was_deferring) = {
+            let mut state = COORDINATION_STATE.write().await;
+            state
+                .peers
+                .retain(|_, (_, last_seen)| last_seen.elapsed() < PEER_TIMEOUT);
+
+            let mut candidates: Vec<CoordinationPeer> =
+                state.peers.values().map(|(peer, _)| peer.clone()).collect();
+            candidates.push(local.clone());
+            let new_leader_id = elect_leader(&candidates).map(|peer| peer.instance_id.clone());
+
+            if state.leader_id == new_leader_id {
+                return;
+            }
+            state.leader_id.clone_from(&new_leader_id);
+            let was_deferring = state.deferring_to_peer;
+            state.deferring_to_peer = new_leader_id.as_ref() != Some(&local.instance_id);
+            (new_leader_id, was_deferring)
+        };
+
+        // Electing ourselves only hands mining back if we previously stopped it for a peer.
+        // Otherwise starting and stopping stays with the user and the mining scheduler.
+        let is_leader = new_leader_id.as_ref() == Some(&local.instance_id);
+        if is_leader {
+            info!(target: LOG_TARGET, "This instance was elected to mine");
+            if was_deferring {
+                Self::resume_local_miners(app_handle).await;
+            }
+        } else if !was_deferring {
+            info!(target: LOG_TARGET, "Instance {:?} was elected to mine. Stopping local miners", new_leader_id);
+            stop_all_miners(app_handle).await;
+        }
+        EventsEmitter::emit_mining_coordination_changed(is_leader, new_leader_id).await;
+    }
+
+    async fn resume_local_miners(app_handle: &AppHandle) {
+        if MiningScheduler::is_within_schedule().await == Some(false) {
+            info!(target: LOG_TARGET, "Outside of the mining schedule. Leaving local miners stopped");
+            return;
+        }
+        info!(target: LOG_TARGET, "Resuming local miners");
+        start_all_miners(app_handle).await;
+    }
+
+    /// Starts announcing this instance on the LAN and following the elected leader.
+    /// Does nothing when coordination is disabled or already running.
+    pub async fn spawn(app_handle: AppHandle) {
+        let lan_coordination = ConfigMining::content().await.lan_coordination().clone();
+        if !lan_coordination.enabled {
+            return;
+        }
+        if lan_coordination.shared_secret.is_empty() {
+            warn!(target: LOG_TARGET, "LAN coordination is enabled without a shared secret. Not starting");
+            return;
+        }
+        if IS_RUNNING.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let key = hmac::Key::new(hmac::HMAC_SHA256, lan_coordination.shared_secret.as_bytes());
+
+        let socket = match UdpSocket::bind((Ipv4Addr::UNSPECIFIED, COORDINATION_PORT)).await {
+            Ok(socket) => socket,
+            Err(e) => {
+                error!(target: LOG_TARGET, "Could not bind coordination socket: {}", e);
+                IS_RUNNING.store(false, Ordering::SeqCst);
+                return;
+            }
+        };
+        if let Err(e) = socket.set_broadcast(true) {
+            error!(target: LOG_TARGET, "Could not enable broadcast on coordination socket: {}", e);
+            IS_RUNNING.store(false, Ordering::SeqCst);
+            return;
+        }
+        let broadcast_address = SocketAddr::from((Ipv4Addr::BROADCAST, COORDINATION_PORT));
+
+        let mut interval = time::interval(ANNOUNCE_INTERVAL);
+        let mut shutdown_signal = TasksTrackers::current().mining_phase.get_signal().await;
+        TasksTrackers::current()
+            .mining_phase
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut buffer = [0u8; 1024];
+                let mut local = MiningCoordinator::local_announcement().await;
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received. Stopping mining coordinator.");
+                            break;
+                        }
+                        _ = interval.tick() => {
+                            if !ConfigMining::content().await.lan_coordination().enabled {
+                                info!(target: LOG_TARGET, "LAN coordination disabled. Stopping mining coordinator.");
+                                break;
+                            }
+                            local = MiningCoordinator::local_announcement().await;
+                            match CoordinationEnvelope::seal(&local, &key).and_then(|envelope| serde_json::to_vec(&envelope)) {
+                                Ok(message) => {
+                                    if let Err(e) = socket.send_to(&message, broadcast_address).await {
+                                        warn!(target: LOG_TARGET, "Could not broadcast coordination announcement: {}", e);
+                                    }
+                                }
+                                Err(e) => error!(target: LOG_TARGET, "Could not serialize coordination announcement: {}", e),
+                            }
+                            MiningCoordinator::run_election(&app_handle, local.clone()).await;
+                        }
+                        Ok((size, _)) = socket.recv_from(&mut buffer) => {
+                            MiningCoordinator::handle_peer_message(&buffer[..size], &local, &key).await;
+                        }
+                    }
+                }
+                let was_deferring = std::mem::take(&mut *COORDINATION_STATE.write().await).deferring_to_peer;
+                if was_deferring {
+                    MiningCoordinator::resume_local_miners(&app_handle).await;
+                }
+                IS_RUNNING.store(false, Ordering::SeqCst);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer(instance_id: &str, capability_score: u32, in_window: bool) -> CoordinationPeer {
+        CoordinationPeer {
+            instance_id: instance_id.to_string(),
+            group_name: "home".to_string(),
+            capability_score,
+            is_in_electricity_window: in_window,
+        }
+    }
+
+    #[test]
+    fn test_elect_leader_prefers_electricity_window() {
+        let candidates = vec![peer("a", 500, false), peer("b", 10, true)];
+        assert_eq!(
+            elect_leader(&candidates).map(|p| p.instance_id.as_str()),
+            Some("b")
+        );
+    }
+
+    #[test]
+    fn test_elect_leader_prefers_capability_then_id() {
+        let candidates = vec![
+            peer("c", 10, true),
+            peer("b", 200, true),
+            peer("a", 200, true),
+        ];
+        assert_eq!(
+            elect_leader(&candidates).map(|p| p.instance_id.as_str()),
+            Some("a")
+        );
+    }
+
+    #[test]
+    fn test_elect_leader_empty() {
+        assert!(elect_leader(&[]).is_none());
+    }
+
+    #[test]
+    fn test_envelope_rejects_wrong_secret() {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"correct horse battery");
+        let other_key = hmac::Key::new(hmac::HMAC_SHA256, b"wrong horse battery");
+        let local = peer("a", 10, true);
+
+        let envelope = CoordinationEnvelope::seal(&local, &key).expect("sealable");
+        assert_eq!(envelope.open(&key), Some(local.clone()));
+
+        let envelope = CoordinationEnvelope::seal(&local, &key).expect("sealable");
+        assert!(envelope.open(&other_key).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use log::{info, warn};
+use tauri::AppHandle;
+use tokio::{select, time};
+
+use crate::{
+    configs::{config_mining::ConfigMining, trait_config::ConfigImpl},
+    events_emitter::EventsEmitter,
+    mining_coordinator::MiningCoordinator,
+    tasks_tracker::TasksTrackers,
+    utils::{
+        mining_utils::{start_all_miners, stop_all_miners},
+        timezone_utils::{is_time_in_window, parse_time_of_day, AppTimezone},
+    },
+};
+
+const LOG_TARGET: &str = "tari::universe::mining_scheduler";
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+pub struct MiningScheduler;
+
+impl MiningScheduler {
+    /// Returns `None` when there is no active schedule, otherwise whether mining is allowed now
+    /// in the configured timezone.
+    pub async fn is_within_schedule() -> Option<bool> {
+        let schedule = ConfigMining::content().await.mining_schedule().clone()?;
+        if !schedule.enabled {
+            return None;
+        }
+
+        let (start, end) = match (
+            parse_time_of_day(&schedule.start_time),
+            parse_time_of_day(&schedule.end_time),
+        ) {
+            (Ok(start), Ok(end)) => (start, end),
+            (Err(e), _) | (_, Err(e)) => {
+                warn!(target: LOG_TARGET, "Invalid mining schedule: {:?}", e);
+                return None;
+            }
+        };
+
+        let now = AppTimezone::current().await.now_time();
+        Some(is_time_in_window(now, start, end))
+    }
+
+    async fn apply_schedule_state(app_handle: &AppHandle, should_mine: bool) {
+        if should_mine && MiningCoordinator::is_deferring_to_peer().await {
+            info!(target: LOG_TARGET, "Entering scheduled mining window, but another LAN instance is mining");
+        } else if should_mine {
+            info!(target: LOG_TARGET, "Entering scheduled mining window. Starting miners");
+            start_all_miners(app_handle).await;
+        } else {
+            info!(target: LOG_TARGET, "Leaving scheduled mining window. Stopping miners");
+            stop_all_miners(app_handle).await;
+        }
+        EventsEmitter::emit_mining_schedule_state_changed(should_mine).await;
+    }
+
+    pub async fn spawn(app_handle: AppHandle) {
+        let mut interval = time::interval(SCHEDULE_CHECK_INTERVAL);
+        let mut shutdown_signal = TasksTrackers::current().mining_phase.get_signal().await;
+        TasksTrackers::current()
+            .mining_phase
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut last_state: Option<bool> = None;
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received. Stopping mining scheduler.");
+                            break;
+                        }
+                        _ = interval.tick() => {
+                            let current_state = MiningScheduler::is_within_schedule().await;
+                            if let Some(should_mine) = current_state {
+                                if last_state != Some(should_mine) {
+                                    MiningScheduler::apply_schedule_state(&app_handle, should_mine).await;
+                                }
+                            }
+                            last_state = current_state;
+                        }
+                    }
+                }
+            });
+    }
+}
@@ -123,10 +123,11 @@ impl MiningStatusManager {
                         .clone()
                         .map(|handle| handle.package_info().version.clone().to_string());
 
-                    let jwt_token = ConfigCore::content()
-                        .await
+                    let config = ConfigCore::content().await;
+                    let jwt_token = config
                         .airdrop_tokens()
                         .clone()
+                        .filter(|_| *config.allow_airdrop_participation())
                         .map(|tokens| tokens.token);
                     let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
 
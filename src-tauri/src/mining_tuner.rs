@@ -0,0 +1,339 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::configs::{
+    config_mining::{ConfigMining, ConfigMiningContent, MiningMode},
+    trait_config::ConfigImpl,
+};
+
+const LOG_TARGET: &str = "tari::universe::mining_tuner";
+/// History is kept in memory and only written back to the mining config this often, or sooner
+/// when a thread count becomes trusted or the learned presets change
+const PERSIST_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// A thread count needs this many samples before it is trusted for a preset
+const MIN_SAMPLES_FOR_PRESET: u64 = 120;
+/// Eco presets never use more than this share of the available threads
+const ECO_MAX_THREAD_PERCENTAGE: u32 = 30;
+/// Ludicrous only tries thread counts this far below the maximum, as a fraction of it
+const LUDICROUS_EXPLORATION_FRACTIONS: [u32; 3] = [16, 8, 4];
+
+static INSTANCE: LazyLock<MiningTuner> = LazyLock::new(MiningTuner::default);
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ThreadSample {
+    pub threads: u32,
+    pub average_hashrate: f64,
+    pub samples: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct LearnedCpuPresets {
+    pub eco_threads: Option<u32>,
+    /// Only set when fewer threads than the machine has measured faster than using all of them
+    pub ludicrous_threads: Option<u32>,
+}
+
+/// Hashrate history per CPU thread count, kept in the mining config across restarts
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct CpuTuning {
+    pub samples: Vec<ThreadSample>,
+    pub learned: LearnedCpuPresets,
+}
+
+fn eco_thread_cap(max_threads: u32) -> u32 {
+    ((ECO_MAX_THREAD_PERCENTAGE * max_threads) / 100).max(1)
+}
+
+/// Thread counts a preset may try out, largest first so the usual count is measured first. Eco
+/// stays under its cap, ludicrous only steps a little below the maximum, so exploring never
+/// costs much hashrate.
+fn exploration_candidates(mode: &MiningMode, max_threads: u32) -> Vec<u32> {
+    match mode {
+        MiningMode::Eco => (1..=eco_thread_cap(max_threads)).rev().collect(),
+        MiningMode::Ludicrous => {
+            let mut candidates: Vec<u32> = LUDICROUS_EXPLORATION_FRACTIONS
+                .iter()
+                .map(|fraction| max_threads.saturating_sub((max_threads / fraction).max(1)))
+                .filter(|threads| *threads > 0)
+                .chain([max_threads])
+                .collect();
+            candidates.sort_unstable_by(|a, b| b.cmp(a));
+            candidates.dedup();
+            candidates
+        }
+        MiningMode::Custom => vec![],
+    }
+}
+
+impl CpuTuning {
+    /// Returns true when this sample makes the thread count trusted
+    pub fn add_sample(&mut self, threads: u32, hashrate: f64) -> bool {
+        match self.samples.iter_mut().find(|s| s.threads == threads) {
+            Some(sample) => {
+                let count = sample.samples as f64;
+                sample.average_hashrate =
+                    (sample.average_hashrate * count + hashrate) / (count + 1.0);
+                sample.samples += 1;
+                sample.samples == MIN_SAMPLES_FOR_PRESET
+            }
+            None => {
+                self.samples.push(ThreadSample {
+                    threads,
+                    average_hashrate: hashrate,
+                    samples: 1,
+                });
+                MIN_SAMPLES_FOR_PRESET <= 1
+            }
+        }
+    }
+
+    fn sample_count(&self, threads: u32) -> u64 {
+        self.samples
+            .iter()
+            .find(|s| s.threads == threads)
+            .map_or(0, |s| s.samples)
+    }
+
+    /// The least measured candidate that is not trusted yet, none once all of them are
+    pub fn next_exploration(&self, candidates: &[u32]) -> Option<u32> {
+        candidates
+            .iter()
+            .copied()
+            .filter(|threads| self.sample_count(*threads) < MIN_SAMPLES_FOR_PRESET)
+            .min_by_key(|threads| self.sample_count(*threads))
+    }
+
+    pub fn relearn(&mut self, max_threads: u32) {
+        let trusted: Vec<&ThreadSample> = self
+            .samples
+            .iter()
+            .filter(|s| s.samples >= MIN_SAMPLES_FOR_PRESET && s.average_hashrate > 0.0)
+            .collect();
+        let eco_cap = eco_thread_cap(max_threads);
+
+        let eco_threads = trusted
+            .iter()
+            .filter(|s| s.threads <= eco_cap)
+            .max_by(|a, b| {
+                (a.average_hashrate / f64::from(a.threads))
+                    .total_cmp(&(b.average_hashrate / f64::from(b.threads)))
+            })
+            .map(|s| s.threads);
+        let ludicrous_threads = trusted
+            .iter()
+            .max_by(|a, b| a.average_hashrate.total_cmp(&b.average_hashrate))
+            .map(|s| s.threads)
+            .filter(|threads| *threads < max_threads);
+
+        self.learned = LearnedCpuPresets {
+            eco_threads,
+            ludicrous_threads,
+        };
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TuningRecommendations {
+    pub max_threads: u32,
+    pub learned: LearnedCpuPresets,
+    pub samples: Vec<ThreadSample>,
+}
+
+#[derive(Default)]
+struct TunerState {
+    /// Loaded from the mining config on first use
+    tuning: Option<CpuTuning>,
+    persisted_at: Option<Instant>,
+}
+
+#[derive(Default)]
+pub struct MiningTuner {
+    state: Mutex<TunerState>,
+}
+
+impl MiningTuner {
+    pub fn current() -> &'static MiningTuner {
+        &INSTANCE
+    }
+
+    pub fn max_threads() -> u32 {
+        std::thread::available_parallelism()
+            .map(|threads| u32::try_from(threads.get()).unwrap_or(1))
+            .unwrap_or(1)
+    }
+
+    async fn tuning(&self) -> CpuTuning {
+        let state = self.state.lock().await;
+        match &state.tuning {
+            Some(tuning) => tuning.clone(),
+            None => ConfigMining::content().await.cpu_tuning().clone(),
+        }
+    }
+
+    pub async fn record_cpu_sample(&self, threads: u32, hashrate: f64) {
+        if threads == 0 || hashrate <= 0.0 {
+            return;
+        }
+        let mut state = self.state.lock().await;
+        if state.tuning.is_none() {
+            state.tuning = Some(ConfigMining::content().await.cpu_tuning().clone());
+            state.persisted_at = Some(Instant::now());
+        }
+        let Some(cpu_tuning) = state.tuning.as_mut() else {
+            return;
+        };
+        let previous = cpu_tuning.learned.clone();
+        let became_trusted = cpu_tuning.add_sample(threads, hashrate);
+        cpu_tuning.relearn(Self::max_threads());
+        let has_learned_changed = cpu_tuning.learned != previous;
+        if has_learned_changed {
+            info!(target: LOG_TARGET, "Learned cpu presets updated: {:?}", cpu_tuning.learned);
+        }
+        let cpu_tuning = cpu_tuning.clone();
+        let is_due = state
+            .persisted_at
+            .is_none_or(|persisted_at| persisted_at.elapsed() >= PERSIST_INTERVAL);
+        if !(has_learned_changed || became_trusted || is_due) {
+            return;
+        }
+        state.persisted_at = Some(Instant::now());
+        drop(state);
+
+        if let Err(e) =
+            ConfigMining::update_field(ConfigMiningContent::set_cpu_tuning, cpu_tuning).await
+        {
+            warn!(target: LOG_TARGET, "Failed to store cpu tuning history: {:?}", e);
+        }
+    }
+
+    pub async fn learned_cpu_presets(&self) -> LearnedCpuPresets {
+        self.tuning().await.learned
+    }
+
+    /// Thread count to measure next for `mode`, until every candidate has enough samples
+    pub async fn cpu_threads_to_explore(&self, mode: &MiningMode) -> Option<u32> {
+        let candidates = exploration_candidates(mode, Self::max_threads());
+        let threads = self.tuning().await.next_exploration(&candidates);
+        if let Some(threads) = threads {
+            info!(target: LOG_TARGET, "Measuring {:?} mode with {} cpu threads", mode, threads);
+        }
+        threads
+    }
+
+    pub async fn recommendations(&self) -> TuningRecommendations {
+        let cpu_tuning = self.tuning().await;
+        TuningRecommendations {
+            max_threads: Self::max_threads(),
+            learned: cpu_tuning.learned,
+            samples: cpu_tuning.samples,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tuning_with(samples: &[(u32, f64)]) -> CpuTuning {
+        CpuTuning {
+            samples: samples
+                .iter()
+                .map(|(threads, average_hashrate)| ThreadSample {
+                    threads: *threads,
+                    average_hashrate: *average_hashrate,
+                    samples: MIN_SAMPLES_FOR_PRESET,
+                })
+                .collect(),
+            learned: LearnedCpuPresets::default(),
+        }
+    }
+
+    #[test]
+    fn add_sample_keeps_running_average() {
+        let mut tuning = CpuTuning::default();
+        tuning.add_sample(4, 100.0);
+        tuning.add_sample(4, 200.0);
+        assert_eq!(tuning.samples.len(), 1);
+        assert_eq!(tuning.samples[0].samples, 2);
+        assert!((tuning.samples[0].average_hashrate - 150.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn eco_prefers_most_efficient_thread_count_under_cap() {
+        let mut tuning = tuning_with(&[(2, 300.0), (4, 500.0), (16, 1200.0)]);
+        tuning.relearn(16);
+        assert_eq!(tuning.learned.eco_threads, Some(2));
+    }
+
+    #[test]
+    fn ludicrous_only_set_when_fewer_threads_are_faster() {
+        let mut tuning = tuning_with(&[(12, 1300.0), (16, 1200.0)]);
+        tuning.relearn(16);
+        assert_eq!(tuning.learned.ludicrous_threads, Some(12));
+
+        let mut tuning = tuning_with(&[(12, 1100.0), (16, 1200.0)]);
+        tuning.relearn(16);
+        assert_eq!(tuning.learned.ludicrous_threads, None);
+    }
+
+    #[test]
+    fn explores_least_measured_candidate_until_all_are_trusted() {
+        let mut tuning = tuning_with(&[(16, 1200.0)]);
+        tuning.add_sample(15, 1150.0);
+        assert_eq!(tuning.next_exploration(&[12, 14, 15, 16]), Some(12));
+
+        let tuning = tuning_with(&[(12, 1100.0), (16, 1200.0)]);
+        assert_eq!(tuning.next_exploration(&[12, 16]), None);
+    }
+
+    #[test]
+    fn exploration_stays_near_the_preset() {
+        assert_eq!(
+            exploration_candidates(&MiningMode::Eco, 16),
+            vec![4, 3, 2, 1]
+        );
+        assert_eq!(
+            exploration_candidates(&MiningMode::Ludicrous, 16),
+            vec![16, 15, 14, 12]
+        );
+        assert!(exploration_candidates(&MiningMode::Custom, 16).is_empty());
+    }
+
+    #[test]
+    fn ignores_thread_counts_without_enough_samples() {
+        let mut tuning = tuning_with(&[(4, 500.0)]);
+        tuning.samples[0].samples = 1;
+        tuning.relearn(16);
+        assert_eq!(tuning.learned, LearnedCpuPresets::default());
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::anyhow;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tokio::fs;
+
+use crate::{
+    download_utils::{calculate_checksum, compress_dir_to_gz, unpack_gz_with_manifest},
+    runtime_paths::ensure_same_network,
+    utils::file_utils::swap_in_dir,
+};
+
+const LOG_TARGET: &str = "tari::universe::chain_snapshot";
+const CHECKSUM_EXTENSION: &str = "sha256";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainSnapshotInfo {
+    pub snapshot_file: PathBuf,
+    pub checksum: String,
+    pub block_height: u64,
+}
+
+/// Written into the archive so an import can tell which network the chain belongs to
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotManifest {
+    network: String,
+    block_height: u64,
+    created_at: u64,
+}
+
+pub fn chain_data_dir(base_path: &Path) -> PathBuf {
+    base_path
+        .join("node")
+        .join(Network::get_current().to_string().to_lowercase())
+        .join("data")
+}
+
+fn checksum_file_path(snapshot_file: &Path) -> PathBuf {
+    let mut file_name = snapshot_file.as_os_str().to_owned();
+    file_name.push(format!(".{}", CHECKSUM_EXTENSION));
+    PathBuf::from(file_name)
+}
+
+/// Checksum files use the `sha256sum` layout so they can also be checked by hand
+fn parse_checksum_file(contents: &str) -> Option<String> {
+    contents
+        .split_whitespace()
+        .next()
+        .map(|checksum| checksum.to_lowercase())
+}
+
+/// The node has to be stopped before exporting, otherwise the database may be mid-write
+pub async fn export_snapshot(
+    base_path: &Path,
+    destination_dir: &Path,
+    block_height: u64,
+) -> Result<ChainSnapshotInfo, anyhow::Error> {
+    let data_dir = chain_data_dir(base_path);
+    if !data_dir.exists() {
+        return Err(anyhow!("No local chain data found at {:?}", data_dir));
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)?
+        .as_secs();
+    let file_name = format!(
+        "tari-chain-snapshot-{}-{}-{}.tar.gz",
+        Network::get_current().as_key_str(),
+        block_height,
+        timestamp
+    );
+    let snapshot_file = destination_dir.join(file_name);
+    let manifest = serde_json::to_vec(&SnapshotManifest {
+        network: Network::get_current().as_key_str().to_string(),
+        block_height,
+        created_at: timestamp,
+    })?;
+    compress_dir_to_gz(&data_dir, Some(manifest), &snapshot_file).await?;
+
+    let checksum = calculate_checksum(&snapshot_file).await?;
+    let snapshot_name = snapshot_file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    fs::write(
+        checksum_file_path(&snapshot_file),
+        format!("{}  {}\n", checksum, snapshot_name),
+    )
+    .await?;
+
+    info!(target: LOG_TARGET, "Exported chain snapshot at height {} to {:?}", block_height, snapshot_file);
+    Ok(ChainSnapshotInfo {
+        snapshot_file,
+        checksum,
+        block_height,
+    })
+}
+
+fn unpack_snapshot(
+    snapshot_file: &Path,
+    import_dir: &Path,
+) -> Result<SnapshotManifest, anyhow::Error> {
+    let reader = std::io::BufReader::new(std::fs::File::open(snapshot_file)?);
+    let manifest = unpack_gz_with_manifest(reader, import_dir)?.ok_or_else(|| {
+        anyhow!("This snapshot has no manifest so its network cannot be verified")
+    })?;
+    Ok(serde_json::from_slice(&manifest)?)
+}
+
+/// Uses `expected_checksum` when given, otherwise the `.sha256` file shipped next to the snapshot
+pub async fn import_snapshot(
+    base_path: &Path,
+    snapshot_file: &Path,
+    expected_checksum: Option<String>,
+) -> Result<(), anyhow::Error> {
+    let expected_checksum = match expected_checksum {
+        Some(checksum) => checksum.trim().to_lowercase(),
+        None => {
+            let contents = fs::read_to_string(checksum_file_path(snapshot_file))
+                .await
+                .map_err(|_| {
+                    anyhow!("No checksum provided and no checksum file found next to the snapshot")
+                })?;
+            parse_checksum_file(&contents).ok_or_else(|| anyhow!("Checksum file is empty"))?
+        }
+    };
+
+    let actual_checksum = calculate_checksum(snapshot_file).await?;
+    if actual_checksum != expected_checksum {
+        warn!(target: LOG_TARGET, "Chain snapshot checksum mismatch. Expected {}, got {}", expected_checksum, actual_checksum);
+        return Err(anyhow!("Chain snapshot checksum does not match"));
+    }
+
+    let data_dir = chain_data_dir(base_path);
+    let import_dir = data_dir.with_extension("importing");
+    if import_dir.exists() {
+        fs::remove_dir_all(&import_dir).await?;
+    }
+    let (source, unpack_dir) = (snapshot_file.to_path_buf(), import_dir.clone());
+    let unpacked = tokio::task::spawn_blocking(move || unpack_snapshot(&source, &unpack_dir))
+        .await?
+        .and_then(|manifest| {
+            ensure_same_network(&manifest.network)?;
+            info!(target: LOG_TARGET, "Importing chain snapshot taken at height {}", manifest.block_height);
+            Ok(())
+        });
+    if let Err(e) = unpacked {
+        let _unused = fs::remove_dir_all(&import_dir).await;
+        return Err(anyhow!("Failed to import chain snapshot: {}", e));
+    }
+
+    swap_in_dir(&import_dir, &data_dir).await?;
+    info!(target: LOG_TARGET, "Imported chain snapshot from {:?}", snapshot_file);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_file_sits_next_to_snapshot() {
+        let snapshot = PathBuf::from("/tmp/tari-chain-snapshot-mainnet-100-1.tar.gz");
+        assert_eq!(
+            checksum_file_path(&snapshot),
+            PathBuf::from("/tmp/tari-chain-snapshot-mainnet-100-1.tar.gz.sha256")
+        );
+    }
+
+    #[test]
+    fn parses_sha256sum_format() {
+        assert_eq!(
+            parse_checksum_file("ABCDEF  tari-chain-snapshot.tar.gz\n"),
+            Some("abcdef".to_string())
+        );
+        assert_eq!(parse_checksum_file("   \n"), None);
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::Path;
+
+use anyhow::anyhow;
+use base64::prelude::*;
+use log::{info, warn};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde::Deserialize;
+use tari_common::configuration::Network;
+use tokio::fs;
+
+use crate::configs::config_core::FastSync;
+use crate::github::request_client::RequestClient;
+use crate::node::chain_snapshot::{chain_data_dir, import_snapshot};
+use crate::progress_tracker_old::{ProgressTracker, ProgressUpdate};
+
+const LOG_TARGET: &str = "tari::universe::fast_sync";
+
+/// Published by mirrors at `<mirror>/<network>/latest.json`, next to the snapshot archive
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnapshotManifest {
+    pub network: String,
+    pub block_height: u64,
+    pub file_name: String,
+    pub sha256: String,
+    /// Base64 ed25519 signature over [`SnapshotManifest::signed_message`]
+    pub signature: String,
+}
+
+impl SnapshotManifest {
+    pub fn signed_message(&self) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            self.network,
+            self.block_height,
+            self.file_name,
+            self.sha256.to_lowercase()
+        )
+    }
+
+    pub fn verify(&self, trusted_public_key: &str) -> Result<(), anyhow::Error> {
+        let public_key = BASE64_STANDARD
+            .decode(trusted_public_key.trim())
+            .map_err(|_| anyhow!("Fast-sync public key is not valid base64"))?;
+        let signature = BASE64_STANDARD
+            .decode(self.signature.trim())
+            .map_err(|_| anyhow!("Snapshot signature is not valid base64"))?;
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(self.signed_message().as_bytes(), &signature)
+            .map_err(|_| anyhow!("Snapshot signature does not match the trusted key"))?;
+
+        if self.network != Network::get_current().as_key_str() {
+            return Err(anyhow!(
+                "Snapshot is for network {}, expected {}",
+                self.network,
+                Network::get_current().as_key_str()
+            ));
+        }
+        if self.file_name.contains(['/', '\\']) || self.file_name.contains("..") {
+            return Err(anyhow!("Snapshot file name is not a plain file name"));
+        }
+        Ok(())
+    }
+}
+
+fn mirror_url(mirror: &str, path: &str) -> String {
+    format!(
+        "{}/{}/{}",
+        mirror.trim_end_matches('/'),
+        Network::get_current().as_key_str(),
+        path
+    )
+}
+
+async fn sync_from_mirror(
+    base_path: &Path,
+    mirror: &str,
+    trusted_public_key: &str,
+    progress_tracker: &ProgressTracker,
+) -> Result<u64, anyhow::Error> {
+    let manifest = RequestClient::current()
+        .send_get_request(&mirror_url(mirror, "latest.json"))
+        .await?
+        .json::<SnapshotManifest>()
+        .await?;
+    manifest.verify(trusted_public_key)?;
+
+    let download_dir = base_path.join("node").join("fast_sync");
+    fs::create_dir_all(&download_dir).await?;
+    let snapshot_file = download_dir.join(&manifest.file_name);
+    RequestClient::current()
+        .download_file_with_progress(
+            &mirror_url(mirror, &manifest.file_name),
+            &snapshot_file,
+            false,
+            progress_tracker,
+            "fast_sync",
+        )
+        .await?;
+
+    progress_tracker
+        .send_progress(ProgressUpdate::new(
+            "fast_sync",
+            "import",
+            format!(
+                "Importing chain snapshot at height {}",
+                manifest.block_height
+            ),
+        ))
+        .await;
+    let result = import_snapshot(base_path, &snapshot_file, Some(manifest.sha256.clone())).await;
+    let _unused = fs::remove_dir_all(&download_dir).await;
+    result.map(|_| manifest.block_height)
+}
+
+/// Only runs on a node without chain data. Any failure leaves the node to sync from scratch as usual
+pub async fn try_fast_sync(
+    base_path: &Path,
+    config: &FastSync,
+    progress_tracker: &ProgressTracker,
+) {
+    if !config.enabled || chain_data_dir(base_path).exists() {
+        return;
+    }
+    if config.trusted_public_key.trim().is_empty() || config.mirrors.is_empty() {
+        warn!(target: LOG_TARGET, "Fast-sync is enabled but no mirrors or trusted key are configured");
+        return;
+    }
+
+    warn!(
+        target: LOG_TARGET,
+        "Fast-sync enabled: chain history below the snapshot height is trusted from the snapshot signer instead of being verified by this node"
+    );
+    for mirror in &config.mirrors {
+        match sync_from_mirror(
+            base_path,
+            mirror,
+            &config.trusted_public_key,
+            progress_tracker,
+        )
+        .await
+        {
+            Ok(block_height) => {
+                info!(target: LOG_TARGET, "Fast-synced to height {} from {}", block_height, mirror);
+                return;
+            }
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Fast-sync from {} failed: {:?}", mirror, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    fn signed_manifest(file_name: &str) -> Option<(SnapshotManifest, String)> {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).ok()?;
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).ok()?;
+        let mut manifest = SnapshotManifest {
+            network: Network::get_current().as_key_str().to_string(),
+            block_height: 1000,
+            file_name: file_name.to_string(),
+            sha256: "ABCD".to_string(),
+            signature: String::new(),
+        };
+        manifest.signature =
+            BASE64_STANDARD.encode(key_pair.sign(manifest.signed_message().as_bytes()));
+        Some((
+            manifest,
+            BASE64_STANDARD.encode(key_pair.public_key().as_ref()),
+        ))
+    }
+
+    #[test]
+    fn accepts_manifest_signed_by_trusted_key() {
+        let Some((manifest, public_key)) = signed_manifest("snapshot.tar.gz") else {
+            panic!("could not sign manifest");
+        };
+        assert!(manifest.verify(&public_key).is_ok());
+    }
+
+    #[test]
+    fn rejects_tampered_manifest() {
+        let Some((mut manifest, public_key)) = signed_manifest("snapshot.tar.gz") else {
+            panic!("could not sign manifest");
+        };
+        manifest.block_height += 1;
+        assert!(manifest.verify(&public_key).is_err());
+    }
+
+    #[test]
+    fn rejects_path_in_file_name() {
+        let Some((manifest, public_key)) = signed_manifest("../snapshot.tar.gz") else {
+            panic!("could not sign manifest");
+        };
+        assert!(manifest.verify(&public_key).is_err());
+    }
+}
@@ -78,6 +78,7 @@ pub(crate) struct LocalNodeAdapter {
     pub(crate) tor_control_port: Option<u16>,
     required_initial_peers: u32,
     pub(crate) ab_test_group: ABTestSelector,
+    peer_seeds: Vec<String>,
 }
 
 impl LocalNodeAdapter {
@@ -93,6 +94,7 @@ impl LocalNodeAdapter {
             use_tor: false,
             tor_control_port: None,
             ab_test_group: ABTestSelector::GroupA,
+            peer_seeds: vec![],
         }
     }
 
@@ -153,6 +155,10 @@ impl NodeAdapter for LocalNodeAdapter {
     fn set_ab_group(&mut self, ab_test_group: ABTestSelector) {
         self.ab_test_group = ab_test_group;
     }
+
+    fn set_peer_seeds(&mut self, peer_seeds: Vec<String>) {
+        self.peer_seeds = peer_seeds;
+    }
 }
 
 impl ProcessAdapter for LocalNodeAdapter {
@@ -341,6 +347,15 @@ impl ProcessAdapter for LocalNodeAdapter {
             }
         }
 
+        if !self.peer_seeds.is_empty() {
+            args.push("-p".to_string());
+            args.push(format!(
+                "{key}.p2p.seeds.peer_seeds={seeds}",
+                key = Network::get_current_or_user_setting_or_default().as_key_str(),
+                seeds = self.peer_seeds.join(","),
+            ));
+        }
+
         // AB testing
         if self.ab_test_group == ABTestSelector::GroupB {
             info!(target: LOG_TARGET, "Using AB test group B");
@@ -20,7 +20,10 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod chain_snapshot;
+pub mod fast_sync;
 pub mod local_node_adapter;
 pub mod node_adapter;
+pub mod node_client;
 pub mod node_manager;
 pub mod remote_node_adapter;
@@ -21,32 +21,26 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::ab_test_selector::ABTestSelector;
+use crate::node::node_client::{GrpcNodeClient, NodeClient};
 use crate::node::node_manager::NodeType;
 use crate::process_adapter::{HealthStatus, StatusMonitor};
-use anyhow::{anyhow, Error};
+use anyhow::Error;
 use async_trait::async_trait;
-use minotari_node_grpc_client::grpc::{
-    BlockHeader, Empty, GetBlocksRequest, GetNetworkStateRequest, SyncState,
-};
+use minotari_node_grpc_client::grpc::SyncState;
 use std::path::PathBuf;
-use std::time::{Duration, SystemTime};
+use std::time::Duration;
 use tari_utilities::epoch_time::EpochTime;
 use tokio::fs;
 
-use chrono::{NaiveDateTime, TimeZone, Utc};
 use log::{error, info, warn};
-use minotari_node_grpc_client::BaseNodeGrpcClient;
 use serde::Serialize;
 use std::collections::HashMap;
-use std::fmt::Write as _;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tari_common::configuration::Network;
 use tari_core::transactions::tari_amount::MicroMinotari;
 use tari_crypto::ristretto::RistrettoPublicKey;
 use tari_shutdown::ShutdownSignal;
-use tari_utilities::hex::Hex;
-use tari_utilities::ByteArray;
 use tokio::sync::watch;
 use tokio::time::timeout;
 
@@ -63,92 +57,43 @@ pub trait NodeAdapter {
     fn use_tor(&mut self, use_tor: bool);
     fn set_tor_control_port(&mut self, tor_control_port: Option<u16>);
     fn set_ab_group(&mut self, ab_group: ABTestSelector);
+    fn set_peer_seeds(&mut self, peer_seeds: Vec<String>);
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct NodeAdapterService {
-    connection_address: String,
+    client: Arc<dyn NodeClient>,
     required_sync_peers: u32,
 }
 
 impl NodeAdapterService {
     pub fn new(connection_address: String, required_sync_peers: u32) -> Self {
+        Self::with_client(
+            Arc::new(GrpcNodeClient::new(connection_address)),
+            required_sync_peers,
+        )
+    }
+
+    pub fn with_client(client: Arc<dyn NodeClient>, required_sync_peers: u32) -> Self {
         Self {
-            connection_address,
+            client,
             required_sync_peers,
         }
     }
 
     pub async fn get_network_state(&self) -> Result<BaseNodeStatus, NodeStatusMonitorError> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone())
-            .await
-            .map_err(|_| NodeStatusMonitorError::NodeNotStarted)?;
-
-        let res = client
-            .get_network_state(GetNetworkStateRequest {})
-            .await
-            .map_err(|e| NodeStatusMonitorError::UnknownError(e.into()))?;
-        let res = res.into_inner();
-        let metadata = match res.metadata {
-            Some(metadata) => metadata,
-            None => {
-                return Err(NodeStatusMonitorError::UnknownError(anyhow!(
-                    "No metadata found"
-                )));
-            }
-        };
-
-        Ok(BaseNodeStatus {
-            sha_network_hashrate: res.sha3x_estimated_hash_rate,
-            tari_randomx_network_hashrate: res.tari_randomx_estimated_hash_rate,
-            monero_randomx_network_hashrate: res.monero_randomx_estimated_hash_rate,
-            block_reward: MicroMinotari(res.reward),
-            block_height: metadata.best_block_height,
-            block_time: metadata.timestamp,
-            is_synced: res.initial_sync_achieved,
-            num_connections: res.num_connections,
-        })
+        self.client.network_state().await
     }
 
     pub async fn get_historical_blocks(
         &self,
         heights: Vec<u64>,
     ) -> Result<Vec<(u64, String)>, Error> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
-
-        let mut res = client
-            .get_blocks(GetBlocksRequest { heights })
-            .await?
-            .into_inner();
-
-        let mut blocks: Vec<(u64, String)> = Vec::new();
-        while let Some(block) = res.message().await? {
-            let BlockHeader { height, hash, .. } = block
-                .block
-                .clone()
-                .expect("Failed to get block data")
-                .header
-                .expect("Failed to get block header data");
-            let hash: String = hash.iter().fold(String::new(), |mut acc, x| {
-                write!(acc, "{:02x}", x).expect("Unable to write");
-                acc
-            });
-
-            blocks.push((height, hash));
-        }
-        Ok(blocks)
+        self.client.block_hashes(heights).await
     }
 
     pub async fn get_identity(&self) -> Result<NodeIdentity, Error> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
-        let id = client.identify(Empty {}).await?;
-        let res = id.into_inner();
-
-        Ok(NodeIdentity {
-            public_key: RistrettoPublicKey::from_canonical_bytes(&res.public_key)
-                .map_err(|e| anyhow!(e.to_string()))?,
-            public_addresses: res.public_addresses,
-        })
+        self.client.identity().await
     }
 
     #[allow(clippy::too_many_lines)]
@@ -158,31 +103,17 @@ impl NodeAdapterService {
         progress_percentage_tx: &watch::Sender<f64>,
         shutdown_signal: ShutdownSignal,
     ) -> Result<u64, NodeStatusMonitorError> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone())
-            .await
-            .map_err(|_e| NodeStatusMonitorError::NodeNotStarted)?;
-
         loop {
             if shutdown_signal.is_triggered() {
                 return Ok(0);
             }
 
-            let tip = client
-                .get_tip_info(Empty {})
-                .await
-                .map_err(|e| NodeStatusMonitorError::UnknownError(e.into()))?;
-            let sync_progress = client
-                .get_sync_progress(Empty {})
-                .await
-                .map_err(|e| NodeStatusMonitorError::UnknownError(e.into()))?;
-
-            let tip_res = tip.into_inner();
-            let sync_progress = sync_progress.into_inner();
+            let sync_progress = self.client.sync_progress().await?;
 
             let mut progress_params: HashMap<String, String> = HashMap::new();
             let mut percentage = 0f64;
 
-            if sync_progress.state == SyncState::Startup as i32 {
+            if sync_progress.state == Some(SyncState::Startup) {
                 percentage = sync_progress.initial_connected_peers as f64
                     / f64::from(self.required_sync_peers);
                 progress_params.insert("step".to_string(), "Startup".to_string());
@@ -195,7 +126,7 @@ impl NodeAdapterService {
                     self.required_sync_peers.to_string(),
                 );
             }
-            if sync_progress.state == SyncState::Header as i32 {
+            if sync_progress.state == Some(SyncState::Header) {
                 percentage = sync_progress.local_height as f64 / sync_progress.tip_height as f64;
                 progress_params.insert("step".to_string(), "Header".to_string());
                 progress_params.insert(
@@ -221,7 +152,7 @@ impl NodeAdapterService {
                     sync_progress.tip_height.to_string(),
                 );
             }
-            if sync_progress.state == SyncState::Block as i32 {
+            if sync_progress.state == Some(SyncState::Block) {
                 percentage = sync_progress.local_height as f64 / sync_progress.tip_height as f64;
                 progress_params.insert("step".to_string(), "Block".to_string());
                 progress_params.insert(
@@ -254,18 +185,9 @@ impl NodeAdapterService {
             progress_percentage_tx.send(percentage).ok();
             progress_params_tx.send(progress_params).ok();
 
-            if tip_res.initial_sync_achieved
-                && tip_res
-                    .metadata
-                    .clone()
-                    .is_some_and(|metadata| metadata.best_block_height > 0)
-            {
+            if sync_progress.initial_sync_achieved && sync_progress.best_block_height > 0 {
                 info!(target: LOG_TARGET, "Initial sync achieved");
-                let tip_height = match tip_res.metadata {
-                    Some(metadata) => metadata.best_block_height,
-                    None => 0,
-                };
-                return Ok(tip_height);
+                return Ok(sync_progress.best_block_height);
             }
 
             tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
@@ -273,37 +195,7 @@ impl NodeAdapterService {
     }
 
     pub async fn list_connected_peers(&self) -> Result<Vec<String>, anyhow::Error> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
-        let peers_list = client
-            .list_connected_peers(Empty {})
-            .await
-            .map_err(|e| anyhow::anyhow!("Error list_connected_peers: {}", e))?
-            .into_inner()
-            .connected_peers;
-
-        let connected_peers = peers_list
-            .iter()
-            .filter(|peer| {
-                let since = match NaiveDateTime::parse_from_str(
-                    peer.addresses[0].last_seen.as_str(),
-                    "%Y-%m-%d %H:%M:%S%.f",
-                ) {
-                    Ok(datetime) => datetime,
-                    Err(_e) => {
-                        return false;
-                    }
-                };
-                let since = Utc.from_utc_datetime(&since);
-                let duration = SystemTime::now()
-                    .duration_since(since.into())
-                    .unwrap_or_default();
-                duration.as_secs() < 60
-            })
-            .cloned()
-            .map(|peer| peer.addresses[0].address.to_hex())
-            .collect::<Vec<String>>();
-
-        Ok(connected_peers)
+        self.client.connected_peers().await
     }
 
     pub async fn check_if_is_orphan_chain(&self) -> Result<bool, anyhow::Error> {
@@ -0,0 +1,355 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    fmt::{Debug, Write as _},
+    time::SystemTime,
+};
+
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use log::warn;
+use minotari_node_grpc_client::{
+    grpc::{BlockHeader, Empty, GetBlocksRequest, GetNetworkStateRequest, SyncState},
+    BaseNodeGrpcClient,
+};
+use tari_core::transactions::tari_amount::MicroMinotari;
+use tari_crypto::ristretto::RistrettoPublicKey;
+use tari_utilities::{hex::Hex, ByteArray};
+use tokio::sync::Mutex;
+use tonic::{transport::Channel, Code, Status};
+
+use crate::node::node_adapter::{BaseNodeStatus, NodeIdentity, NodeStatusMonitorError};
+
+const LOG_TARGET: &str = "tari::universe::node_client";
+/// Peers not seen within this window are not reported as connected
+const CONNECTED_PEER_MAX_AGE_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NodeSyncProgress {
+    /// None when the node reports a state this app does not know about
+    pub state: Option<SyncState>,
+    pub local_height: u64,
+    pub tip_height: u64,
+    pub initial_connected_peers: u64,
+    pub initial_sync_achieved: bool,
+    pub best_block_height: u64,
+}
+
+/// The base node calls the app relies on. The gRPC implementation talks to a running
+/// minotari node; the mock one serves canned data for tests.
+#[async_trait]
+pub(crate) trait NodeClient: Debug + Send + Sync {
+    async fn network_state(&self) -> Result<BaseNodeStatus, NodeStatusMonitorError>;
+    async fn sync_progress(&self) -> Result<NodeSyncProgress, NodeStatusMonitorError>;
+    async fn block_hashes(&self, heights: Vec<u64>) -> Result<Vec<(u64, String)>, Error>;
+    async fn identity(&self) -> Result<NodeIdentity, Error>;
+    async fn connected_peers(&self) -> Result<Vec<String>, Error>;
+}
+
+/// The channel to the node is reused between calls and dropped whenever the transport
+/// fails, so the next call reconnects.
+#[derive(Debug)]
+pub(crate) struct GrpcNodeClient {
+    connection_address: String,
+    client: Mutex<Option<BaseNodeGrpcClient<Channel>>>,
+}
+
+impl GrpcNodeClient {
+    pub fn new(connection_address: String) -> Self {
+        Self {
+            connection_address,
+            client: Mutex::new(None),
+        }
+    }
+
+    async fn client(&self) -> Result<BaseNodeGrpcClient<Channel>, tonic::transport::Error> {
+        let mut cached = self.client.lock().await;
+        if let Some(client) = cached.as_ref() {
+            return Ok(client.clone());
+        }
+        let client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        *cached = Some(client.clone());
+        Ok(client)
+    }
+
+    /// Drops the cached channel when `result` failed on the transport rather than in the node
+    async fn checked<T>(&self, result: Result<T, Status>) -> Result<T, Status> {
+        if let Err(status) = &result {
+            if matches!(status.code(), Code::Unavailable | Code::Unknown) {
+                self.client.lock().await.take();
+            }
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl NodeClient for GrpcNodeClient {
+    async fn network_state(&self) -> Result<BaseNodeStatus, NodeStatusMonitorError> {
+        let mut client = self
+            .client()
+            .await
+            .map_err(|_| NodeStatusMonitorError::NodeNotStarted)?;
+
+        let res = self
+            .checked(client.get_network_state(GetNetworkStateRequest {}).await)
+            .await
+            .map_err(|e| NodeStatusMonitorError::UnknownError(e.into()))?
+            .into_inner();
+        let Some(metadata) = res.metadata else {
+            return Err(NodeStatusMonitorError::UnknownError(anyhow!(
+                "No metadata found"
+            )));
+        };
+
+        Ok(BaseNodeStatus {
+            sha_network_hashrate: res.sha3x_estimated_hash_rate,
+            tari_randomx_network_hashrate: res.tari_randomx_estimated_hash_rate,
+            monero_randomx_network_hashrate: res.monero_randomx_estimated_hash_rate,
+            block_reward: MicroMinotari(res.reward),
+            block_height: metadata.best_block_height,
+            block_time: metadata.timestamp,
+            is_synced: res.initial_sync_achieved,
+            num_connections: res.num_connections,
+        })
+    }
+
+    async fn sync_progress(&self) -> Result<NodeSyncProgress, NodeStatusMonitorError> {
+        let mut client = self
+            .client()
+            .await
+            .map_err(|_| NodeStatusMonitorError::NodeNotStarted)?;
+
+        let tip = self
+            .checked(client.get_tip_info(Empty {}).await)
+            .await
+            .map_err(|e| NodeStatusMonitorError::UnknownError(e.into()))?
+            .into_inner();
+        let progress = self
+            .checked(client.get_sync_progress(Empty {}).await)
+            .await
+            .map_err(|e| NodeStatusMonitorError::UnknownError(e.into()))?
+            .into_inner();
+        let state = SyncState::try_from(progress.state)
+            .inspect_err(|_| {
+                warn!(target: LOG_TARGET, "Node reported unknown sync state {}", progress.state)
+            })
+            .ok();
+
+        Ok(NodeSyncProgress {
+            state,
+            local_height: progress.local_height,
+            tip_height: progress.tip_height,
+            initial_connected_peers: progress.initial_connected_peers,
+            initial_sync_achieved: tip.initial_sync_achieved,
+            best_block_height: tip
+                .metadata
+                .map(|metadata| metadata.best_block_height)
+                .unwrap_or_default(),
+        })
+    }
+
+    async fn block_hashes(&self, heights: Vec<u64>) -> Result<Vec<(u64, String)>, Error> {
+        let mut client = self.client().await?;
+
+        let mut res = self
+            .checked(client.get_blocks(GetBlocksRequest { heights }).await)
+            .await?
+            .into_inner();
+
+        let mut blocks: Vec<(u64, String)> = Vec::new();
+        while let Some(block) = res.message().await? {
+            let BlockHeader { height, hash, .. } = block
+                .block
+                .and_then(|block| block.header)
+                .ok_or_else(|| anyhow!("Failed to get block header data"))?;
+            let hash: String = hash.iter().fold(String::new(), |mut acc, x| {
+                let _unused = write!(acc, "{:02x}", x);
+                acc
+            });
+
+            blocks.push((height, hash));
+        }
+        Ok(blocks)
+    }
+
+    async fn identity(&self) -> Result<NodeIdentity, Error> {
+        let mut client = self.client().await?;
+        let res = self
+            .checked(client.identify(Empty {}).await)
+            .await?
+            .into_inner();
+
+        Ok(NodeIdentity {
+            public_key: RistrettoPublicKey::from_canonical_bytes(&res.public_key)
+                .map_err(|e| anyhow!(e.to_string()))?,
+            public_addresses: res.public_addresses,
+        })
+    }
+
+    async fn connected_peers(&self) -> Result<Vec<String>, Error> {
+        let mut client = self.client().await?;
+        let peers_list = self
+            .checked(client.list_connected_peers(Empty {}).await)
+            .await
+            .map_err(|e| anyhow!("Error list_connected_peers: {}", e))?
+            .into_inner()
+            .connected_peers;
+
+        let connected_peers = peers_list
+            .iter()
+            .filter_map(|peer| peer.addresses.first())
+            .filter(|address| {
+                let Ok(since) = NaiveDateTime::parse_from_str(
+                    address.last_seen.as_str(),
+                    "%Y-%m-%d %H:%M:%S%.f",
+                ) else {
+                    return false;
+                };
+                let since = Utc.from_utc_datetime(&since);
+                let duration = SystemTime::now()
+                    .duration_since(since.into())
+                    .unwrap_or_default();
+                duration.as_secs() < CONNECTED_PEER_MAX_AGE_SECS
+            })
+            .map(|address| address.address.to_hex())
+            .collect::<Vec<String>>();
+
+        Ok(connected_peers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use tari_shutdown::Shutdown;
+    use tokio::sync::watch;
+
+    use super::*;
+    use crate::node::node_adapter::NodeAdapterService;
+
+    /// In-memory node that reports whatever status it was last given. Block hashes are
+    /// derived from the height so orphan chain checks stay deterministic.
+    #[derive(Debug, Default)]
+    struct MockNodeClient {
+        status: Mutex<BaseNodeStatus>,
+    }
+
+    impl MockNodeClient {
+        fn set_network_state(&self, status: BaseNodeStatus) {
+            if let Ok(mut current) = self.status.lock() {
+                *current = status;
+            }
+        }
+
+        fn status(&self) -> BaseNodeStatus {
+            self.status.lock().map(|status| *status).unwrap_or_default()
+        }
+    }
+
+    #[async_trait]
+    impl NodeClient for MockNodeClient {
+        async fn network_state(&self) -> Result<BaseNodeStatus, NodeStatusMonitorError> {
+            Ok(self.status())
+        }
+
+        async fn sync_progress(&self) -> Result<NodeSyncProgress, NodeStatusMonitorError> {
+            let status = self.status();
+            Ok(NodeSyncProgress {
+                state: Some(if status.is_synced {
+                    SyncState::Done
+                } else {
+                    SyncState::Startup
+                }),
+                local_height: status.block_height,
+                tip_height: status.block_height,
+                initial_connected_peers: status.num_connections,
+                initial_sync_achieved: status.is_synced,
+                best_block_height: status.block_height,
+            })
+        }
+
+        async fn block_hashes(&self, heights: Vec<u64>) -> Result<Vec<(u64, String)>, Error> {
+            let tip = self.status().block_height;
+            Ok(heights
+                .into_iter()
+                .filter(|height| *height <= tip)
+                .map(|height| (height, format!("{height:064x}")))
+                .collect())
+        }
+
+        async fn identity(&self) -> Result<NodeIdentity, Error> {
+            Ok(NodeIdentity {
+                public_key: RistrettoPublicKey::default(),
+                public_addresses: vec![],
+            })
+        }
+
+        async fn connected_peers(&self) -> Result<Vec<String>, Error> {
+            Ok(vec![])
+        }
+    }
+
+    fn synced_status(block_height: u64) -> BaseNodeStatus {
+        BaseNodeStatus {
+            block_height,
+            is_synced: true,
+            num_connections: 4,
+            ..BaseNodeStatus::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_only_returns_blocks_up_to_tip() {
+        let client = MockNodeClient::default();
+        client.set_network_state(synced_status(100));
+
+        let blocks = client
+            .block_hashes(vec![50, 100, 150])
+            .await
+            .unwrap_or_default();
+        let heights: Vec<u64> = blocks.iter().map(|(height, _)| *height).collect();
+        assert_eq!(heights, vec![50, 100]);
+    }
+
+    #[tokio::test]
+    async fn wait_synced_returns_tip_from_client() {
+        let client = MockNodeClient::default();
+        client.set_network_state(synced_status(1234));
+        let service = NodeAdapterService::with_client(Arc::new(client), 1);
+        let (params_tx, _params_rx) = watch::channel(HashMap::new());
+        let (percentage_tx, _percentage_rx) = watch::channel(0.0);
+        let shutdown = Shutdown::new();
+
+        let height = service
+            .wait_synced(&params_tx, &percentage_tx, shutdown.to_signal())
+            .await
+            .unwrap_or_default();
+        assert_eq!(height, 1234);
+    }
+}
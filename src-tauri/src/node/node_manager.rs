@@ -49,6 +49,7 @@ use crate::process_watcher::ProcessWatcher;
 use crate::process_watcher::ProcessWatcherStats;
 use crate::setup::setup_manager::SetupManager;
 use crate::tasks_tracker::TasksTrackers;
+use crate::utils::doh_resolver::DohResolver;
 use crate::{BaseNodeStatus, LocalNodeAdapter, RemoteNodeAdapter};
 
 const LOG_TARGET: &str = "tari::universe::minotari_node_manager";
@@ -222,6 +223,10 @@ impl NodeManager {
             node_watcher.adapter.set_tor_control_port(tor_control_port);
             let ab_group = *ConfigCore::content().await.ab_group();
             node_watcher.adapter.set_ab_group(ab_group);
+            node_watcher.adapter.set_peer_seeds(
+                DohResolver::resolve_peer_seeds(Network::get_current_or_user_setting_or_default())
+                    .await,
+            );
 
             if let Some(remote_grpc_address) = remote_grpc_address {
                 node_watcher.adapter.set_grpc_address(remote_grpc_address)?;
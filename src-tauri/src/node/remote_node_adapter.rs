@@ -125,6 +125,10 @@ impl NodeAdapter for RemoteNodeAdapter {
         log::info!(target: LOG_TARGET, "RemoteNodeAdapter doesn't use tor_control_port");
     }
 
+    fn set_peer_seeds(&mut self, _peer_seeds: Vec<String>) {
+        log::info!(target: LOG_TARGET, "RemoteNodeAdapter doesn't use peer_seeds");
+    }
+
     async fn get_connection_details(&self) -> Result<(RistrettoPublicKey, String), anyhow::Error> {
         let node_service = self.get_service();
         if let Some(node_service) = node_service {
@@ -0,0 +1,92 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+pub mod models;
+
+use log::info;
+#[cfg(feature = "ootle")]
+use tari_common::configuration::Network;
+use thiserror::Error;
+
+use models::OotleTemplateCall;
+
+const LOG_TARGET: &str = "tari::universe::ootle";
+
+#[derive(Debug, Error)]
+pub enum OotleError {
+    #[error("ootle-disabled-in-build")]
+    FeatureDisabled,
+    #[error("ootle-not-available-on-network | network-{network}")]
+    NotAvailableOnNetwork { network: String },
+    #[error("ootle-not-supported-by-wallet")]
+    NotSupportedByWallet,
+}
+
+impl serde::Serialize for OotleError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
+
+/// Entry point for second-layer (Ootle) wallet features. Everything here is a stub until the
+/// wallet exposes Ootle accounts; the `ootle` feature only enables network checks.
+pub struct OotleWallet {}
+
+impl OotleWallet {
+    #[cfg(feature = "ootle")]
+    fn is_network_supported(network: Network) -> bool {
+        matches!(
+            network,
+            Network::Igor | Network::Esmeralda | Network::LocalNet
+        )
+    }
+
+    #[cfg(feature = "ootle")]
+    fn ensure_available() -> Result<(), OotleError> {
+        let network = Network::get_current_or_user_setting_or_default();
+        if !Self::is_network_supported(network) {
+            return Err(OotleError::NotAvailableOnNetwork {
+                network: network.as_key_str().to_string(),
+            });
+        }
+        Err(OotleError::NotSupportedByWallet)
+    }
+
+    #[cfg(not(feature = "ootle"))]
+    fn ensure_available() -> Result<(), OotleError> {
+        Err(OotleError::FeatureDisabled)
+    }
+
+    /// Always fails with the reason Ootle accounts can't be read yet
+    pub async fn get_account_balances() -> Result<(), OotleError> {
+        Self::ensure_available()
+    }
+
+    /// Always fails with the reason Ootle templates can't be called yet
+    pub async fn call_template(call: OotleTemplateCall) -> Result<(), OotleError> {
+        info!(target: LOG_TARGET, "Requested template {} function {}", call.template_address, call.function_name);
+        Self::ensure_available()
+    }
+}
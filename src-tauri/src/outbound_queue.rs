@@ -0,0 +1,148 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        LazyLock,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::{info, warn};
+use serde::Serialize;
+use tari_core::transactions::tari_amount::MicroMinotari;
+use tokio::sync::Mutex;
+
+use crate::events_emitter::EventsEmitter;
+
+const LOG_TARGET: &str = "tari::universe::outbound_queue";
+/// Longest review window that can be configured
+pub const MAX_OUTBOUND_REVIEW_MINUTES: u64 = 24 * 60;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+static QUEUE: LazyLock<Mutex<BTreeMap<String, PendingOutbound>>> =
+    LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingOutbound {
+    pub id: String,
+    pub amount: MicroMinotari,
+    pub destination: String,
+    pub payment_id: Option<String>,
+    pub queued_at: u64,
+    pub broadcast_at: u64,
+}
+
+/// Reported when a queued send could not be broadcast, its allowance has been refunded
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingOutboundFailure {
+    pub id: String,
+    pub amount: MicroMinotari,
+    pub destination: String,
+    pub error: String,
+}
+
+/// Sends held back for a review window before they are broadcast. Entries only live in
+/// memory, so anything still queued when the app exits is never sent.
+pub struct OutboundQueue;
+
+impl OutboundQueue {
+    pub async fn enqueue(
+        amount: MicroMinotari,
+        destination: String,
+        payment_id: Option<String>,
+        review_window: Duration,
+    ) -> PendingOutbound {
+        let queued_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let pending = PendingOutbound {
+            id: format!("outbound-{}", NEXT_ID.fetch_add(1, Ordering::SeqCst)),
+            amount,
+            destination,
+            payment_id,
+            queued_at,
+            broadcast_at: queued_at + review_window.as_secs(),
+        };
+        info!(target: LOG_TARGET, "Queued send {} of {} until {}", pending.id, amount, pending.broadcast_at);
+        QUEUE
+            .lock()
+            .await
+            .insert(pending.id.clone(), pending.clone());
+        Self::emit_changed().await;
+        pending
+    }
+
+    pub async fn list() -> Vec<PendingOutbound> {
+        QUEUE.lock().await.values().cloned().collect()
+    }
+
+    /// Removes a send from the queue so it is never broadcast
+    pub async fn cancel(id: &str) -> Option<PendingOutbound> {
+        let cancelled = QUEUE.lock().await.remove(id);
+        if cancelled.is_some() {
+            info!(target: LOG_TARGET, "Cancelled queued send {}", id);
+            Self::emit_changed().await;
+        }
+        cancelled
+    }
+
+    /// Empties the queue, used when the guest loses the right to spend
+    pub async fn cancel_all() -> Vec<PendingOutbound> {
+        let cancelled: Vec<PendingOutbound> = std::mem::take(&mut *QUEUE.lock().await)
+            .into_values()
+            .collect();
+        if !cancelled.is_empty() {
+            info!(target: LOG_TARGET, "Cancelled {} queued sends", cancelled.len());
+            Self::emit_changed().await;
+        }
+        cancelled
+    }
+
+    pub async fn report_failure(pending: PendingOutbound, error: String) {
+        warn!(target: LOG_TARGET, "Queued send {} was not broadcast: {}", pending.id, error);
+        EventsEmitter::emit_pending_outbound_failed(PendingOutboundFailure {
+            id: pending.id,
+            amount: pending.amount,
+            destination: pending.destination,
+            error,
+        })
+        .await;
+    }
+
+    /// Takes a send out of the queue once its review window is over. `None` means it was
+    /// cancelled in the meantime.
+    pub async fn take_for_broadcast(id: &str) -> Option<PendingOutbound> {
+        let pending = QUEUE.lock().await.remove(id);
+        if pending.is_some() {
+            Self::emit_changed().await;
+        }
+        pending
+    }
+
+    async fn emit_changed() {
+        EventsEmitter::emit_pending_outbound_changed(Self::list().await).await;
+    }
+}
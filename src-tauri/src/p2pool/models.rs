@@ -134,3 +134,13 @@ pub(crate) struct ConnectedPeerInfo {
 pub(crate) struct Connections {
     peers: Vec<ConnectedPeerInfo>,
 }
+
+impl Connections {
+    pub fn peer_count(&self) -> usize {
+        self.peers.len()
+    }
+
+    pub fn into_peers(self) -> Vec<ConnectedPeerInfo> {
+        self.peers
+    }
+}
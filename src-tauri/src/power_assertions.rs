@@ -0,0 +1,162 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::LazyLock;
+
+use log::{info, warn};
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use tokio::{process::Child, select, sync::Mutex};
+
+use crate::{
+    configs::{config_mining::ConfigMining, trait_config::ConfigImpl},
+    tasks_tracker::TasksTrackers,
+    UniverseAppState,
+};
+
+const LOG_TARGET: &str = "tari::universe::power_assertions";
+
+static INSTANCE: LazyLock<PowerAssertions> = LazyLock::new(PowerAssertions::new);
+
+#[derive(Debug, Clone, Serialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PowerAssertionStatus {
+    /// Sleep prevention and App Nap handling are only implemented on macOS
+    pub is_supported: bool,
+    pub prevent_sleep_while_mining: bool,
+    /// True while mining holds an assertion that keeps the system awake
+    pub is_preventing_sleep: bool,
+    /// Takes effect the next time the app is launched
+    pub is_app_nap_disabled: bool,
+}
+
+/// Keeps a Mac from idle sleeping while mining. The display is still allowed to sleep,
+/// only system sleep is held off.
+pub struct PowerAssertions {
+    assertion: Mutex<Option<Child>>,
+}
+
+impl PowerAssertions {
+    fn new() -> Self {
+        Self {
+            assertion: Mutex::new(None),
+        }
+    }
+
+    pub fn current() -> &'static PowerAssertions {
+        &INSTANCE
+    }
+
+    pub async fn status(&self) -> PowerAssertionStatus {
+        let config = ConfigMining::content().await;
+        PowerAssertionStatus {
+            is_supported: cfg!(target_os = "macos"),
+            prevent_sleep_while_mining: *config.prevent_sleep_while_mining(),
+            is_preventing_sleep: self.assertion.lock().await.is_some(),
+            is_app_nap_disabled: cfg!(target_os = "macos") && *config.disable_app_nap(),
+        }
+    }
+
+    /// Takes or drops the assertion so it matches whether any miner is running
+    pub async fn update(&self, is_mining: bool) {
+        let should_hold = is_mining && *ConfigMining::content().await.prevent_sleep_while_mining();
+        let mut assertion = self.assertion.lock().await;
+        match (should_hold, assertion.is_some()) {
+            (true, false) => *assertion = Self::hold(),
+            (false, true) => {
+                if let Some(mut child) = assertion.take() {
+                    info!(target: LOG_TARGET, "Releasing sleep assertion");
+                    let _unused = child.kill().await;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `caffeinate -i` holds a PreventUserIdleSystemSleep assertion, `-w` ties it to our
+    /// pid so it is released even if the app crashes
+    #[cfg(target_os = "macos")]
+    fn hold() -> Option<Child> {
+        info!(target: LOG_TARGET, "Preventing idle system sleep while mining");
+        tokio::process::Command::new("caffeinate")
+            .args(["-i", "-w", &std::process::id().to_string()])
+            .kill_on_drop(true)
+            .spawn()
+            .inspect_err(|e| warn!(target: LOG_TARGET, "Could not start caffeinate: {}", e))
+            .ok()
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn hold() -> Option<Child> {
+        None
+    }
+
+    /// App Nap throttles timers of hidden apps, which slows the miners down when the window
+    /// is in the background. macOS reads the setting at launch.
+    #[cfg(target_os = "macos")]
+    pub async fn apply_app_nap_setting(app_handle: &AppHandle) {
+        let disable_app_nap = *ConfigMining::content().await.disable_app_nap();
+        let identifier = app_handle.config().identifier.clone();
+        let result = tokio::process::Command::new("defaults")
+            .args([
+                "write",
+                &identifier,
+                "NSAppSleepDisabled",
+                "-bool",
+                if disable_app_nap { "YES" } else { "NO" },
+            ])
+            .status()
+            .await;
+        if let Err(e) = result {
+            warn!(target: LOG_TARGET, "Could not update App Nap setting: {}", e);
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    pub async fn apply_app_nap_setting(_app_handle: &AppHandle) {}
+
+    pub async fn spawn(app_handle: AppHandle) {
+        Self::apply_app_nap_setting(&app_handle).await;
+
+        let state = app_handle.state::<UniverseAppState>();
+        let mut cpu_status_rx = (*state.cpu_miner_status_watch_rx).clone();
+        let mut gpu_status_rx = (*state.gpu_latest_status).clone();
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => break,
+                        result = cpu_status_rx.changed() => if result.is_err() { break; },
+                        result = gpu_status_rx.changed() => if result.is_err() { break; },
+                    }
+                    let is_mining =
+                        cpu_status_rx.borrow().is_mining || gpu_status_rx.borrow().is_mining;
+                    PowerAssertions::current().update(is_mining).await;
+                }
+                PowerAssertions::current().update(false).await;
+            });
+    }
+}
@@ -23,11 +23,70 @@
 use std::{collections::HashMap, sync::Arc};
 
 use log::error;
+use serde::Serialize;
 use tauri::AppHandle;
 use tokio::sync::{watch::Sender, RwLock};
 
+use crate::events_emitter::EventsEmitter;
+
 const LOG_TARGET: &str = "tari::universe::progress_tracker";
 
+/// Structured progress of one step of the binary and tapplet pipeline. `step_id` is
+/// hierarchical, `<binary or tapplet>/<step>`, so consumers can group steps per download.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgressUpdate {
+    pub step_id: String,
+    pub description: String,
+    pub percent: Option<f64>,
+    pub bytes_done: Option<u64>,
+    pub bytes_total: Option<u64>,
+    pub bytes_per_second: Option<u64>,
+    pub eta_seconds: Option<u64>,
+}
+
+impl ProgressUpdate {
+    pub fn new(parent: &str, step: &str, description: String) -> Self {
+        Self {
+            step_id: format!("{}/{}", parent, step),
+            description,
+            percent: None,
+            bytes_done: None,
+            bytes_total: None,
+            bytes_per_second: None,
+            eta_seconds: None,
+        }
+    }
+
+    pub fn with_transfer(
+        mut self,
+        bytes_done: u64,
+        bytes_total: Option<u64>,
+        bytes_per_second: u64,
+    ) -> Self {
+        self.bytes_done = Some(bytes_done);
+        self.bytes_total = bytes_total;
+        self.bytes_per_second = Some(bytes_per_second);
+        self.percent = bytes_total
+            .filter(|total| *total > 0)
+            .map(|total| (bytes_done as f64 / total as f64 * 100.0).min(100.0));
+        self.eta_seconds = estimate_eta_seconds(bytes_done, bytes_total, bytes_per_second);
+        self
+    }
+}
+
+/// A stalled transfer has no ETA rather than an absurdly large one
+pub fn estimate_eta_seconds(
+    bytes_done: u64,
+    bytes_total: Option<u64>,
+    bytes_per_second: u64,
+) -> Option<u64> {
+    let remaining = bytes_total?.checked_sub(bytes_done)?;
+    if bytes_per_second == 0 {
+        return None;
+    }
+    Some(remaining.div_ceil(bytes_per_second))
+}
+
 pub struct ProgressTracker {
     inner: Arc<RwLock<ProgressTrackerInner>>,
 }
@@ -52,8 +111,13 @@ impl ProgressTracker {
         self.inner.write().await.set_next_max(max);
     }
 
-    pub async fn send_last_action(&self, action: String) {
-        self.inner.read().await.send_last_action(action);
+    /// The description also feeds the last action channel, which is what a setup timeout reports
+    pub async fn send_progress(&self, update: ProgressUpdate) {
+        self.inner
+            .read()
+            .await
+            .send_last_action(update.description.clone());
+        EventsEmitter::emit_progress_detail_update(update).await;
     }
 
     pub async fn update(
@@ -121,3 +185,31 @@ impl ProgressTrackerInner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eta_from_remaining_bytes() {
+        assert_eq!(estimate_eta_seconds(500, Some(1_500), 100), Some(10));
+        assert_eq!(estimate_eta_seconds(500, Some(1_550), 100), Some(11));
+    }
+
+    #[test]
+    fn no_eta_when_stalled_or_size_unknown() {
+        assert_eq!(estimate_eta_seconds(500, Some(1_500), 0), None);
+        assert_eq!(estimate_eta_seconds(500, None, 100), None);
+    }
+
+    #[test]
+    fn transfer_sets_percent() {
+        let update = ProgressUpdate::new("minotari_node", "download", String::new()).with_transfer(
+            250,
+            Some(1_000),
+            50,
+        );
+        assert_eq!(update.percent, Some(25.0));
+        assert_eq!(update.eta_seconds, Some(15));
+    }
+}
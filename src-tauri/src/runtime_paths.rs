@@ -0,0 +1,119 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use serde::Serialize;
+use tari_common::configuration::Network;
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    node::chain_snapshot::chain_data_dir,
+    wallet_backup::wallet_data_dir,
+    UniverseAppState,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuntimePorts {
+    pub node_grpc_address: Option<String>,
+    pub p2pool_grpc_address: Option<String>,
+    pub p2pool_stats_port: Option<u16>,
+    pub mmproxy_monero_port: Option<u16>,
+}
+
+/// Where the current network keeps its data, so it is clear which files a session touches
+#[derive(Debug, Clone, Serialize)]
+pub struct RuntimePaths {
+    pub network: String,
+    pub data_dir: Option<PathBuf>,
+    pub node_data_dir: Option<PathBuf>,
+    pub wallet_data_dir: Option<PathBuf>,
+    pub config_dir: Option<PathBuf>,
+    pub log_dir: Option<PathBuf>,
+    pub ports: RuntimePorts,
+}
+
+impl RuntimePaths {
+    pub async fn collect(app_handle: &AppHandle) -> Self {
+        let state = app_handle.state::<UniverseAppState>();
+        let data_dir = app_handle.path().app_local_data_dir().ok();
+        let is_p2pool_running = state.p2pool_manager.is_running().await;
+        let p2pool_grpc_address = if is_p2pool_running {
+            Some(state.p2pool_manager.get_grpc_address(true).await)
+        } else {
+            None
+        };
+        let p2pool_stats_port = if is_p2pool_running {
+            Some(state.p2pool_manager.stats_server_port().await)
+        } else {
+            None
+        };
+
+        Self {
+            network: Network::get_current().as_key_str().to_string(),
+            node_data_dir: data_dir.as_deref().map(chain_data_dir),
+            wallet_data_dir: data_dir.as_deref().map(wallet_data_dir),
+            data_dir,
+            config_dir: ConfigCore::_get_config_path()
+                .parent()
+                .map(Path::to_path_buf),
+            log_dir: app_handle.path().app_log_dir().ok(),
+            ports: RuntimePorts {
+                node_grpc_address: state.node_manager.get_grpc_address().await.ok(),
+                p2pool_grpc_address,
+                p2pool_stats_port,
+                mmproxy_monero_port: state.mm_proxy_manager.get_monero_port().await.ok(),
+            },
+        }
+    }
+}
+
+fn check_network(archive_network: &str, current: Network) -> Result<(), anyhow::Error> {
+    if archive_network.eq_ignore_ascii_case(current.as_key_str()) {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "This archive was created on {} but this app is running on {}",
+            archive_network,
+            current.as_key_str()
+        ))
+    }
+}
+
+/// Rejects backups and snapshots whose manifest says they belong to another network
+pub fn ensure_same_network(archive_network: &str) -> Result<(), anyhow::Error> {
+    check_network(archive_network, Network::get_current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_archives_from_other_networks() {
+        assert!(check_network("esmeralda", Network::MainNet).is_err());
+        assert!(check_network("esmeralda", Network::Esmeralda).is_ok());
+        assert!(check_network(Network::NextNet.as_key_str(), Network::NextNet).is_ok());
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#[cfg(not(target_os = "windows"))]
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use dunce::canonicalize;
+use log::info;
+#[cfg(target_os = "windows")]
+use planif::{
+    enums::TaskCreationFlags,
+    schedule::TaskScheduler,
+    schedule_builder::{Action, ScheduleBuilder},
+    settings::{Duration, LogonType, PrincipalSettings, RunLevel, Settings},
+};
+use serde::Serialize;
+use tauri::utils::platform::current_exe;
+#[cfg(target_os = "windows")]
+use whoami::username;
+
+const LOG_TARGET: &str = "tari::universe::service_installer";
+pub const HEADLESS_ARG: &str = "--headless";
+#[cfg(target_os = "linux")]
+const SYSTEMD_UNIT_NAME: &str = "tari-universe.service";
+#[cfg(target_os = "macos")]
+const LAUNCHD_LABEL: &str = "com.tari.universe.headless";
+#[cfg(target_os = "windows")]
+const WINDOWS_TASK_NAME: &str = "Tari Universe headless";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceInstallStatus {
+    pub is_installed: bool,
+    pub definition_path: Option<String>,
+}
+
+/// Registers the headless app to start with the user's session rather than as a system
+/// service. The app still opens a webview, so it needs the user's display, and installing
+/// it this way does not require administrator rights.
+pub struct ServiceInstaller {}
+
+impl ServiceInstaller {
+    fn app_path() -> Result<String, anyhow::Error> {
+        let app_exe = canonicalize(current_exe()?)?;
+        app_exe
+            .to_str()
+            .map(|path| path.to_string())
+            .ok_or(anyhow!("Failed to convert path to string"))
+    }
+
+    /// `~/.config/systemd/user`, started by the user's systemd instance
+    #[cfg(target_os = "linux")]
+    fn definition_path() -> Result<PathBuf, anyhow::Error> {
+        Ok(dirs::config_dir()
+            .ok_or_else(|| anyhow!("Could not find the user config directory"))?
+            .join("systemd")
+            .join("user")
+            .join(SYSTEMD_UNIT_NAME))
+    }
+
+    /// `~/Library/LaunchAgents`, loaded when the user logs in
+    #[cfg(target_os = "macos")]
+    fn definition_path() -> Result<PathBuf, anyhow::Error> {
+        Ok(dirs::home_dir()
+            .ok_or_else(|| anyhow!("Could not find the home directory"))?
+            .join("Library")
+            .join("LaunchAgents")
+            .join(format!("{}.plist", LAUNCHD_LABEL)))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn build_definition(app_path: &str) -> String {
+        format!(
+            "[Unit]\n\
+             Description=Tari Universe (headless)\n\
+             PartOf=graphical-session.target\n\
+             After=graphical-session.target network-online.target\n\
+             \n\
+             [Service]\n\
+             Type=simple\n\
+             ExecStart=\"{app_path}\" {HEADLESS_ARG}\n\
+             Restart=always\n\
+             RestartSec=30\n\
+             \n\
+             [Install]\n\
+             WantedBy=graphical-session.target\n"
+        )
+    }
+
+    #[cfg(target_os = "macos")]
+    fn build_definition(app_path: &str) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+             <plist version=\"1.0\">\n\
+             <dict>\n\
+             \t<key>Label</key>\n\
+             \t<string>{LAUNCHD_LABEL}</string>\n\
+             \t<key>ProgramArguments</key>\n\
+             \t<array>\n\
+             \t\t<string>{app_path}</string>\n\
+             \t\t<string>{HEADLESS_ARG}</string>\n\
+             \t</array>\n\
+             \t<key>LimitLoadToSessionType</key>\n\
+             \t<string>Aqua</string>\n\
+             \t<key>RunAtLoad</key>\n\
+             \t<true/>\n\
+             \t<key>KeepAlive</key>\n\
+             \t<true/>\n\
+             \t<key>ThrottleInterval</key>\n\
+             \t<integer>30</integer>\n\
+             </dict>\n\
+             </plist>\n"
+        )
+    }
+
+    fn run_command(program: &str, args: &[&str]) -> Result<(), anyhow::Error> {
+        let mut command = std::process::Command::new(program);
+        command.args(args);
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            command.creation_flags(crate::consts::PROCESS_CREATION_NO_WINDOW);
+        }
+        let output = command.output()?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "{} {:?} failed: {}",
+                program,
+                args,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    pub fn install() -> Result<ServiceInstallStatus, anyhow::Error> {
+        let app_path = Self::app_path()?;
+        let definition_path = Self::definition_path()?;
+        info!(target: LOG_TARGET, "Installing headless service definition at {:?}", definition_path);
+
+        if let Some(parent) = definition_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&definition_path, Self::build_definition(&app_path)).map_err(|e| {
+            anyhow!(
+                "Could not write service definition to {:?}: {}",
+                definition_path,
+                e
+            )
+        })?;
+
+        #[cfg(target_os = "linux")]
+        {
+            Self::run_command("systemctl", &["--user", "daemon-reload"])?;
+            Self::run_command("systemctl", &["--user", "enable", SYSTEMD_UNIT_NAME])?;
+        }
+        #[cfg(target_os = "macos")]
+        Self::run_command(
+            "launchctl",
+            &["load", "-w", &definition_path.to_string_lossy()],
+        )?;
+
+        Self::status()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    pub fn uninstall() -> Result<ServiceInstallStatus, anyhow::Error> {
+        let definition_path = Self::definition_path()?;
+        if !definition_path.exists() {
+            return Self::status();
+        }
+        info!(target: LOG_TARGET, "Removing headless service definition at {:?}", definition_path);
+
+        #[cfg(target_os = "linux")]
+        Self::run_command("systemctl", &["--user", "disable", SYSTEMD_UNIT_NAME])?;
+        #[cfg(target_os = "macos")]
+        Self::run_command(
+            "launchctl",
+            &["unload", "-w", &definition_path.to_string_lossy()],
+        )?;
+
+        std::fs::remove_file(&definition_path)?;
+
+        #[cfg(target_os = "linux")]
+        Self::run_command("systemctl", &["--user", "daemon-reload"])?;
+
+        Self::status()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    pub fn status() -> Result<ServiceInstallStatus, anyhow::Error> {
+        let definition_path = Self::definition_path()?;
+        Ok(ServiceInstallStatus {
+            is_installed: definition_path.exists(),
+            definition_path: Some(definition_path.to_string_lossy().to_string()),
+        })
+    }
+
+    /// Runs at logon with the user's interactive token so the webview has a desktop to use
+    #[cfg(target_os = "windows")]
+    fn register_logon_task() -> Result<(), Box<dyn std::error::Error>> {
+        let app_path = Self::app_path()?;
+        let task_scheduler = TaskScheduler::new()?;
+        let com_runtime = task_scheduler.get_com();
+        let schedule_builder = ScheduleBuilder::new(&com_runtime)?;
+
+        let mut restart_interval = Duration::new();
+        restart_interval.minutes = Some(1);
+
+        schedule_builder
+            .create_logon()
+            .author("Tari Universe")?
+            .trigger("headless_logon_trigger", true)?
+            .action(Action::new("headless_action", &app_path, "", HEADLESS_ARG))?
+            .principal(PrincipalSettings {
+                display_name: "Tari Universe".to_string(),
+                group_id: None,
+                user_id: Some(username()),
+                id: "Tari universe headless principal".to_string(),
+                logon_type: LogonType::InteractiveToken,
+                run_level: RunLevel::Highest,
+            })?
+            .settings(Settings {
+                stop_if_going_on_batteries: Some(false),
+                disallow_start_if_on_batteries: Some(false),
+                start_when_available: Some(true),
+                enabled: Some(true),
+                restart_count: Some(999),
+                restart_interval: Some(restart_interval.to_string()),
+                allow_demand_start: Some(true),
+                ..Default::default()
+            })?
+            .build()?
+            .register(WINDOWS_TASK_NAME, TaskCreationFlags::CreateOrUpdate as i32)?;
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn install() -> Result<ServiceInstallStatus, anyhow::Error> {
+        info!(target: LOG_TARGET, "Registering headless logon task");
+        Self::register_logon_task().map_err(|e| anyhow!("Failed to register logon task: {}", e))?;
+        Self::status()
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn uninstall() -> Result<ServiceInstallStatus, anyhow::Error> {
+        if Self::status()?.is_installed {
+            info!(target: LOG_TARGET, "Deleting headless logon task");
+            Self::run_command("schtasks", &["/Delete", "/TN", WINDOWS_TASK_NAME, "/F"])?;
+        }
+        Self::status()
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn status() -> Result<ServiceInstallStatus, anyhow::Error> {
+        Ok(ServiceInstallStatus {
+            is_installed: Self::run_command("schtasks", &["/Query", "/TN", WINDOWS_TASK_NAME])
+                .is_ok(),
+            definition_path: Some(WINDOWS_TASK_NAME.to_string()),
+        })
+    }
+}
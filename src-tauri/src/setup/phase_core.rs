@@ -38,7 +38,10 @@ use crate::{
     },
     setup::setup_manager::SetupPhase,
     tasks_tracker::TasksTrackers,
-    utils::{network_status::NetworkStatus, platform_utils::PlatformUtils},
+    utils::{
+        clipboard_monitor::ClipboardMonitor, network_status::NetworkStatus,
+        platform_utils::PlatformUtils, time_sync::TimeSync,
+    },
     EventsEmitter, UniverseAppState,
 };
 
@@ -193,6 +196,10 @@ impl SetupPhaseImpl for CoreSetupPhase {
 
         NetworkStatus::current().run_speed_test_with_timeout().await;
 
+        TimeSync::current().start_periodic_check().await;
+
+        ClipboardMonitor::spawn(self.app_handle.clone()).await;
+
         Ok(())
     }
 
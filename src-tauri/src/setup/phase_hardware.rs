@@ -27,7 +27,9 @@ use crate::{
     configs::{config_mining::ConfigMining, trait_config::ConfigImpl},
     events_emitter::EventsEmitter,
     gpu_miner::EngineType,
-    hardware::hardware_status_monitor::HardwareStatusMonitor,
+    hardware::{
+        gpu_driver_compat::GpuDriverCompat, hardware_status_monitor::HardwareStatusMonitor,
+    },
     progress_tracker_old::ProgressTracker,
     progress_trackers::{
         progress_plans::{ProgressPlans, ProgressSetupHardwarePlan},
@@ -35,8 +37,8 @@ use crate::{
         ProgressStepper,
     },
     setup::setup_manager::SetupPhase,
-    systemtray_manager::SystemTrayData,
     tasks_tracker::TasksTrackers,
+    tray::systemtray_manager::SystemTrayData,
     utils::locks_utils::try_write_with_retry,
     GpuMinerStatus, UniverseAppState,
 };
@@ -201,9 +203,13 @@ impl SetupPhaseImpl for HardwareSetupPhase {
             .detect(
                 config_dir.clone(),
                 self.app_configuration.gpu_engine.clone(),
+                false,
             )
             .await
             .inspect_err(|e| error!(target: LOG_TARGET, "Could not detect gpu miner: {:?}", e));
+        GpuDriverCompat::current()
+            .refresh(&config_dir, &self.app_configuration.gpu_engine)
+            .await;
 
         HardwareStatusMonitor::current().initialize().await?;
 
@@ -259,6 +265,7 @@ impl SetupPhaseImpl for HardwareSetupPhase {
                             gpu_hashrate: gpu_status.hash_rate,
                             estimated_earning: (cpu_status.estimated_earnings
                                 + gpu_status.estimated_earnings) as f64,
+                            is_mining: cpu_status.is_mining || gpu_status.is_mining,
                         };
 
                         match try_write_with_retry(&app_state.systemtray_manager, 6).await {
@@ -23,7 +23,10 @@
 use crate::{
     binaries::{Binaries, BinaryResolver},
     configs::{config_core::ConfigCore, config_mining::ConfigMining, trait_config::ConfigImpl},
+    earnings_monitor::EarningsMonitor,
     events_emitter::EventsEmitter,
+    mining_coordinator::MiningCoordinator,
+    mining_scheduler::MiningScheduler,
     p2pool_manager::P2poolConfig,
     progress_tracker_old::ProgressTracker,
     progress_trackers::{
@@ -279,6 +282,10 @@ impl SetupPhaseImpl for MiningSetupPhase {
 
         EventsEmitter::emit_mining_phase_finished(true).await;
 
+        MiningScheduler::spawn(self.app_handle.clone()).await;
+        MiningCoordinator::spawn(self.app_handle.clone()).await;
+        EarningsMonitor::spawn(self.app_handle.clone()).await;
+
         Ok(())
     }
 }
@@ -24,10 +24,17 @@ use std::{collections::HashMap, time::Duration};
 
 use crate::{
     binaries::{Binaries, BinaryResolver},
-    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    configs::{
+        config_core::{ConfigCore, FastSync},
+        trait_config::ConfigImpl,
+    },
     events_emitter::EventsEmitter,
     events_manager::EventsManager,
-    node::node_manager::{NodeManagerError, STOP_ON_ERROR_CODES},
+    heartbeat::EXIT_CODE_NODE_START_FAILED,
+    node::{
+        fast_sync::try_fast_sync,
+        node_manager::{NodeManagerError, STOP_ON_ERROR_CODES},
+    },
     progress_tracker_old::ProgressTracker,
     progress_trackers::{
         progress_plans::{ProgressPlans, ProgressSetupNodePlan},
@@ -66,6 +73,7 @@ pub struct NodeSetupPhaseOutput {}
 pub struct NodeSetupPhaseAppConfiguration {
     use_tor: bool,
     base_node_grpc_address: String,
+    fast_sync: FastSync,
 }
 
 pub struct NodeSetupPhase {
@@ -153,10 +161,12 @@ impl SetupPhaseImpl for NodeSetupPhase {
             .await
             .remote_base_node_address()
             .clone();
+        let fast_sync = ConfigCore::content().await.fast_sync().clone();
 
         Ok(NodeSetupPhaseAppConfiguration {
             use_tor,
             base_node_grpc_address,
+            fast_sync,
         })
     }
 
@@ -175,26 +185,36 @@ impl SetupPhaseImpl for NodeSetupPhase {
         let progress = ProgressTracker::new(self.app_handle.clone(), Some(tx));
         let binary_resolver = BinaryResolver::current().read().await;
 
-        if self.app_configuration.use_tor && !cfg!(target_os = "macos") {
-            tokio::time::sleep(Duration::from_secs(10)).await;
+        let use_local_tor = self.app_configuration.use_tor && !cfg!(target_os = "macos");
+        if use_local_tor {
             progress_stepper
                 .resolve_step(ProgressPlans::Node(ProgressSetupNodePlan::BinariesTor))
                 .await;
-            binary_resolver
-                .initialize_binary_timeout(Binaries::Tor, progress.clone(), rx.clone())
-                .await?;
         } else {
             progress_stepper.skip_step(ProgressPlans::Node(ProgressSetupNodePlan::BinariesTor));
         };
-
         progress_stepper
             .resolve_step(ProgressPlans::Node(ProgressSetupNodePlan::BinariesNode))
             .await;
-        binary_resolver
-            .initialize_binary_timeout(Binaries::MinotariNode, progress.clone(), rx.clone())
-            .await?;
 
-        if self.app_configuration.use_tor && !cfg!(target_os = "macos") {
+        // The tor and node binaries don't depend on each other, so fetch them side by side
+        let tor_binary = async {
+            if use_local_tor {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                binary_resolver
+                    .initialize_binary_timeout(Binaries::Tor, progress.clone(), rx.clone())
+                    .await?;
+            }
+            Ok::<(), Error>(())
+        };
+        let node_binary = binary_resolver.initialize_binary_timeout(
+            Binaries::MinotariNode,
+            progress.clone(),
+            rx.clone(),
+        );
+        tokio::try_join!(tor_binary, node_binary)?;
+
+        if use_local_tor {
             progress_stepper
                 .resolve_step(ProgressPlans::Node(ProgressSetupNodePlan::StartTor))
                 .await;
@@ -204,6 +224,10 @@ impl SetupPhaseImpl for NodeSetupPhase {
                 .await?;
         }
 
+        if state.node_manager.is_local().await? {
+            try_fast_sync(&data_dir, &self.app_configuration.fast_sync, &progress).await;
+        }
+
         let tor_control_port = state.tor_manager.get_control_port().await?;
         progress_stepper
             .resolve_step(ProgressPlans::Node(ProgressSetupNodePlan::StartingNode))
@@ -242,7 +266,7 @@ impl SetupPhaseImpl for NodeSetupPhase {
                         continue;
                     }
                     error!(target: LOG_TARGET, "Could not start node manager after restart: {:?} | Exitting the app", e);
-                    self.app_handle.exit(-1);
+                    self.app_handle.exit(EXIT_CODE_NODE_START_FAILED);
                     return Err(e.into());
                 }
             }
@@ -35,8 +35,10 @@ use crate::{
         ProgressStepper,
     },
     setup::setup_manager::SetupPhase,
+    spend_wallet_manager::format_send_amount,
     tapplets::{TappletResolver, Tapplets},
     tasks_tracker::TasksTrackers,
+    utils::wallet_utils::new_watched_receipts,
     wallet_manager::WalletStartupConfig,
     UniverseAppState,
 };
@@ -57,6 +59,7 @@ use super::{
 };
 
 static LOG_TARGET: &str = "tari::universe::phase_wallet";
+const WATCHED_ADDRESS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
 
 // Bump to force wallet full scan
 const WALLET_MIGRATION_NONCE: u64 = 1;
@@ -82,6 +85,52 @@ pub struct WalletSetupPhase {
     timeout_watcher: TimeoutWatcher,
 }
 
+impl WalletSetupPhase {
+    /// Announces transactions the wallet receives on a watched address. Sends from this app
+    /// to a watched address are announced when they are made.
+    async fn spawn_watched_address_monitor(app_handle: AppHandle) {
+        let mut shutdown_signal = TasksTrackers::current().wallet_phase.get_signal().await;
+        TasksTrackers::current()
+            .wallet_phase
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let wallet_manager = app_handle
+                    .state::<UniverseAppState>()
+                    .wallet_manager
+                    .clone();
+                let mut seen_tx_ids = None;
+                loop {
+                    let config = ConfigWallet::content().await;
+                    if !config.watched_addresses().is_empty() {
+                        match wallet_manager.get_transactions_history(None, None).await {
+                            Ok(transactions) => {
+                                for (address, transaction) in
+                                    new_watched_receipts(&config, &transactions, &mut seen_tx_ids)
+                                {
+                                    let label = config.address_labels().get(&address).cloned();
+                                    EventsEmitter::emit_watched_address_received(
+                                        address,
+                                        label,
+                                        format_send_amount(transaction.amount),
+                                    )
+                                    .await;
+                                }
+                            }
+                            Err(e) => {
+                                log::warn!(target: LOG_TARGET, "Could not check watched addresses: {}", e);
+                            }
+                        }
+                    }
+                    tokio::select! {
+                        _ = shutdown_signal.wait() => break,
+                        _ = tokio::time::sleep(WATCHED_ADDRESS_POLL_INTERVAL) => {}
+                    }
+                }
+            });
+    }
+}
+
 impl SetupPhaseImpl for WalletSetupPhase {
     type AppConfiguration = WalletSetupPhaseAppConfiguration;
 
@@ -321,6 +370,8 @@ impl SetupPhaseImpl for WalletSetupPhase {
                 });
         }
 
+        Self::spawn_watched_address_monitor(self.get_app_handle().clone()).await;
+
         EventsEmitter::emit_wallet_phase_finished(true).await;
 
         Ok(())
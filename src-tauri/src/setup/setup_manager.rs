@@ -28,23 +28,28 @@ use super::{
 use crate::app_in_memory_config::EXCHANGE_ID;
 use crate::configs::config_core::ConfigCoreContent;
 use crate::{
+    access_control::AccessControl,
     app_in_memory_config::{DynamicMemoryConfig, ExchangeMiner, DEFAULT_EXCHANGE_ID},
+    binaries::{Binaries, BinaryResolver},
     configs::{
         config_core::ConfigCore, config_mining::ConfigMining, config_ui::ConfigUI,
         config_wallet::ConfigWallet, trait_config::ConfigImpl,
     },
+    demo_mode::DemoMode,
     events::ConnectionStatusPayload,
     events_emitter::EventsEmitter,
     events_manager::EventsManager,
     initialize_frontend_updates,
     internal_wallet::InternalWallet,
+    progress_tracker_old::ProgressTracker,
     release_notes::ReleaseNotes,
     tasks_tracker::TasksTrackers,
     utils::system_status::SystemStatus,
     websocket_manager::WebsocketMessage,
     UniverseAppState,
 };
-use log::{debug, error, info};
+use futures::future::join_all;
+use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter},
@@ -54,7 +59,10 @@ use std::{
 use tauri::{AppHandle, Listener, Manager};
 use tokio::{
     select,
-    sync::{watch::Sender, Mutex, RwLock},
+    sync::{
+        watch::{Receiver, Sender},
+        Mutex, RwLock,
+    },
 };
 use tokio_util::sync::CancellationToken;
 
@@ -155,6 +163,17 @@ impl SetupPhase {
             SetupPhase::Mining,
         ]
     }
+
+    /// Phases that must succeed before this one starts, anything not listed here runs concurrently
+    pub fn dependencies(&self) -> Vec<SetupPhase> {
+        match self {
+            SetupPhase::Core => vec![],
+            SetupPhase::Hardware | SetupPhase::Node => vec![SetupPhase::Core],
+            SetupPhase::Wallet => vec![SetupPhase::Node],
+            SetupPhase::Mining => vec![SetupPhase::Node, SetupPhase::Hardware],
+        }
+    }
+
     pub fn get_critical_problem_title(&self) -> String {
         match self {
             SetupPhase::Core => "phase-core-critical-problem-title".to_string(),
@@ -177,7 +196,7 @@ impl SetupPhase {
 }
 
 #[allow(dead_code)]
-#[derive(Default)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Serialize)]
 pub enum PhaseStatus {
     #[default]
     None,
@@ -212,6 +231,59 @@ impl PhaseStatus {
     }
 }
 
+/// Tells a value that is empty because its phase hasn't finished apart from a genuine zero
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InitializationState {
+    NotStarted,
+    Initializing,
+    Ready,
+    Failed,
+}
+
+impl From<PhaseStatus> for InitializationState {
+    fn from(status: PhaseStatus) -> Self {
+        match status {
+            PhaseStatus::None => InitializationState::NotStarted,
+            PhaseStatus::Initialized | PhaseStatus::AwaitingStart | PhaseStatus::InProgress => {
+                InitializationState::Initializing
+            }
+            PhaseStatus::Failed => InitializationState::Failed,
+            PhaseStatus::Success | PhaseStatus::SuccessWithWarnings => InitializationState::Ready,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SetupPhaseProgress {
+    pub phase: SetupPhase,
+    pub status: PhaseStatus,
+    pub initialization_state: InitializationState,
+    pub depends_on: Vec<SetupPhase>,
+}
+
+impl SetupPhaseProgress {
+    pub fn new(phase: SetupPhase, status: PhaseStatus) -> Self {
+        Self {
+            depends_on: phase.dependencies(),
+            initialization_state: InitializationState::from(status),
+            phase,
+            status,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct SetupProgress {
+    pub phases: Vec<SetupPhaseProgress>,
+    pub is_app_unlocked: bool,
+    pub is_wallet_unlocked: bool,
+    pub is_cpu_mining_unlocked: bool,
+    pub is_gpu_mining_unlocked: bool,
+    pub is_initial_setup_finished: bool,
+}
+
 #[derive(Default)]
 pub struct SetupManager {
     features: RwLock<SetupFeaturesList>,
@@ -241,6 +313,76 @@ impl SetupManager {
         &INSTANCE
     }
 
+    fn phase_status_sender(&self, phase: &SetupPhase) -> &Sender<PhaseStatus> {
+        match phase {
+            SetupPhase::Core => &self.core_phase_status,
+            SetupPhase::Hardware => &self.hardware_phase_status,
+            SetupPhase::Node => &self.node_phase_status,
+            SetupPhase::Wallet => &self.wallet_phase_status,
+            SetupPhase::Mining => &self.mining_phase_status,
+        }
+    }
+
+    fn dependency_listeners(&self, phase: &SetupPhase) -> Vec<Receiver<PhaseStatus>> {
+        phase
+            .dependencies()
+            .iter()
+            .map(|dependency| self.phase_status_sender(dependency).subscribe())
+            .collect()
+    }
+
+    pub fn get_initialization_state(&self, phase: &SetupPhase) -> InitializationState {
+        InitializationState::from(*self.phase_status_sender(phase).borrow())
+    }
+
+    pub async fn get_setup_progress(&self) -> SetupProgress {
+        let phases = SetupPhase::all()
+            .into_iter()
+            .map(|phase| {
+                let status = *self.phase_status_sender(&phase).borrow();
+                SetupPhaseProgress::new(phase, status)
+            })
+            .collect();
+        SetupProgress {
+            phases,
+            is_app_unlocked: *self.is_app_unlocked.lock().await,
+            is_wallet_unlocked: *self.is_wallet_unlocked.lock().await,
+            is_cpu_mining_unlocked: *self.is_cpu_mining_unlocked.lock().await,
+            is_gpu_mining_unlocked: *self.is_gpu_mining_unlocked.lock().await,
+            is_initial_setup_finished: *self.is_initial_setup_finished.lock().await,
+        }
+    }
+
+    /// Forwards every phase status transition to the frontend, including restarts
+    async fn spawn_phase_status_forwarding(&self) {
+        for phase in SetupPhase::all() {
+            let mut status_subscriber = self.phase_status_sender(&phase).subscribe();
+            let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+            TasksTrackers::current()
+                .common
+                .get_task_tracker()
+                .await
+                .spawn(async move {
+                    loop {
+                        select! {
+                            _ = shutdown_signal.wait() => {
+                                break;
+                            }
+                            result = status_subscriber.changed() => {
+                                if result.is_err() {
+                                    break;
+                                }
+                                let status = *status_subscriber.borrow_and_update();
+                                debug!(target: LOG_TARGET, "Phase {} status changed to {}", phase, status);
+                                EventsEmitter::emit_setup_phase_status_changed(SetupPhaseProgress::new(phase.clone(), status))
+                                .await;
+                            }
+                        }
+                    }
+                });
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     async fn pre_setup(&self, app_handle: AppHandle) {
         info!(target: LOG_TARGET, "Pre Setup");
@@ -320,6 +462,7 @@ impl SetupManager {
             .path()
             .app_config_dir()
             .expect("Could not get config dir");
+        AccessControl::initialize(config_path.clone());
         let internal_wallet = InternalWallet::load_or_create(config_path, state)
             .await
             .expect("Could not load or create internal wallet");
@@ -374,7 +517,9 @@ impl SetupManager {
         let hardware_phase_setup = PhaseBuilder::new()
             // NODE: Temporary solution to process payrefs in TU v1.2.9
             .with_setup_timeout_duration(Duration::from_secs(60 * 15)) // 15 minutes
-            .with_listeners_for_required_phases_statuses(vec![self.core_phase_status.subscribe()])
+            .with_listeners_for_required_phases_statuses(
+                self.dependency_listeners(&SetupPhase::Hardware),
+            )
             .build::<HardwareSetupPhase>(
                 app_handle.clone(),
                 self.hardware_phase_status.clone(),
@@ -389,7 +534,9 @@ impl SetupManager {
 
         let node_phase_setup = PhaseBuilder::new()
             .with_setup_timeout_duration(Duration::from_secs(60 * 30))
-            .with_listeners_for_required_phases_statuses(vec![self.core_phase_status.subscribe()])
+            .with_listeners_for_required_phases_statuses(
+                self.dependency_listeners(&SetupPhase::Node),
+            )
             .build::<NodeSetupPhase>(
                 app_handle.clone(),
                 self.node_phase_status.clone(),
@@ -410,7 +557,9 @@ impl SetupManager {
         }
         let wallet_phase_setup = PhaseBuilder::new()
             .with_setup_timeout_duration(Duration::from_secs(60 * 10)) // 10 minutes
-            .with_listeners_for_required_phases_statuses(vec![self.node_phase_status.subscribe()])
+            .with_listeners_for_required_phases_statuses(
+                self.dependency_listeners(&SetupPhase::Wallet),
+            )
             .build::<WalletSetupPhase>(
                 app_handle.clone(),
                 self.wallet_phase_status.clone(),
@@ -424,10 +573,9 @@ impl SetupManager {
         let setup_features = self.features.read().await.clone();
         let mining_phase_setup = PhaseBuilder::new()
             .with_setup_timeout_duration(Duration::from_secs(60 * 10)) // 10 minutes
-            .with_listeners_for_required_phases_statuses(vec![
-                self.node_phase_status.subscribe(),
-                self.hardware_phase_status.subscribe(),
-            ])
+            .with_listeners_for_required_phases_statuses(
+                self.dependency_listeners(&SetupPhase::Mining),
+            )
             .build::<MiningSetupPhase>(
                 app_handle.clone(),
                 self.mining_phase_status.clone(),
@@ -881,6 +1029,45 @@ impl SetupManager {
         EventsEmitter::emit_connection_status_changed(ConnectionStatusPayload::Succeed).await;
     }
 
+    /// Wallet and mining binaries would otherwise only be fetched once the node has synced,
+    /// so they are downloaded alongside the node bootstrap. Their phases skip the download later.
+    async fn spawn_binary_prefetch(&self, app_handle: AppHandle) {
+        let mut binaries = vec![Binaries::MergeMiningProxy, Binaries::ShaP2pool];
+        let state = app_handle.state::<UniverseAppState>();
+        if state.in_memory_config.read().await.exchange_id == DEFAULT_EXCHANGE_ID {
+            binaries.push(Binaries::Wallet);
+        }
+        let mut core_status = self.core_phase_status.subscribe();
+        let mut shutdown_signal = TasksTrackers::current().core_phase.get_signal().await;
+        TasksTrackers::current()
+            .core_phase
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                select! {
+                    _ = core_status.wait_for(|status| status.is_success()) => {}
+                    _ = shutdown_signal.wait() => {
+                        return;
+                    }
+                }
+                let progress = ProgressTracker::new(app_handle, None);
+                let binary_resolver = BinaryResolver::current().read().await;
+                let downloads = binaries.into_iter().map(|binary| {
+                    let progress = progress.clone();
+                    let binary_resolver = &binary_resolver;
+                    async move {
+                        if let Err(e) = binary_resolver.initialize_binary(binary, progress).await {
+                            warn!(target: LOG_TARGET, "Prefetching {} failed, its phase will retry: {:?}", binary.name(), e);
+                        }
+                    }
+                });
+                select! {
+                    _ = join_all(downloads) => {}
+                    _ = shutdown_signal.wait() => {}
+                }
+            });
+    }
+
     pub async fn start_setup(&self, app_handle: AppHandle) {
         self.await_selected_exchange_miner(app_handle.clone()).await;
         self.pre_setup(app_handle.clone()).await;
@@ -888,16 +1075,43 @@ impl SetupManager {
             .await
             .inspect_err(|e| error!(target: LOG_TARGET, "Failed to set setup features during start_setup: {}", e));
         *self.app_handle.lock().await = Some(app_handle.clone());
+        self.spawn_phase_status_forwarding().await;
+
+        if DemoMode::is_enabled() {
+            self.start_demo_setup(app_handle).await;
+            return;
+        }
 
         self.wait_for_unlock_conditions(app_handle.clone()).await;
 
         self.setup_core_phase(app_handle.clone()).await;
+        self.spawn_binary_prefetch(app_handle.clone()).await;
         self.setup_hardware_phase(app_handle.clone()).await;
         self.setup_node_phase(app_handle.clone()).await;
         self.setup_wallet_phase(app_handle.clone()).await;
         self.setup_mining_phase(app_handle.clone()).await;
     }
 
+    /// Demo sessions only run the core phase, the node, wallet and miners are simulated
+    async fn start_demo_setup(&self, app_handle: AppHandle) {
+        info!(target: LOG_TARGET, "Starting demo setup");
+        self.setup_core_phase(app_handle.clone()).await;
+
+        EventsEmitter::emit_hardware_phase_finished(true).await;
+        EventsEmitter::emit_node_phase_finished(true).await;
+        EventsEmitter::emit_wallet_phase_finished(true).await;
+        EventsEmitter::emit_mining_phase_finished(true).await;
+        EventsEmitter::emit_wallet_address_update(DemoMode::tari_address(), true).await;
+
+        self.unlock_app(app_handle).await;
+        self.unlock_wallet().await;
+        self.unlock_cpu_mining().await;
+        self.unlock_gpu_mining().await;
+        EventsEmitter::emit_initial_setup_finished().await;
+
+        DemoMode::spawn().await;
+    }
+
     async fn await_selected_exchange_miner(&self, app_handle: AppHandle) {
         let state = app_handle.state::<UniverseAppState>();
         let memory_config = state.in_memory_config.read().await;
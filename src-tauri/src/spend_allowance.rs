@@ -0,0 +1,188 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::LazyLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::info;
+use serde::Serialize;
+use tari_core::transactions::tari_amount::MicroMinotari;
+use tokio::sync::Mutex;
+
+use crate::spend_wallet_manager::{parse_send_amount, SpendWalletError};
+
+const LOG_TARGET: &str = "tari::universe::spend_allowance";
+const MAX_ALLOWANCE_HOURS: u64 = 24 * 7;
+
+static ALLOWANCE: LazyLock<Mutex<Option<SpendAllowance>>> = LazyLock::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SpendAllowanceStatus {
+    pub limit: MicroMinotari,
+    pub spent: MicroMinotari,
+    pub remaining: MicroMinotari,
+    pub expires_at: u64,
+}
+
+#[derive(Debug, Clone)]
+struct SpendAllowance {
+    limit: MicroMinotari,
+    spent: MicroMinotari,
+    expires_at: SystemTime,
+}
+
+impl SpendAllowance {
+    fn remaining(&self) -> MicroMinotari {
+        MicroMinotari(self.limit.as_u64().saturating_sub(self.spent.as_u64()))
+    }
+
+    fn is_expired(&self, now: SystemTime) -> bool {
+        now >= self.expires_at
+    }
+
+    fn try_consume(&mut self, amount: MicroMinotari, now: SystemTime) -> Result<(), String> {
+        if self.is_expired(now) {
+            return Err("Spend allowance has expired".to_string());
+        }
+        if amount > self.remaining() {
+            return Err(format!(
+                "Spend allowance exhausted: {} requested, {} remaining",
+                amount,
+                self.remaining()
+            ));
+        }
+        self.spent += amount;
+        Ok(())
+    }
+
+    fn refund(&mut self, amount: MicroMinotari) {
+        self.spent = MicroMinotari(self.spent.as_u64().saturating_sub(amount.as_u64()));
+    }
+
+    fn status(&self) -> SpendAllowanceStatus {
+        SpendAllowanceStatus {
+            limit: self.limit,
+            spent: self.spent,
+            remaining: self.remaining(),
+            expires_at: self
+                .expires_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        }
+    }
+}
+
+/// Lets the owner pre-approve spending up to a fixed amount for a limited time, so a guest
+/// session can send without the admin PIN until the allowance runs out or expires.
+/// Kept in memory only, a restart always revokes it.
+pub struct SpendAllowances;
+
+impl SpendAllowances {
+    pub async fn grant(amount: &str, hours: u64) -> Result<SpendAllowanceStatus, SpendWalletError> {
+        if !(1..=MAX_ALLOWANCE_HOURS).contains(&hours) {
+            return Err(SpendWalletError::InvalidAmount(format!(
+                "Allowance duration must be between 1 and {} hours",
+                MAX_ALLOWANCE_HOURS
+            )));
+        }
+        let limit = parse_send_amount(amount)?;
+        let allowance = SpendAllowance {
+            limit,
+            spent: MicroMinotari(0),
+            expires_at: SystemTime::now() + Duration::from_secs(hours * 60 * 60),
+        };
+        info!(target: LOG_TARGET, "Granted spend allowance of {} for {} hours", limit, hours);
+        let status = allowance.status();
+        *ALLOWANCE.lock().await = Some(allowance);
+        Ok(status)
+    }
+
+    pub async fn revoke() {
+        info!(target: LOG_TARGET, "Spend allowance revoked");
+        *ALLOWANCE.lock().await = None;
+    }
+
+    /// `None` when no allowance is active, expired allowances are dropped here
+    pub async fn status() -> Option<SpendAllowanceStatus> {
+        let mut allowance = ALLOWANCE.lock().await;
+        if allowance
+            .as_ref()
+            .is_some_and(|a| a.is_expired(SystemTime::now()))
+        {
+            *allowance = None;
+        }
+        allowance.as_ref().map(SpendAllowance::status)
+    }
+
+    pub async fn consume(amount: MicroMinotari) -> Result<(), SpendWalletError> {
+        ALLOWANCE
+            .lock()
+            .await
+            .as_mut()
+            .ok_or_else(|| "No spend allowance has been granted".to_string())
+            .and_then(|allowance| allowance.try_consume(amount, SystemTime::now()))
+            .map_err(SpendWalletError::PermissionDenied)
+    }
+
+    /// Gives back an amount consumed for a send that did not go through
+    pub async fn refund(amount: MicroMinotari) {
+        if let Some(allowance) = ALLOWANCE.lock().await.as_mut() {
+            allowance.refund(amount);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowance(limit: u64) -> SpendAllowance {
+        SpendAllowance {
+            limit: MicroMinotari(limit),
+            spent: MicroMinotari(0),
+            expires_at: SystemTime::now() + Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn consumes_until_exhausted() {
+        let mut allowance = allowance(1_000);
+        let now = SystemTime::now();
+        assert!(allowance.try_consume(MicroMinotari(600), now).is_ok());
+        assert!(allowance.try_consume(MicroMinotari(500), now).is_err());
+        assert!(allowance.try_consume(MicroMinotari(400), now).is_ok());
+        assert_eq!(allowance.remaining(), MicroMinotari(0));
+
+        allowance.refund(MicroMinotari(400));
+        assert_eq!(allowance.remaining(), MicroMinotari(400));
+    }
+
+    #[test]
+    fn expired_allowance_cannot_be_spent() {
+        let mut allowance = allowance(1_000);
+        let later = SystemTime::now() + Duration::from_secs(120);
+        assert!(allowance.try_consume(MicroMinotari(1), later).is_err());
+    }
+}
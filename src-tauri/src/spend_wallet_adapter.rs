@@ -27,6 +27,7 @@ use crate::process_adapter::{
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::file_utils::convert_to_string;
 use crate::utils::logging_utils::setup_logging;
+use crate::wallet_manager::WalletManagerError;
 use crate::UniverseAppState;
 use crate::{internal_wallet::InternalWallet, process_adapter::HealthStatus};
 use anyhow::Error;
@@ -49,6 +50,24 @@ use tonic::async_trait;
 
 const LOG_TARGET: &str = "tari::universe::spend_wallet_adapter";
 
+#[derive(Debug, thiserror::Error)]
+pub enum SpendWalletAdapterError {
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
+    #[error("Command '{command}' failed with exit code: {exit_code}")]
+    CommandFailed {
+        command: String,
+        exit_code: i32,
+        stderr: String,
+    },
+    #[error("Transaction ID not found in the command output: {0}")]
+    MissingTransactionId(String),
+    #[error("Failed to import the transaction into the view wallet: {0}")]
+    ImportFailed(#[from] WalletManagerError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
 #[derive(Clone)]
 pub struct SpendWalletAdapter {
     pub(crate) base_node_public_key: Option<RistrettoPublicKey>,
@@ -119,11 +138,12 @@ impl SpendWalletAdapter {
         destination: String,
         payment_id: Option<String>,
         state: tauri::State<'_, UniverseAppState>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), SpendWalletAdapterError> {
         let seed_words = self
             .get_seed_words(self.get_config_dir(), state.clone())
             .await?;
-        let t_amount = Minotari::from_str(_amount.as_str())?;
+        let t_amount = Minotari::from_str(_amount.as_str())
+            .map_err(|e| SpendWalletAdapterError::InvalidAmount(e.to_string()))?;
         let converted_amount = MicroMinotari::from(t_amount);
         let amount = converted_amount.to_string();
 
@@ -134,15 +154,11 @@ impl SpendWalletAdapter {
             .execute_send_one_sided_command(&amount, &destination, payment_id)
             .await?;
 
-        if let Some(tx_id) = tx_id {
-            let exported_tx_path = self.export_transaction(&tx_id).await?;
-            state
-                .wallet_manager
-                .import_transaction(exported_tx_path)
-                .await?;
-        } else {
-            return Err(anyhow::anyhow!("Failed to extract Transaction ID"));
-        }
+        let exported_tx_path = self.export_transaction(&tx_id).await?;
+        state
+            .wallet_manager
+            .import_transaction(exported_tx_path)
+            .await?;
 
         Ok(())
     }
@@ -150,7 +166,7 @@ impl SpendWalletAdapter {
     async fn execute_recovery_command(
         &self,
         seed_words: &str,
-    ) -> Result<(i32, Vec<String>, Vec<String>), Error> {
+    ) -> Result<(i32, Vec<String>, Vec<String>), SpendWalletAdapterError> {
         let base_node_public_key = self.get_base_node_public_key_hex();
         let base_node_address = self.get_base_node_address();
         let command = ExecutionCommand::new("recovery")
@@ -170,7 +186,9 @@ impl SpendWalletAdapter {
         self.execute_command(command, vec![0, 109]).await
     }
 
-    async fn execute_sync_command(&self) -> Result<(i32, Vec<String>, Vec<String>), Error> {
+    async fn execute_sync_command(
+        &self,
+    ) -> Result<(i32, Vec<String>, Vec<String>), SpendWalletAdapterError> {
         let base_node_public_key = self.get_base_node_public_key_hex();
         let base_node_address = self.get_base_node_address();
         let command = ExecutionCommand::new("sync").with_extra_args(vec![
@@ -190,7 +208,7 @@ impl SpendWalletAdapter {
         amount: &str,
         destination: &str,
         payment_id: Option<String>,
-    ) -> Result<Option<String>, Error> {
+    ) -> Result<String, SpendWalletAdapterError> {
         // Allocate an unused port to ensure the transaction is not successfully broadcasted.
         // This is intentional as we want to export the transaction to the view wallet instead.
         let fake_base_node_public_key = self.get_base_node_public_key_hex();
@@ -219,22 +237,24 @@ impl SpendWalletAdapter {
             .iter()
             .find(|line| line.starts_with("Transaction ID:"))
             .and_then(|line| line.split("Transaction ID: ").nth(1))
-            .map(|id| id.trim());
+            .map(|id| id.trim().to_string());
 
-        if tx_id.is_none() {
+        let Some(tx_id) = tx_id else {
             log::error!(
                 target: LOG_TARGET,
                 "Transaction ID not found. Details: {{ stdout_lines: {:?}, stderr_lines: {:?} }}",
                 stdout_lines.join("\n"),
                 stderr_lines.join("\n"),
             );
-            return Err(anyhow::anyhow!(stderr_lines.join(" | ")));
+            return Err(SpendWalletAdapterError::MissingTransactionId(
+                stderr_lines.join(" | "),
+            ));
         };
 
-        Ok(tx_id.map(|id| id.to_string()))
+        Ok(tx_id)
     }
 
-    pub async fn export_transaction(&self, tx_id: &str) -> Result<PathBuf, Error> {
+    async fn export_transaction(&self, tx_id: &str) -> Result<PathBuf, SpendWalletAdapterError> {
         let fake_base_node_public_key = self.get_base_node_public_key_hex();
         let fake_base_node_address = format!(
             "/ip4/127.0.0.1/tcp/{:?}",
@@ -254,16 +274,7 @@ impl SpendWalletAdapter {
             tx_id.to_string(),
         ]);
 
-        let (exit_code, _stdout_lines, _stderr_lines) =
-            self.execute_command(command, vec![0]).await?;
-
-        if exit_code != 0 {
-            return Err(anyhow::anyhow!(
-                "Failed to export transaction with ID {}. Exit code: {}",
-                tx_id,
-                exit_code
-            ));
-        }
+        self.execute_command(command, vec![0]).await?;
 
         Ok(output_path)
     }
@@ -272,7 +283,7 @@ impl SpendWalletAdapter {
         &self,
         command: ExecutionCommand,
         allow_exit_codes: Vec<i32>,
-    ) -> Result<(i32, Vec<String>, Vec<String>), Error> {
+    ) -> Result<(i32, Vec<String>, Vec<String>), SpendWalletAdapterError> {
         let (mut instance, _monitor) = self.spawn(
             self.get_data_dir(),
             self.get_config_dir(),
@@ -353,11 +364,11 @@ impl SpendWalletAdapter {
             //     command.extra_args
             // );
 
-            return Err(anyhow::anyhow!(
-                "Command '{}' failed with exit code: {}",
-                command.name,
-                exit_code
-            ));
+            return Err(SpendWalletAdapterError::CommandFailed {
+                command: command.name,
+                exit_code,
+                stderr: stderr_lines.join("\n"),
+            });
         }
 
         if exit_code == 109 {
@@ -22,21 +22,294 @@
 
 use crate::binaries::Binaries;
 use crate::binaries::BinaryResolver;
+use crate::idempotency::{IdempotencyKeyReused, IdempotentResult};
 use crate::node::node_manager::NodeManager;
-use crate::spend_wallet_adapter::SpendWalletAdapter;
+use crate::spend_wallet_adapter::{SpendWalletAdapter, SpendWalletAdapterError};
 use crate::tasks_tracker::TasksTrackers;
+use crate::wallet_adapter::WalletBalance;
+use crate::wallet_manager::WalletManagerError;
 use crate::BaseNodeStatus;
 use crate::UniverseAppState;
 use anyhow::Error;
 use log::{debug, info};
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
+use tari_common::exit_codes::ExitCode;
+use tari_core::transactions::{
+    fee::Fee,
+    tari_amount::{MicroMinotari, Minotari},
+    weight::TransactionWeight,
+};
 use tari_shutdown::ShutdownSignal;
 use tokio::sync::watch::{self};
 use tokio::task::JoinHandle;
+use tonic::{Code, Status};
 
 const LOG_TARGET: &str = "tari::universe::spend_wallet_manager";
 const BLOCKS_THRESHOLD: u64 = 5;
+/// Fee per gram the console wallet builds sends with when none is given
+const SEND_FEE_PER_GRAM: MicroMinotari = MicroMinotari(5);
+/// Payment, and change when there is any
+const SEND_OUTPUT_COUNT: usize = 2;
+/// Features and script of a one-sided stealth output
+const SEND_OUTPUT_FEATURES_AND_SCRIPT_SIZE: usize = 70;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SpendWalletError {
+    #[error("insufficient-funds | {0}")]
+    InsufficientFunds(String),
+    #[error("output-locked | {0}")]
+    OutputLocked(String),
+    #[error("node-unreachable | {0}")]
+    NodeUnreachable(String),
+    #[error("wallet-unavailable | {0}")]
+    WalletUnavailable(String),
+    #[error("invalid-amount | {0}")]
+    InvalidAmount(String),
+    #[error("invalid-address | {0}")]
+    InvalidAddress(String),
+    #[error("operations-halted | {0}")]
+    OperationsHalted(String),
+    #[error("permission-denied | {0}")]
+    PermissionDenied(String),
+    #[error("idempotency-key-reused | {0}")]
+    IdempotencyKeyReused(String),
+    #[error("unknown-error | {0}")]
+    Unknown(String),
+}
+
+impl SpendWalletError {
+    /// Stable identifier the frontend branches on
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SpendWalletError::InsufficientFunds(_) => "insufficient-funds",
+            SpendWalletError::OutputLocked(_) => "output-locked",
+            SpendWalletError::NodeUnreachable(_) => "node-unreachable",
+            SpendWalletError::WalletUnavailable(_) => "wallet-unavailable",
+            SpendWalletError::InvalidAmount(_) => "invalid-amount",
+            SpendWalletError::InvalidAddress(_) => "invalid-address",
+            SpendWalletError::OperationsHalted(_) => "operations-halted",
+            SpendWalletError::PermissionDenied(_) => "permission-denied",
+            SpendWalletError::IdempotencyKeyReused(_) => "idempotency-key-reused",
+            SpendWalletError::Unknown(_) => "unknown-error",
+        }
+    }
+
+    /// Numeric code for the kind, kept stable across releases
+    pub fn code(&self) -> u16 {
+        match self {
+            SpendWalletError::InsufficientFunds(_) => 1,
+            SpendWalletError::OutputLocked(_) => 2,
+            SpendWalletError::NodeUnreachable(_) => 3,
+            SpendWalletError::WalletUnavailable(_) => 4,
+            SpendWalletError::InvalidAmount(_) => 5,
+            SpendWalletError::InvalidAddress(_) => 6,
+            SpendWalletError::OperationsHalted(_) => 7,
+            SpendWalletError::PermissionDenied(_) => 8,
+            SpendWalletError::IdempotencyKeyReused(_) => 9,
+            SpendWalletError::Unknown(_) => 99,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            SpendWalletError::InsufficientFunds(message)
+            | SpendWalletError::OutputLocked(message)
+            | SpendWalletError::NodeUnreachable(message)
+            | SpendWalletError::WalletUnavailable(message)
+            | SpendWalletError::InvalidAmount(message)
+            | SpendWalletError::InvalidAddress(message)
+            | SpendWalletError::OperationsHalted(message)
+            | SpendWalletError::PermissionDenied(message)
+            | SpendWalletError::IdempotencyKeyReused(message)
+            | SpendWalletError::Unknown(message) => message,
+        }
+    }
+}
+
+/// The cli exits with a generic command error when the output manager refuses a send, so
+/// the wallet error is picked out of its stderr. Both the message and the variant name are
+/// matched since the cli prints either depending on how the error is formatted.
+fn classify_wallet_stderr(stderr: &str, message: String) -> SpendWalletError {
+    let stderr = stderr.to_lowercase();
+    if stderr.contains("fundspending") || stderr.contains("funds pending") {
+        SpendWalletError::OutputLocked(message)
+    } else if stderr.contains("notenoughfunds")
+        || stderr.contains("not enough funds")
+        || stderr.contains("fundsnotavailable")
+    {
+        SpendWalletError::InsufficientFunds(message)
+    } else {
+        SpendWalletError::Unknown(message)
+    }
+}
+
+impl From<IdempotencyKeyReused> for SpendWalletError {
+    fn from(error: IdempotencyKeyReused) -> Self {
+        SpendWalletError::IdempotencyKeyReused(error.to_string())
+    }
+}
+
+impl IdempotentResult for Result<(), SpendWalletError> {
+    /// Failures that may clear up on their own are retried instead of replayed. Anything
+    /// else, including unknown errors where the send may have gone out, is final.
+    fn is_terminal(&self) -> bool {
+        !matches!(
+            self,
+            Err(SpendWalletError::NodeUnreachable(_)
+                | SpendWalletError::WalletUnavailable(_)
+                | SpendWalletError::OutputLocked(_)
+                | SpendWalletError::OperationsHalted(_)
+                | SpendWalletError::PermissionDenied(_))
+        )
+    }
+}
+
+impl From<SpendWalletAdapterError> for SpendWalletError {
+    /// Maps the wallet cli exit codes and errors and the view wallet gRPC status codes onto a
+    /// known failure kind. The cli signs the transaction against an unreachable node, so nothing has
+    /// been broadcast unless the view wallet import went through.
+    fn from(error: SpendWalletAdapterError) -> Self {
+        let message = error.to_string();
+        match error {
+            SpendWalletAdapterError::InvalidAmount(_) => SpendWalletError::InvalidAmount(message),
+            SpendWalletAdapterError::CommandFailed { exit_code, .. }
+                if exit_code == ExitCode::NetworkError as i32
+                    || exit_code == ExitCode::TorOffline as i32 =>
+            {
+                SpendWalletError::NodeUnreachable(message)
+            }
+            SpendWalletAdapterError::CommandFailed { exit_code, .. }
+                if exit_code == ExitCode::IncorrectOrEmptyPassword as i32
+                    || exit_code == ExitCode::DatabaseError as i32
+                    || exit_code == ExitCode::IOError as i32 =>
+            {
+                SpendWalletError::WalletUnavailable(message)
+            }
+            SpendWalletAdapterError::CommandFailed { stderr, .. } => {
+                classify_wallet_stderr(&stderr, message)
+            }
+            SpendWalletAdapterError::ImportFailed(
+                WalletManagerError::WalletNotStarted
+                | WalletManagerError::TemporarilyUnavailable(_),
+            ) => SpendWalletError::WalletUnavailable(message),
+            SpendWalletAdapterError::ImportFailed(WalletManagerError::UnknownError(e)) => {
+                match e
+                    .chain()
+                    .find_map(|e| e.downcast_ref::<Status>())
+                    .map(Status::code)
+                {
+                    Some(Code::Unavailable | Code::DeadlineExceeded) => {
+                        SpendWalletError::WalletUnavailable(message)
+                    }
+                    Some(Code::FailedPrecondition) => SpendWalletError::OutputLocked(message),
+                    Some(Code::ResourceExhausted) => SpendWalletError::InsufficientFunds(message),
+                    _ => SpendWalletError::Unknown(message),
+                }
+            }
+            _ => SpendWalletError::Unknown(message),
+        }
+    }
+}
+
+pub fn parse_send_amount(amount: &str) -> Result<MicroMinotari, SpendWalletError> {
+    Minotari::from_str(amount)
+        .map(MicroMinotari::from)
+        .map_err(|e| SpendWalletError::InvalidAmount(e.to_string()))
+}
+
+/// Fee for sending `amount` out of the wallet's unspent outputs, or all of them when `amount`
+/// is `None`. Inputs are taken smallest first, which is the most the wallet would spend, so
+/// the estimate errs on the high side.
+pub fn estimate_send_fee(
+    unspent_amounts: &[MicroMinotari],
+    amount: Option<MicroMinotari>,
+) -> MicroMinotari {
+    let fee = Fee::new(TransactionWeight::latest());
+    let mut sorted_amounts = unspent_amounts.to_vec();
+    sorted_amounts.sort();
+    let mut input_count = 0;
+    let mut selected = MicroMinotari(0);
+    for unspent in sorted_amounts {
+        if let Some(amount) = amount {
+            let fee_so_far = fee.calculate(
+                SEND_FEE_PER_GRAM,
+                1,
+                input_count,
+                SEND_OUTPUT_COUNT,
+                SEND_OUTPUT_COUNT * SEND_OUTPUT_FEATURES_AND_SCRIPT_SIZE,
+            );
+            if input_count > 0 && selected >= amount + fee_so_far {
+                break;
+            }
+        }
+        selected += unspent;
+        input_count += 1;
+    }
+    fee.calculate(
+        SEND_FEE_PER_GRAM,
+        1,
+        input_count.max(1),
+        SEND_OUTPUT_COUNT,
+        SEND_OUTPUT_COUNT * SEND_OUTPUT_FEATURES_AND_SCRIPT_SIZE,
+    )
+}
+
+/// Checks the spendable balance covers the amount and the estimated fee before a
+/// transaction is built. Timelocked and pending funds are not spendable.
+pub fn check_sufficient_funds(
+    balance: &WalletBalance,
+    amount: MicroMinotari,
+    fee: MicroMinotari,
+) -> Result<(), SpendWalletError> {
+    let required = amount + fee;
+    if balance.available_balance < required {
+        let shortfall = required - balance.available_balance;
+        return Err(SpendWalletError::InsufficientFunds(format!(
+            "required: {}, available: {}, shortfall: {}",
+            required, balance.available_balance, shortfall
+        )));
+    }
+    Ok(())
+}
+
+/// Amount that empties the spendable balance once the fee for spending every output is
+/// taken off
+pub fn sweep_amount(
+    balance: &WalletBalance,
+    fee: MicroMinotari,
+) -> Result<MicroMinotari, SpendWalletError> {
+    if balance.available_balance <= fee {
+        return Err(SpendWalletError::InsufficientFunds(format!(
+            "available: {} does not cover the estimated fee: {}",
+            balance.available_balance, fee
+        )));
+    }
+    Ok(balance.available_balance - fee)
+}
+
+/// Formats an amount the way `parse_send_amount` reads it back, without losing precision
+pub fn format_send_amount(amount: MicroMinotari) -> String {
+    let micro = amount.as_u64();
+    format!("{}.{:06}", micro / 1_000_000, micro % 1_000_000)
+}
+
+/// Sent to the frontend as `{ code, kind, message }`
+impl serde::Serialize for SpendWalletError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("SpendWalletError", 3)?;
+        state.serialize_field("code", &self.code())?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", self.message())?;
+        state.end()
+    }
+}
 
 pub struct SpendWalletManager {
     adapter: SpendWalletAdapter,
@@ -124,23 +397,33 @@ impl SpendWalletManager {
         destination: String,
         payment_id: Option<String>,
         state: tauri::State<'_, UniverseAppState>,
-    ) -> Result<(), Error> {
-        self.node_manager.wait_ready().await?;
-        let (public_key, public_address) = self.node_manager.get_connection_details().await?;
+    ) -> Result<(), SpendWalletError> {
+        self.node_manager
+            .wait_ready()
+            .await
+            .map_err(|e| SpendWalletError::NodeUnreachable(e.to_string()))?;
+        let (public_key, public_address) = self
+            .node_manager
+            .get_connection_details()
+            .await
+            .map_err(|e| SpendWalletError::NodeUnreachable(e.to_string()))?;
         self.adapter.base_node_public_key = Some(public_key.clone());
         self.adapter.base_node_address = Some(public_address.clone());
         info!(target: LOG_TARGET, "[send_one_sided_to_stealth_address] with node {:?}:{:?}", public_key, public_address);
 
         // Prevent from erasing wallet data when sending in progress
-        self.set_next_wallet_data_erasure_block(None)?;
+        self.set_next_wallet_data_erasure_block(None)
+            .map_err(|e| SpendWalletError::Unknown(e.to_string()))?;
 
         let res = self
             .adapter
             .send_one_sided_to_stealth_address(amount, destination, payment_id, state)
-            .await;
+            .await
+            .map_err(SpendWalletError::from);
 
         let node_status = *self.base_node_status_rx.borrow();
-        self.set_next_wallet_data_erasure_block(Some(node_status.block_height + BLOCKS_THRESHOLD))?;
+        self.set_next_wallet_data_erasure_block(Some(node_status.block_height + BLOCKS_THRESHOLD))
+            .map_err(|e| SpendWalletError::Unknown(e.to_string()))?;
 
         res
     }
@@ -214,3 +497,139 @@ impl SpendWalletManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet_adapter::WalletStatusMonitorError;
+
+    #[test]
+    fn test_from_adapter_error() {
+        let command_failed = |exit_code: ExitCode| SpendWalletAdapterError::CommandFailed {
+            command: "send-one-sided".to_string(),
+            exit_code: exit_code as i32,
+            stderr: String::new(),
+        };
+        let command_error = |stderr: &str| SpendWalletAdapterError::CommandFailed {
+            command: "send-one-sided".to_string(),
+            exit_code: ExitCode::CommandError as i32,
+            stderr: stderr.to_string(),
+        };
+        assert!(matches!(
+            SpendWalletError::from(SpendWalletAdapterError::InvalidAmount("1.x".to_string())),
+            SpendWalletError::InvalidAmount(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(command_failed(ExitCode::NetworkError)),
+            SpendWalletError::NodeUnreachable(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(command_failed(ExitCode::DatabaseError)),
+            SpendWalletError::WalletUnavailable(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(command_failed(ExitCode::CommandError)),
+            SpendWalletError::Unknown(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(command_error(
+                "Error: TransactionServiceError(OutputManagerError(FundsPending))"
+            )),
+            SpendWalletError::OutputLocked(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(command_error("Not enough funds to cover the amount")),
+            SpendWalletError::InsufficientFunds(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(SpendWalletAdapterError::ImportFailed(
+                WalletManagerError::UnknownError(Status::failed_precondition("locked").into())
+            )),
+            SpendWalletError::OutputLocked(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(SpendWalletAdapterError::ImportFailed(
+                WalletManagerError::UnknownError(Status::resource_exhausted("funds").into())
+            )),
+            SpendWalletError::InsufficientFunds(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(SpendWalletAdapterError::ImportFailed(
+                WalletManagerError::UnknownError(anyhow::Error::new(
+                    WalletStatusMonitorError::UnknownError(
+                        Status::unavailable("restarting").into()
+                    )
+                ))
+            )),
+            SpendWalletError::WalletUnavailable(_)
+        ));
+        assert!(matches!(
+            SpendWalletError::from(SpendWalletAdapterError::ImportFailed(
+                WalletManagerError::UnknownError(Status::internal("rejected").into())
+            )),
+            SpendWalletError::Unknown(_)
+        ));
+    }
+
+    fn balance(available: u64) -> WalletBalance {
+        WalletBalance {
+            available_balance: MicroMinotari(available),
+            timelocked_balance: MicroMinotari(1_000_000_000),
+            pending_incoming_balance: MicroMinotari(0),
+            pending_outgoing_balance: MicroMinotari(0),
+        }
+    }
+
+    #[test]
+    fn test_check_sufficient_funds() {
+        let fee = MicroMinotari(1_000);
+        assert!(check_sufficient_funds(&balance(2_000_000), MicroMinotari(1_000_000), fee).is_ok());
+
+        let error = check_sufficient_funds(&balance(1_000_000), MicroMinotari(1_000_000), fee);
+        match error {
+            Err(SpendWalletError::InsufficientFunds(details)) => {
+                assert!(details.contains(&format!("shortfall: {}", fee)));
+            }
+            _ => panic!("Expected insufficient funds"),
+        }
+    }
+
+    #[test]
+    fn test_sweep_amount() {
+        let fee = MicroMinotari(1_000);
+        let amount = sweep_amount(&balance(2_500_000), fee).unwrap_or_default();
+        assert_eq!(amount, MicroMinotari(2_500_000) - fee);
+        assert!(check_sufficient_funds(&balance(2_500_000), amount, fee).is_ok());
+        assert!(sweep_amount(&balance(100), fee).is_err());
+    }
+
+    #[test]
+    fn test_fee_grows_with_inputs_spent() {
+        let unspent = vec![MicroMinotari(1_000_000); 10];
+        let single_input = estimate_send_fee(&unspent, Some(MicroMinotari(500_000)));
+        let several_inputs = estimate_send_fee(&unspent, Some(MicroMinotari(3_500_000)));
+        let all_inputs = estimate_send_fee(&unspent, None);
+        assert!(single_input > MicroMinotari(0));
+        assert!(single_input < several_inputs);
+        assert!(several_inputs < all_inputs);
+    }
+
+    #[test]
+    fn test_format_send_amount_round_trip() {
+        let amount = MicroMinotari(12_000_345);
+        assert_eq!(format_send_amount(amount), "12.000345");
+        assert_eq!(
+            parse_send_amount(&format_send_amount(amount)).unwrap_or_default(),
+            amount
+        );
+    }
+
+    #[test]
+    fn test_serialized_error_has_code_kind_and_message() {
+        let error = SpendWalletError::OutputLocked("funds pending".to_string());
+        assert_eq!(
+            serde_json::to_value(&error).unwrap_or_default(),
+            serde_json::json!({ "code": 2, "kind": "output-locked", "message": "funds pending" })
+        );
+    }
+}
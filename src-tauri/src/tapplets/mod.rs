@@ -25,7 +25,10 @@ mod tapplets_manager;
 
 pub mod error;
 pub mod interface;
+pub mod tapplet_deep_link;
+pub mod tapplet_registry;
 pub mod tapplet_server;
+pub mod tapplet_storage;
 pub mod tapplets_list;
 pub mod tapplets_resolver;
 
@@ -0,0 +1,180 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use anyhow::{anyhow, Error};
+use log::{error, info, warn};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, Url};
+
+use crate::events_emitter::EventsEmitter;
+
+use super::{TappletResolver, Tapplets};
+
+const LOG_TARGET: &str = "tari::universe::tapplet_deep_link";
+const DEEP_LINK_SCHEME: &str = "tari";
+const DEEP_LINK_HOST: &str = "tapplet";
+const MAX_PARAMS: usize = 16;
+const MAX_PARAM_KEY_LENGTH: usize = 64;
+const MAX_PARAM_VALUE_LENGTH: usize = 512;
+const MAX_PATH_LENGTH: usize = 256;
+
+/// A validated `tari://tapplet/<name>/<path>?<params>` link, forwarded to the frontend
+/// which opens the tapplet or asks the user before installing it.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TappletDeepLink {
+    pub tapplet: String,
+    pub path: String,
+    pub params: Vec<(String, String)>,
+    pub requires_install: bool,
+}
+
+fn is_valid_param_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_PARAM_KEY_LENGTH
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+}
+
+fn is_valid_param_value(value: &str) -> bool {
+    value.len() <= MAX_PARAM_VALUE_LENGTH && !value.chars().any(char::is_control)
+}
+
+fn is_valid_path_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment != "."
+        && segment != ".."
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+pub fn parse_deep_link(url: &Url) -> Result<(Tapplets, TappletDeepLink), Error> {
+    if url.scheme() != DEEP_LINK_SCHEME || url.host_str() != Some(DEEP_LINK_HOST) {
+        return Err(anyhow!("Not a tapplet deep link: {}", url));
+    }
+
+    let mut segments = url
+        .path_segments()
+        .ok_or_else(|| anyhow!("Deep link is missing a tapplet name"))?
+        .filter(|segment| !segment.is_empty());
+    let name = segments
+        .next()
+        .ok_or_else(|| anyhow!("Deep link is missing a tapplet name"))?;
+    let tapplet = Tapplets::from_name(name)
+        .ok_or_else(|| anyhow!("Unknown tapplet in deep link: {}", name))?;
+
+    let path_segments: Vec<&str> = segments.collect();
+    if !path_segments
+        .iter()
+        .all(|segment| is_valid_path_segment(segment))
+    {
+        return Err(anyhow!("Invalid path in deep link: {}", url.path()));
+    }
+    let path = path_segments.join("/");
+    if path.len() > MAX_PATH_LENGTH {
+        return Err(anyhow!("Deep link path is too long"));
+    }
+
+    let params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    if params.len() > MAX_PARAMS {
+        return Err(anyhow!("Deep link has too many parameters"));
+    }
+    if let Some((key, _)) = params
+        .iter()
+        .find(|(key, value)| !is_valid_param_key(key) || !is_valid_param_value(value))
+    {
+        return Err(anyhow!("Invalid deep link parameter: {}", key));
+    }
+
+    Ok((
+        tapplet,
+        TappletDeepLink {
+            tapplet: tapplet.name().to_string(),
+            path,
+            params,
+            requires_install: false,
+        },
+    ))
+}
+
+pub async fn handle_deep_link(app_handle: &AppHandle, url: Url) {
+    let (tapplet, mut deep_link) = match parse_deep_link(&url) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            warn!(target: LOG_TARGET, "Ignoring deep link: {}", e);
+            return;
+        }
+    };
+    info!(target: LOG_TARGET, "Routing deep link to tapplet {}", deep_link.tapplet);
+
+    deep_link.requires_install = TappletResolver::current()
+        .read()
+        .await
+        .get_tapplet_version(tapplet)
+        .await
+        .is_none();
+
+    if let Some(window) = app_handle.get_webview_window("main") {
+        let _unused = window
+            .show()
+            .and_then(|_| window.set_focus())
+            .inspect_err(|e| error!(target: LOG_TARGET, "Could not focus main window: {:?}", e));
+    }
+    EventsEmitter::emit_tapplet_deep_link(deep_link).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(link: &str) -> Option<TappletDeepLink> {
+        let url = Url::parse(link).ok()?;
+        parse_deep_link(&url).ok().map(|(_, deep_link)| deep_link)
+    }
+
+    #[test]
+    fn parses_tapplet_path_and_params() {
+        let Some(deep_link) = parse("tari://tapplet/bridge/swap?amount=10&to=eth") else {
+            panic!("expected a valid deep link");
+        };
+        assert_eq!(deep_link.tapplet, "bridge");
+        assert_eq!(deep_link.path, "swap");
+        assert_eq!(
+            deep_link.params,
+            vec![
+                ("amount".to_string(), "10".to_string()),
+                ("to".to_string(), "eth".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_or_malformed_links() {
+        assert!(parse("tari://tapplet/unknown").is_none());
+        assert!(parse("tari://wallet/bridge").is_none());
+        assert!(parse("https://tapplet/bridge").is_none());
+        assert!(parse("tari://tapplet/").is_none());
+        assert!(parse("tari://tapplet/bridge?bad%20key=1").is_none());
+        assert!(parse("tari://tapplet/bridge?memo=%0A").is_none());
+    }
+}
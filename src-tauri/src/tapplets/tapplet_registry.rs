@@ -0,0 +1,360 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::LazyLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use log::{error, info, warn};
+use semver::Version;
+use serde::Serialize;
+use tokio::{select, sync::RwLock};
+use tokio_util::sync::CancellationToken;
+
+use crate::events_emitter::EventsEmitter;
+use crate::tasks_tracker::TasksTrackers;
+
+use super::{tapplet_server::start_tapplet, TappletResolver, Tapplets};
+
+const LOG_TARGET: &str = "tari::universe::tapplet_registry";
+/// Automatic reloads allowed after a crash before the tapplet is left unhealthy
+const MAX_RELOAD_ATTEMPTS: u32 = 3;
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+/// A tapplet answering health checks this long has recovered and gets its reload attempts back
+const HEALTHY_PERIOD: Duration = Duration::from_secs(10 * 60);
+/// Delay before the first automatic reload, doubled for every attempt after it
+const RELOAD_BACKOFF: Duration = Duration::from_secs(5);
+
+static INSTANCE: LazyLock<RwLock<HashMap<Tapplets, TappletEntry>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TappletLifecycleStatus {
+    Installed,
+    Updated,
+    Started,
+    Crashed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TappletStatus {
+    pub tapplet: String,
+    pub status: TappletLifecycleStatus,
+    pub version: Option<String>,
+    pub source: Option<String>,
+    pub started_at: Option<u64>,
+    pub last_error: Option<String>,
+    pub is_healthy: bool,
+    pub crash_count: u32,
+    pub reload_attempts: u32,
+}
+
+impl TappletStatus {
+    fn new(tapplet: Tapplets, status: TappletLifecycleStatus) -> Self {
+        Self {
+            tapplet: tapplet.name().to_string(),
+            status,
+            version: None,
+            source: None,
+            started_at: None,
+            last_error: None,
+            is_healthy: true,
+            crash_count: 0,
+            reload_attempts: 0,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == TappletLifecycleStatus::Started
+    }
+}
+
+struct TappletEntry {
+    status: TappletStatus,
+    cancel_token: Option<CancellationToken>,
+}
+
+/// Keeps track of installed and running tapplets and emits a lifecycle event whenever
+/// one of them changes state. Running tapplets are health checked and reloaded a limited
+/// number of times if they crash or stop responding.
+pub struct TappletRegistry;
+
+impl TappletRegistry {
+    /// Starts the tapplet server, replacing any instance that is already running.
+    pub async fn launch(tapplet: Tapplets) -> Result<TappletStatus, anyhow::Error> {
+        let (tapplet_path, version) = {
+            let tapplet_resolver = TappletResolver::current().read().await;
+            (
+                tapplet_resolver
+                    .resolve_path_to_tapplet_files(tapplet)
+                    .await?,
+                tapplet_resolver.get_tapplet_version(tapplet).await,
+            )
+        };
+
+        let (addr, cancel_token) =
+            tauri::async_runtime::spawn(start_tapplet(tapplet, tapplet_path))
+                .await
+                .map_err(|e| anyhow!("Error handling tapplet start: {:?}", e))?
+                .map_err(|e| anyhow!(e.to_string()))?;
+
+        Ok(Self::record_started(
+            tapplet,
+            version.as_ref(),
+            format!("http://{}", addr),
+            cancel_token,
+        )
+        .await)
+    }
+
+    /// Manual reload offered to the user once automatic reloads are exhausted.
+    pub async fn reload(tapplet: Tapplets) -> Result<TappletStatus, anyhow::Error> {
+        if let Some(entry) = INSTANCE.write().await.get_mut(&tapplet) {
+            entry.status.reload_attempts = 0;
+        }
+        Self::launch(tapplet).await
+    }
+
+    pub async fn record_installed(tapplet: Tapplets, version: &Version, is_update: bool) {
+        let lifecycle_status = if is_update {
+            TappletLifecycleStatus::Updated
+        } else {
+            TappletLifecycleStatus::Installed
+        };
+        info!(target: LOG_TARGET, "Tapplet {} {:?} to version {}", tapplet.name(), lifecycle_status, version);
+
+        let status = {
+            let mut entries = INSTANCE.write().await;
+            let entry = entries.entry(tapplet).or_insert_with(|| TappletEntry {
+                status: TappletStatus::new(tapplet, lifecycle_status),
+                cancel_token: None,
+            });
+            let mut status = entry.status.clone();
+            status.status = lifecycle_status;
+            status.version = Some(version.to_string());
+            // A newer version on disk does not affect a server that is already running
+            if !entry.status.is_running() {
+                entry.status = status.clone();
+            }
+            status
+        };
+        EventsEmitter::emit_tapplet_lifecycle(status).await;
+    }
+
+    /// Registers a freshly launched tapplet server. Any server previously started for the
+    /// same tapplet is shut down so relaunching does not leak listeners.
+    async fn record_started(
+        tapplet: Tapplets,
+        version: Option<&Version>,
+        source: String,
+        cancel_token: CancellationToken,
+    ) -> TappletStatus {
+        let mut status = TappletStatus::new(tapplet, TappletLifecycleStatus::Started);
+        status.version = version.map(ToString::to_string);
+        status.source = Some(source.clone());
+        status.started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs());
+
+        let previous = {
+            let mut entries = INSTANCE.write().await;
+            if let Some(previous) = entries.get(&tapplet) {
+                status.crash_count = previous.status.crash_count;
+                status.reload_attempts = previous.status.reload_attempts;
+            }
+            entries.insert(
+                tapplet,
+                TappletEntry {
+                    status: status.clone(),
+                    cancel_token: Some(cancel_token.clone()),
+                },
+            )
+        };
+        if let Some(token) = previous.and_then(|entry| entry.cancel_token) {
+            info!(target: LOG_TARGET, "Stopping previous {} tapplet server", tapplet.name());
+            token.cancel();
+        }
+
+        Self::spawn_health_monitor(tapplet, source, cancel_token).await;
+        EventsEmitter::emit_tapplet_lifecycle(status.clone()).await;
+        status
+    }
+
+    /// Marks the tapplet unhealthy, stops whatever is left of its server and schedules an
+    /// automatic reload while attempts remain.
+    pub async fn record_crash(tapplet: Tapplets, error: String) {
+        warn!(target: LOG_TARGET, "Tapplet {} crashed: {}", tapplet.name(), error);
+        let (status, reload_attempt) = {
+            let mut entries = INSTANCE.write().await;
+            let entry = entries.entry(tapplet).or_insert_with(|| TappletEntry {
+                status: TappletStatus::new(tapplet, TappletLifecycleStatus::Crashed),
+                cancel_token: None,
+            });
+            if let Some(token) = entry.cancel_token.take() {
+                token.cancel();
+            }
+            entry.status.status = TappletLifecycleStatus::Crashed;
+            entry.status.last_error = Some(error);
+            entry.status.is_healthy = false;
+            entry.status.crash_count = entry.status.crash_count.saturating_add(1);
+            let reload_attempt = (entry.status.reload_attempts < MAX_RELOAD_ATTEMPTS).then(|| {
+                entry.status.reload_attempts += 1;
+                entry.status.reload_attempts
+            });
+            (entry.status.clone(), reload_attempt)
+        };
+        EventsEmitter::emit_tapplet_lifecycle(status).await;
+
+        let Some(reload_attempt) = reload_attempt else {
+            error!(target: LOG_TARGET, "Tapplet {} exceeded {} automatic reloads, leaving it stopped", tapplet.name(), MAX_RELOAD_ATTEMPTS);
+            return;
+        };
+        let backoff = RELOAD_BACKOFF * 2u32.pow(reload_attempt - 1);
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        // Boxed to break the type cycle between launching, monitoring and crash handling
+        let reload: Pin<Box<dyn Future<Output = ()> + Send>> = Box::pin(async move {
+            select! {
+                _ = shutdown_signal.wait() => return,
+                _ = tokio::time::sleep(backoff) => {}
+            }
+            // A manual reload during the backoff has already brought the tapplet back
+            let is_still_crashed = INSTANCE
+                .read()
+                .await
+                .get(&tapplet)
+                .is_some_and(|entry| entry.status.status == TappletLifecycleStatus::Crashed);
+            if !is_still_crashed {
+                return;
+            }
+            info!(target: LOG_TARGET, "Reloading tapplet {} after {:?}", tapplet.name(), backoff);
+            if let Err(e) = Self::launch(tapplet).await {
+                error!(target: LOG_TARGET, "Failed to reload tapplet {}: {:?}", tapplet.name(), e);
+            }
+        });
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(reload);
+    }
+
+    /// Called when the frame showing the tapplet failed to load, which the health check only
+    /// notices on its next tick if at all
+    pub async fn record_load_failure(source: &str, error: String) {
+        if let Some(tapplet) = Self::tapplet_for_origin(source).await {
+            Self::record_crash(tapplet, format!("Tapplet failed to load: {}", error)).await;
+        }
+    }
+
+    async fn spawn_health_monitor(
+        tapplet: Tapplets,
+        source: String,
+        cancel_token: CancellationToken,
+    ) {
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let client = reqwest::Client::new();
+                let mut interval = tokio::time::interval(HEALTH_CHECK_INTERVAL);
+                // The first tick completes immediately and the server has only just started
+                interval.tick().await;
+                let healthy_since = tokio::time::Instant::now();
+                let mut has_recovered = false;
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => break,
+                        _ = cancel_token.cancelled() => break,
+                        _ = interval.tick() => {
+                            let response = client
+                                .get(&source)
+                                .timeout(HEALTH_CHECK_TIMEOUT)
+                                .send()
+                                .await
+                                .and_then(|response| response.error_for_status());
+                            if let Err(e) = response {
+                                if !cancel_token.is_cancelled() {
+                                    Self::record_crash(tapplet, format!("Tapplet stopped responding: {}", e)).await;
+                                }
+                                break;
+                            }
+                            if !has_recovered && healthy_since.elapsed() >= HEALTHY_PERIOD {
+                                has_recovered = true;
+                                Self::record_recovered(tapplet, &cancel_token).await;
+                            }
+                        }
+                    }
+                }
+            });
+    }
+
+    async fn record_recovered(tapplet: Tapplets, cancel_token: &CancellationToken) {
+        let mut entries = INSTANCE.write().await;
+        let Some(entry) = entries.get_mut(&tapplet) else {
+            return;
+        };
+        // A replaced server has its token cancelled, the newer one keeps its own count
+        if !cancel_token.is_cancelled() && entry.status.reload_attempts > 0 {
+            info!(target: LOG_TARGET, "Tapplet {} has been healthy for {:?}, resetting reload attempts", tapplet.name(), HEALTHY_PERIOD);
+            entry.status.reload_attempts = 0;
+        }
+    }
+
+    /// Maps the origin of an invoking frame back to the running tapplet served from it.
+    pub async fn tapplet_for_origin(origin: &str) -> Option<Tapplets> {
+        let origin = origin.trim_end_matches('/');
+        INSTANCE
+            .read()
+            .await
+            .iter()
+            .find(|(_, entry)| {
+                entry.status.is_running()
+                    && entry
+                        .status
+                        .source
+                        .as_deref()
+                        .is_some_and(|source| source.trim_end_matches('/') == origin)
+            })
+            .map(|(tapplet, _)| *tapplet)
+    }
+
+    pub async fn statuses() -> Vec<TappletStatus> {
+        let mut statuses: Vec<TappletStatus> = INSTANCE
+            .read()
+            .await
+            .values()
+            .map(|entry| entry.status.clone())
+            .collect();
+        statuses.sort_by(|a, b| a.tapplet.cmp(&b.tapplet));
+        statuses
+    }
+}
@@ -20,9 +20,13 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::tapplets::error::{
-    Error::{self, TappletServerError},
-    TappletServerError::*,
+use crate::tapplets::{
+    error::{
+        Error::{self, TappletServerError},
+        TappletServerError::*,
+    },
+    tapplet_registry::TappletRegistry,
+    Tapplets,
 };
 
 use axum::Router;
@@ -33,9 +37,12 @@ use tokio_util::sync::CancellationToken;
 use tower_http::services::ServeDir;
 const LOG_TARGET: &str = "tari::tapplet";
 
-pub async fn start_tapplet(tapplet_path: PathBuf) -> Result<(String, CancellationToken), Error> {
+pub async fn start_tapplet(
+    tapplet: Tapplets,
+    tapplet_path: PathBuf,
+) -> Result<(String, CancellationToken), Error> {
     info!(target: LOG_TARGET, "Start tapplet path {:?}", &tapplet_path);
-    serve(using_serve_dir(tapplet_path), 0).await
+    serve(using_serve_dir(tapplet_path), 0, tapplet).await
 }
 
 pub fn using_serve_dir(tapplet_path: PathBuf) -> Router {
@@ -43,7 +50,11 @@ pub fn using_serve_dir(tapplet_path: PathBuf) -> Router {
     Router::new().nest_service("/", serve_dir)
 }
 
-pub async fn serve(app: Router, port: u16) -> Result<(String, CancellationToken), Error> {
+pub async fn serve(
+    app: Router,
+    port: u16,
+    tapplet: Tapplets,
+) -> Result<(String, CancellationToken), Error> {
     info!(target: LOG_TARGET, "Launch tapplet on port {:?}", &port);
     let cancel_token = CancellationToken::new();
     let cancel_token_clone = cancel_token.clone();
@@ -66,11 +77,13 @@ pub async fn serve(app: Router, port: u16) -> Result<(String, CancellationToken)
         .to_string();
 
     tauri::async_runtime::spawn(async move {
-        axum::serve(listener, app)
+        if let Err(e) = axum::serve(listener, app)
             .with_graceful_shutdown(shutdown_signal(cancel_token_clone))
             .await
-            .inspect_err(|e| error!(target: LOG_TARGET, "Failed to start server error: {:?}", e))
-            .map_err(|_| TappletServerError(FailedToStart))
+        {
+            error!(target: LOG_TARGET, "Failed to start server error: {:?}", e);
+            TappletRegistry::record_crash(tapplet, format!("{}: {}", FailedToStart, e)).await;
+        }
     });
     info!(target: LOG_TARGET, "🚀 The tapplet was launched at the address: {:?}", &address);
 
@@ -0,0 +1,167 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Error};
+use log::info;
+use serde::Serialize;
+
+use super::Tapplets;
+
+const LOG_TARGET: &str = "tari::universe::tapplet_storage";
+pub const DEFAULT_TAPPLET_STORAGE_QUOTA_BYTES: u64 = 50 * 1024 * 1024;
+pub const MAX_TAPPLET_STORAGE_QUOTA_BYTES: u64 = 1024 * 1024 * 1024;
+const MAX_KEY_LENGTH: usize = 128;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TappletStorageUsage {
+    pub tapplet: String,
+    pub used_bytes: u64,
+    pub quota_bytes: u64,
+}
+
+/// Every tapplet gets its own directory so one tapplet can never read or fill up another's data.
+pub fn tapplet_data_dir(base_dir: &Path, tapplet: Tapplets) -> PathBuf {
+    base_dir.join("tapplets_data").join(tapplet.name())
+}
+
+/// Keys map directly to file names, so anything that could escape the data directory is refused.
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_KEY_LENGTH
+        && !key.starts_with('.')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn fits_quota(used_bytes: u64, replaced_bytes: u64, new_bytes: u64, quota_bytes: u64) -> bool {
+    used_bytes
+        .saturating_sub(replaced_bytes)
+        .saturating_add(new_bytes)
+        <= quota_bytes
+}
+
+fn dir_size(dir: &Path) -> Result<u64, Error> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let mut size = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        size += if metadata.is_dir() {
+            dir_size(&entry.path())?
+        } else {
+            metadata.len()
+        };
+    }
+    Ok(size)
+}
+
+fn entry_path(base_dir: &Path, tapplet: Tapplets, key: &str) -> Result<PathBuf, Error> {
+    if !is_valid_key(key) {
+        return Err(anyhow!("Invalid tapplet storage key: {}", key));
+    }
+    Ok(tapplet_data_dir(base_dir, tapplet).join(key))
+}
+
+pub fn usage(
+    base_dir: &Path,
+    tapplet: Tapplets,
+    quota_bytes: u64,
+) -> Result<TappletStorageUsage, Error> {
+    Ok(TappletStorageUsage {
+        tapplet: tapplet.name().to_string(),
+        used_bytes: dir_size(&tapplet_data_dir(base_dir, tapplet))?,
+        quota_bytes,
+    })
+}
+
+pub fn write_entry(
+    base_dir: &Path,
+    tapplet: Tapplets,
+    key: &str,
+    value: &[u8],
+    quota_bytes: u64,
+) -> Result<(), Error> {
+    let path = entry_path(base_dir, tapplet, key)?;
+    let used_bytes = dir_size(&tapplet_data_dir(base_dir, tapplet))?;
+    let replaced_bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    let new_bytes = value.len() as u64;
+    if !fits_quota(used_bytes, replaced_bytes, new_bytes, quota_bytes) {
+        return Err(anyhow!(
+            "Storage quota exceeded for tapplet {}: {} of {} bytes used, {} requested",
+            tapplet.name(),
+            used_bytes,
+            quota_bytes,
+            new_bytes
+        ));
+    }
+
+    fs::create_dir_all(tapplet_data_dir(base_dir, tapplet))?;
+    fs::write(path, value)?;
+    Ok(())
+}
+
+pub fn read_entry(base_dir: &Path, tapplet: Tapplets, key: &str) -> Result<Option<Vec<u8>>, Error> {
+    let path = entry_path(base_dir, tapplet, key)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    Ok(Some(fs::read(path)?))
+}
+
+pub fn clear(base_dir: &Path, tapplet: Tapplets) -> Result<(), Error> {
+    let data_dir = tapplet_data_dir(base_dir, tapplet);
+    if data_dir.exists() {
+        info!(target: LOG_TARGET, "Clearing data for tapplet {}", tapplet.name());
+        fs::remove_dir_all(&data_dir)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_cannot_escape_data_dir() {
+        assert!(is_valid_key("settings.json"));
+        assert!(is_valid_key("bridge_tx-history"));
+        assert!(!is_valid_key(""));
+        assert!(!is_valid_key("../wallet"));
+        assert!(!is_valid_key("nested/key"));
+        assert!(!is_valid_key(".hidden"));
+    }
+
+    #[test]
+    fn overwriting_an_entry_only_counts_the_difference() {
+        assert!(fits_quota(90, 40, 50, 100));
+        assert!(!fits_quota(90, 0, 20, 100));
+        assert!(fits_quota(0, 0, 100, 100));
+    }
+}
@@ -36,11 +36,10 @@ impl Tapplets {
         }
     }
 
-    #[allow(dead_code)]
-    pub fn from_name(name: &str) -> Self {
+    pub fn from_name(name: &str) -> Option<Self> {
         match name {
-            "bridge" => Tapplets::Bridge,
-            _ => panic!("Unknown tapplet name: {}", name),
+            "bridge" => Some(Tapplets::Bridge),
+            _ => None,
         }
     }
 
@@ -31,7 +31,7 @@ use crate::{
     binaries::binaries_resolver::{VersionAsset, VersionDownloadInfo},
     download_utils::{extract, validate_checksum},
     github::request_client::RequestClient,
-    progress_tracker_old::ProgressTracker,
+    progress_tracker_old::{ProgressTracker, ProgressUpdate},
 };
 
 use super::tapplets_resolver::LatestVersionApiAdapter;
@@ -193,9 +193,10 @@ impl TappletManager {
             .join("in_progress");
 
         progress_tracker
-            .send_last_action(format!(
-                "Removing in progress folder: {:?}",
-                in_progress_folder
+            .send_progress(ProgressUpdate::new(
+                &self.tapplet_name,
+                "cleanup",
+                format!("Removing in progress folder: {:?}", in_progress_folder),
             ))
             .await;
         if in_progress_folder.exists() {
@@ -273,9 +274,10 @@ impl TappletManager {
             assets: vec![asset.clone()],
         };
         progress_tracker
-            .send_last_action(format!(
-                "Downloading checksum file for dest: {:?}",
-                destination_dir
+            .send_progress(ProgressUpdate::new(
+                &self.tapplet_name,
+                "download_checksum",
+                format!("Downloading checksum file for dest: {:?}", destination_dir),
             ))
             .await;
         let checksum_file = self
@@ -300,9 +302,13 @@ impl TappletManager {
             .await?;
 
         progress_tracker
-            .send_last_action(format!(
-                "Validating checksum for checksum file: {:?} and in progress file: {:?}",
-                checksum_file, in_progress_file_zip
+            .send_progress(ProgressUpdate::new(
+                &self.tapplet_name,
+                "validate_checksum",
+                format!(
+                    "Validating checksum for checksum file: {:?} and in progress file: {:?}",
+                    checksum_file, in_progress_file_zip
+                ),
             ))
             .await;
         match validate_checksum(in_progress_file_zip.clone(), expected_checksum).await {
@@ -514,17 +520,23 @@ impl TappletManager {
 
         info!(target: LOG_TARGET, "Downloading tapplet: {} from url: {}", self.tapplet_name, download_url);
         progress_tracker
-            .send_last_action(format!(
-                "Downloading tapplet: {} with version: {}",
-                self.tapplet_name, version
+            .send_progress(ProgressUpdate::new(
+                &self.tapplet_name,
+                "download",
+                format!(
+                    "Downloading tapplet: {} with version: {}",
+                    self.tapplet_name, version
+                ),
             ))
             .await;
 
         if RequestClient::current()
-            .download_file(
+            .download_file_with_progress(
                 download_url.as_str(),
                 &in_progress_file_zip,
                 asset.source.is_mirror(),
+                &progress_tracker,
+                &self.tapplet_name,
             )
             .await
             .map_err(|e| anyhow!("Error downloading version: {:?}. Error: {:?}", version, e))
@@ -533,17 +545,23 @@ impl TappletManager {
             if let Some(fallback_url) = fallback_url {
                 info!(target: LOG_TARGET, "Downloading tapplet: {} from fallback url: {}", self.tapplet_name, fallback_url);
                 progress_tracker
-                    .send_last_action(format!(
-                        "Downloading tapplet: {} with version: {} from fallback url",
-                        self.tapplet_name, version
+                    .send_progress(ProgressUpdate::new(
+                        &self.tapplet_name,
+                        "download_fallback",
+                        format!(
+                            "Downloading tapplet: {} with version: {} from fallback url",
+                            self.tapplet_name, version
+                        ),
                     ))
                     .await;
 
                 RequestClient::current()
-                    .download_file(
+                    .download_file_with_progress(
                         fallback_url.as_str(),
                         &in_progress_file_zip,
                         asset.source.is_mirror(),
+                        &progress_tracker,
+                        &self.tapplet_name,
                     )
                     .await
                     .map_err(|e| {
@@ -558,10 +576,14 @@ impl TappletManager {
         }
 
         progress_tracker
-            .send_last_action(format!(
-                "Extracting file: {} to dest: {}",
-                in_progress_file_zip.to_str().unwrap_or_default(),
-                destination_dir.to_str().unwrap_or_default()
+            .send_progress(ProgressUpdate::new(
+                &self.tapplet_name,
+                "extract",
+                format!(
+                    "Extracting file: {} to dest: {}",
+                    in_progress_file_zip.to_str().unwrap_or_default(),
+                    destination_dir.to_str().unwrap_or_default()
+                ),
             ))
             .await;
         extract(&in_progress_file_zip, &destination_dir)
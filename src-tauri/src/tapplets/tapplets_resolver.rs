@@ -22,6 +22,7 @@
 use crate::binaries::binaries_resolver::{VersionAsset, VersionDownloadInfo};
 use crate::configs::config_core::{ConfigCore, ConfigCoreContent};
 use crate::configs::trait_config::ConfigImpl;
+use crate::progress_tracker_old::ProgressUpdate;
 use crate::ProgressTracker;
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
@@ -39,6 +40,7 @@ use tokio::sync::{Mutex, RwLock};
 use tokio::time::timeout;
 
 use super::bridge_adapter::BridgeTappletAdapter;
+use super::tapplet_registry::TappletRegistry;
 use super::tapplets_manager::TappletManager;
 use super::Tapplets;
 
@@ -217,12 +219,14 @@ impl TappletResolver {
         // Selects the highest version from the Vec of downloaded versions and local versions
         let mut highest_version = manager.select_highest_version();
 
+        let mut is_downloaded = false;
         // This covers case when we do not check newest version and there is no local version
         if highest_version.is_none() {
             highest_version = manager.select_highest_version();
             manager
                 .download_version_with_retries(highest_version.clone(), progress_tracker.clone())
                 .await?;
+            is_downloaded = true;
         }
 
         // Check if the files exist after download
@@ -232,6 +236,7 @@ impl TappletResolver {
             manager
                 .download_version_with_retries(highest_version.clone(), progress_tracker.clone())
                 .await?;
+            is_downloaded = true;
         }
 
         // Throw error if files still do not exist
@@ -244,14 +249,17 @@ impl TappletResolver {
             ));
         }
 
-        match highest_version {
-            Some(version) => manager.set_used_version(version),
-            None => {
-                return Err(anyhow!(
-                    "Initialize {} tapplet version: no version selected",
-                    tapplet.name()
-                ))
-            }
+        let Some(version) = highest_version else {
+            return Err(anyhow!(
+                "Initialize {} tapplet version: no version selected",
+                tapplet.name()
+            ));
+        };
+        manager.set_used_version(version.clone());
+        drop(manager);
+
+        if is_downloaded {
+            TappletRegistry::record_installed(tapplet, &version, false).await;
         }
 
         Ok(())
@@ -270,13 +278,18 @@ impl TappletResolver {
             .await;
 
         manager.check_for_updates().await;
+        let previous_version = manager.get_used_version();
         let highest_version = manager.select_highest_version();
 
         progress_tracker
-            .send_last_action(format!(
-                "Checking if files exist before download: {} {}",
+            .send_progress(ProgressUpdate::new(
                 tapplet.name(),
-                highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                "check_files",
+                format!(
+                    "Checking if files exist before download: {} {}",
+                    tapplet.name(),
+                    highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                ),
             ))
             .await;
 
@@ -289,10 +302,14 @@ impl TappletResolver {
         }
 
         progress_tracker
-            .send_last_action(format!(
-                "Checking if files exist after download: {} {}",
+            .send_progress(ProgressUpdate::new(
                 tapplet.name(),
-                highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                "verify_files",
+                format!(
+                    "Checking if files exist after download: {} {}",
+                    tapplet.name(),
+                    highest_version.clone().unwrap_or(Version::new(0, 0, 0))
+                ),
             ))
             .await;
         let check_if_files_exist =
@@ -304,14 +321,17 @@ impl TappletResolver {
             ));
         }
 
-        match highest_version {
-            Some(version) => manager.set_used_version(version),
-            None => {
-                return Err(anyhow!(
-                    "Update {} tapplet version: no version selected",
-                    tapplet.name()
-                ))
-            }
+        let Some(version) = highest_version else {
+            return Err(anyhow!(
+                "Update {} tapplet version: no version selected",
+                tapplet.name()
+            ));
+        };
+        manager.set_used_version(version.clone());
+        drop(manager);
+
+        if previous_version.as_ref() != Some(&version) {
+            TappletRegistry::record_installed(tapplet, &version, previous_version.is_some()).await;
         }
 
         Ok(())
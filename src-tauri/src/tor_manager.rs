@@ -162,18 +162,20 @@ impl TorManager {
             .map(|m| m.control_port))
     }
 
+    pub fn get_status(&self) -> TorStatus {
+        *self.status_watch_rx.borrow()
+    }
+
     pub async fn get_entry_guards(&self) -> Result<Vec<String>, anyhow::Error> {
         self.watcher.read().await.adapter.get_entry_guards().await
     }
 
-    #[allow(dead_code)]
     pub async fn stop(&self) -> Result<i32, anyhow::Error> {
         let mut process_watcher = self.watcher.write().await;
         let exit_code = process_watcher.stop().await?;
         Ok(exit_code)
     }
 
-    #[allow(dead_code)]
     pub async fn is_running(&self) -> bool {
         let process_watcher = self.watcher.read().await;
         process_watcher.is_running()
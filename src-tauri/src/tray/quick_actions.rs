@@ -0,0 +1,170 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Duration,
+};
+
+use log::info;
+use tauri::AppHandle;
+use tokio::select;
+
+use crate::{
+    tasks_tracker::TasksTrackers,
+    utils::mining_utils::{start_all_miners, stop_all_miners},
+};
+
+const LOG_TARGET: &str = "tari::universe::tray::quick_actions";
+const PAUSE_DURATION: Duration = Duration::from_secs(60 * 60);
+
+static IS_MINING: AtomicBool = AtomicBool::new(false);
+static IS_PAUSED: AtomicBool = AtomicBool::new(false);
+// Bumped on every pause/resume so a stale pause timer does not restart mining
+static PAUSE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy)]
+pub enum TrayQuickAction {
+    ToggleMining,
+    PauseMining,
+}
+
+impl TrayQuickAction {
+    pub fn all() -> [TrayQuickAction; 2] {
+        [TrayQuickAction::ToggleMining, TrayQuickAction::PauseMining]
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            TrayQuickAction::ToggleMining => "toggle_mining",
+            TrayQuickAction::PauseMining => "pause_mining",
+        }
+    }
+
+    pub fn from_str(id: &str) -> Option<Self> {
+        match id {
+            "toggle_mining" => Some(TrayQuickAction::ToggleMining),
+            "pause_mining" => Some(TrayQuickAction::PauseMining),
+            _ => None,
+        }
+    }
+
+    pub fn get_title(&self, is_mining: bool) -> String {
+        match self {
+            TrayQuickAction::ToggleMining => {
+                if is_mining {
+                    "Stop mining".to_string()
+                } else {
+                    "Start mining".to_string()
+                }
+            }
+            TrayQuickAction::PauseMining => {
+                if TrayQuickActions::is_paused() {
+                    "Resume mining".to_string()
+                } else {
+                    "Pause mining for 1 hour".to_string()
+                }
+            }
+        }
+    }
+
+    /// Pausing only makes sense while mining or while a pause is pending
+    pub fn is_enabled(&self, is_mining: bool) -> bool {
+        match self {
+            TrayQuickAction::ToggleMining => true,
+            TrayQuickAction::PauseMining => is_mining || TrayQuickActions::is_paused(),
+        }
+    }
+}
+
+pub struct TrayQuickActions;
+
+impl TrayQuickActions {
+    pub fn set_is_mining(is_mining: bool) {
+        IS_MINING.store(is_mining, Ordering::SeqCst);
+    }
+
+    pub fn is_mining() -> bool {
+        IS_MINING.load(Ordering::SeqCst)
+    }
+
+    pub fn is_paused() -> bool {
+        IS_PAUSED.load(Ordering::SeqCst)
+    }
+
+    fn clear_pause() {
+        IS_PAUSED.store(false, Ordering::SeqCst);
+        PAUSE_GENERATION.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn handle(app_handle: AppHandle, action: TrayQuickAction) {
+        let is_mining = TrayQuickActions::is_mining();
+        tauri::async_runtime::spawn(async move {
+            match action {
+                TrayQuickAction::ToggleMining => {
+                    TrayQuickActions::clear_pause();
+                    if is_mining {
+                        info!(target: LOG_TARGET, "Stopping mining from tray");
+                        stop_all_miners(&app_handle).await;
+                    } else {
+                        info!(target: LOG_TARGET, "Starting mining from tray");
+                        start_all_miners(&app_handle).await;
+                    }
+                }
+                TrayQuickAction::PauseMining => {
+                    if TrayQuickActions::is_paused() {
+                        info!(target: LOG_TARGET, "Resuming mining from tray");
+                        TrayQuickActions::clear_pause();
+                        start_all_miners(&app_handle).await;
+                    } else {
+                        TrayQuickActions::pause(app_handle).await;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn pause(app_handle: AppHandle) {
+        info!(target: LOG_TARGET, "Pausing mining from tray for {:?}", PAUSE_DURATION);
+        let generation = PAUSE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        IS_PAUSED.store(true, Ordering::SeqCst);
+        stop_all_miners(&app_handle).await;
+
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                select! {
+                    _ = tokio::time::sleep(PAUSE_DURATION) => {
+                        if PAUSE_GENERATION.load(Ordering::SeqCst) == generation {
+                            info!(target: LOG_TARGET, "Pause finished. Resuming mining");
+                            IS_PAUSED.store(false, Ordering::SeqCst);
+                            start_all_miners(&app_handle).await;
+                        }
+                    }
+                    _ = shutdown_signal.wait() => {}
+                }
+            });
+    }
+}
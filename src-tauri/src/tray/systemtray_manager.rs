@@ -33,6 +33,8 @@ use crate::utils::{
     platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
+use super::quick_actions::{TrayQuickAction, TrayQuickActions};
+
 const LOG_TARGET: &str = "tari::universe::systemtray_manager";
 
 #[derive(Debug)]
@@ -69,6 +71,7 @@ pub struct SystemTrayData {
     pub cpu_hashrate: f64,
     pub gpu_hashrate: f64,
     pub estimated_earning: f64,
+    pub is_mining: bool,
 }
 
 #[derive(Clone)]
@@ -112,6 +115,20 @@ impl SystemTrayManager {
             false,
             None::<&str>,
         )?;
+        let toggle_mining = MenuItem::with_id(
+            &app,
+            TrayQuickAction::ToggleMining.to_str(),
+            TrayQuickAction::ToggleMining.get_title(false),
+            true,
+            None::<&str>,
+        )?;
+        let pause_mining = MenuItem::with_id(
+            &app,
+            TrayQuickAction::PauseMining.to_str(),
+            TrayQuickAction::PauseMining.get_title(false),
+            false,
+            None::<&str>,
+        )?;
         let minimize_toggle = MenuItem::with_id(
             &app,
             SystrayItemId::MinimizeToggle.to_str(),
@@ -132,6 +149,9 @@ impl SystemTrayManager {
                 &separator,
                 &estimated_earning,
                 &separator,
+                &toggle_mining,
+                &pause_mining,
+                &separator,
                 &minimize_toggle,
             ],
         )?;
@@ -189,9 +209,12 @@ impl SystemTrayManager {
                     });
                 }
             },
-            _ => {
-                error!(target: LOG_TARGET, "menu item {:?} not handled", event.id);
-            }
+            id => match TrayQuickAction::from_str(id) {
+                Some(action) => TrayQuickActions::handle(app.clone(), action),
+                None => {
+                    error!(target: LOG_TARGET, "menu item {:?} not handled", event.id);
+                }
+            },
         });
 
         self.menu.replace(menu);
@@ -200,6 +223,28 @@ impl SystemTrayManager {
         Ok(())
     }
 
+    fn update_quick_actions(&self, menu: &Menu<Wry>, is_mining: bool) {
+        TrayQuickActions::set_is_mining(is_mining);
+        for action in TrayQuickAction::all() {
+            match menu
+                .get(action.to_str())
+                .and_then(|item| item.as_menuitem().cloned())
+            {
+                Some(menu_item) => {
+                    if let Err(e) = menu_item.set_text(action.get_title(is_mining)) {
+                        error!(target: LOG_TARGET, "Failed to update quick action title: {}", e);
+                    }
+                    if let Err(e) = menu_item.set_enabled(action.is_enabled(is_mining)) {
+                        error!(target: LOG_TARGET, "Failed to update quick action state: {}", e);
+                    }
+                }
+                None => {
+                    error!(target: LOG_TARGET, "Failed to get quick action menu item for {:?}", action);
+                }
+            }
+        }
+    }
+
     pub fn update_tray(&mut self, data: SystemTrayData) {
         if let Some(tray) = &self.tray {
             if let Err(e) = tray.set_tooltip(self.get_tooltip_text(data.clone())) {
@@ -229,6 +274,7 @@ impl SystemTrayManager {
                     error!(target: LOG_TARGET, "Failed to get menu item by id for {:?}", id);
                 }
             }
+            self.update_quick_actions(menu, data.is_mining);
         } else {
             error!(target: LOG_TARGET, "Menu not initialized");
         }
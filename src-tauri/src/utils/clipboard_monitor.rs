@@ -0,0 +1,347 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use log::{info, warn};
+use tauri::{AppHandle, Manager, UserAttentionType};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_notification::NotificationExt;
+use tokio::{
+    select,
+    sync::{mpsc, oneshot},
+    time::{self, Interval, MissedTickBehavior},
+};
+
+use crate::{
+    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    events_emitter::EventsEmitter,
+    tasks_tracker::TasksTrackers,
+    utils::wallet_utils::normalize_tari_address,
+};
+
+const LOG_TARGET: &str = "tari::universe::clipboard_monitor";
+/// Only used where the platform has no clipboard change notifications (macOS, Wayland)
+const CLIPBOARD_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Clipboard hijackers swap the address right after it is copied, a user copying another
+/// address takes longer than this
+const HIJACK_WINDOW: Duration = Duration::from_secs(3);
+
+static IS_RUNNING: AtomicBool = AtomicBool::new(false);
+
+struct CopiedAddress {
+    address: String,
+    copied_at: Instant,
+}
+
+pub struct ClipboardMonitor;
+
+impl ClipboardMonitor {
+    fn alert(app_handle: &AppHandle, original_address: String, replaced_with: String) {
+        warn!(target: LOG_TARGET, "Copied address {} was replaced in the clipboard with {}", original_address, replaced_with);
+        if let Some(window) = app_handle.get_webview_window("main") {
+            let _unused = window.request_user_attention(Some(UserAttentionType::Critical));
+        }
+        if let Err(e) = app_handle
+            .notification()
+            .builder()
+            .title("Copied address was replaced")
+            .body("Another application replaced the Tari address you copied. Do not paste it, copy the address again.")
+            .show()
+        {
+            warn!(target: LOG_TARGET, "Failed to show clipboard notification: {:?}", e);
+        }
+        tauri::async_runtime::spawn(async move {
+            EventsEmitter::emit_clipboard_address_replaced(original_address, replaced_with).await;
+        });
+    }
+
+    /// Resolves when the clipboard may have changed. Returns false once the platform listener stops.
+    async fn next_change(
+        changes: &mut Option<mpsc::UnboundedReceiver<()>>,
+        interval: &mut Interval,
+    ) -> bool {
+        match changes {
+            Some(changes) => changes.recv().await.is_some(),
+            None => {
+                interval.tick().await;
+                true
+            }
+        }
+    }
+
+    /// Watches the clipboard while protection is enabled in settings
+    pub async fn spawn(app_handle: AppHandle) {
+        if !*ConfigCore::content().await.clipboard_protection_enabled() {
+            return;
+        }
+        if IS_RUNNING.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let mut changes = listen_for_changes().await;
+        if changes.is_some() {
+            info!(target: LOG_TARGET, "Starting clipboard monitor with platform change notifications");
+        } else {
+            info!(target: LOG_TARGET, "Starting clipboard monitor, polling every {:?}", CLIPBOARD_POLL_INTERVAL);
+        }
+        let mut interval = time::interval(CLIPBOARD_POLL_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut last_text: Option<String> = None;
+                let mut last_copied_address: Option<CopiedAddress> = None;
+                loop {
+                    let changed = select! {
+                        _ = shutdown_signal.wait() => {
+                            break;
+                        }
+                        changed = ClipboardMonitor::next_change(&mut changes, &mut interval) => changed,
+                    };
+                    if !changed {
+                        warn!(target: LOG_TARGET, "Clipboard change listener stopped. Falling back to polling.");
+                        changes = None;
+                        continue;
+                    }
+                    if !*ConfigCore::content().await.clipboard_protection_enabled() {
+                        info!(target: LOG_TARGET, "Clipboard protection disabled. Stopping clipboard monitor.");
+                        break;
+                    }
+                    let Ok(text) = app_handle.clipboard().read_text() else {
+                        continue;
+                    };
+                    if last_text.as_ref() == Some(&text) {
+                        continue;
+                    }
+                    last_text = Some(text.clone());
+
+                    let Some(address) = normalize_tari_address(&text) else {
+                        last_copied_address = None;
+                        continue;
+                    };
+                    if let Some(previous) = last_copied_address.take() {
+                        if previous.address != address && previous.copied_at.elapsed() < HIJACK_WINDOW {
+                            ClipboardMonitor::alert(&app_handle, previous.address, address.clone());
+                        }
+                    }
+                    last_copied_address = Some(CopiedAddress {
+                        address,
+                        copied_at: Instant::now(),
+                    });
+                }
+                IS_RUNNING.store(false, Ordering::SeqCst);
+            });
+    }
+}
+
+/// Starts a listener thread that signals every clipboard change. The thread exits on the first
+/// change after the receiver is dropped.
+async fn listen_for_changes() -> Option<mpsc::UnboundedReceiver<()>> {
+    let (changes_tx, changes_rx) = mpsc::unbounded_channel();
+    let (ready_tx, ready_rx) = oneshot::channel();
+    let spawned = std::thread::Builder::new()
+        .name("clipboard-listener".to_string())
+        .spawn(move || platform::listen(changes_tx, ready_tx));
+    if let Err(e) = spawned {
+        warn!(target: LOG_TARGET, "Failed to spawn clipboard listener thread: {}", e);
+        return None;
+    }
+    match ready_rx.await {
+        Ok(true) => Some(changes_rx),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::{cell::RefCell, ptr::null};
+
+    use log::warn;
+    use tokio::sync::{mpsc::UnboundedSender, oneshot};
+    use windows_sys::Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        System::{
+            DataExchange::{AddClipboardFormatListener, RemoveClipboardFormatListener},
+            LibraryLoader::GetModuleHandleW,
+        },
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW,
+            PostQuitMessage, RegisterClassW, HWND_MESSAGE, MSG, WM_CLIPBOARDUPDATE, WNDCLASSW,
+        },
+    };
+
+    use super::LOG_TARGET;
+
+    thread_local! {
+        static CHANGES: RefCell<Option<UnboundedSender<()>>> = const { RefCell::new(None) };
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg == WM_CLIPBOARDUPDATE {
+            let receiver_dropped = CHANGES
+                .with_borrow(|changes| changes.as_ref().is_none_or(|tx| tx.send(()).is_err()));
+            if receiver_dropped {
+                PostQuitMessage(0);
+            }
+            return 0;
+        }
+        DefWindowProcW(hwnd, msg, wparam, lparam)
+    }
+
+    /// Registers a message-only window for WM_CLIPBOARDUPDATE and pumps its messages
+    pub fn listen(changes: UnboundedSender<()>, ready: oneshot::Sender<bool>) {
+        let class_name: Vec<u16> = "TariUniverseClipboardListener\0".encode_utf16().collect();
+        // SAFETY: every pointer handed to the Win32 calls below outlives the call, and the window
+        // is only used from this thread
+        unsafe {
+            let instance = GetModuleHandleW(null());
+            let class = WNDCLASSW {
+                lpfnWndProc: Some(window_proc),
+                hInstance: instance,
+                lpszClassName: class_name.as_ptr(),
+                ..std::mem::zeroed()
+            };
+            // Fails harmlessly when the class is still registered by a previous run of the monitor
+            RegisterClassW(&class);
+            let hwnd = CreateWindowExW(
+                0,
+                class_name.as_ptr(),
+                null(),
+                0,
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                std::ptr::null_mut(),
+                instance,
+                null(),
+            );
+            if hwnd.is_null() || AddClipboardFormatListener(hwnd) == 0 {
+                warn!(target: LOG_TARGET, "Failed to register clipboard format listener");
+                if !hwnd.is_null() {
+                    DestroyWindow(hwnd);
+                }
+                let _unused = ready.send(false);
+                return;
+            }
+            CHANGES.set(Some(changes));
+            let _unused = ready.send(true);
+
+            let mut msg: MSG = std::mem::zeroed();
+            while GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) > 0 {
+                DispatchMessageW(&msg);
+            }
+            RemoveClipboardFormatListener(hwnd);
+            DestroyWindow(hwnd);
+            CHANGES.set(None);
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use log::warn;
+    use tokio::sync::{mpsc::UnboundedSender, oneshot};
+    use x11rb::{
+        connection::Connection,
+        protocol::{
+            xfixes::{ConnectionExt as _, SelectionEventMask},
+            xproto::ConnectionExt as _,
+            Event,
+        },
+    };
+
+    use super::LOG_TARGET;
+
+    fn subscribe(
+        conn: &impl Connection,
+        screen_num: usize,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // XFixes requests are rejected until the client has negotiated a version
+        conn.xfixes_query_version(5, 0)?.reply()?;
+        let clipboard = conn.intern_atom(false, b"CLIPBOARD")?.reply()?.atom;
+        let root = conn.setup().roots[screen_num].root;
+        conn.xfixes_select_selection_input(
+            root,
+            clipboard,
+            SelectionEventMask::SET_SELECTION_OWNER
+                | SelectionEventMask::SELECTION_WINDOW_DESTROY
+                | SelectionEventMask::SELECTION_CLIENT_CLOSE,
+        )?;
+        conn.flush()?;
+        Ok(())
+    }
+
+    /// Subscribes to XFixes ownership changes of the CLIPBOARD selection. Wayland clipboards are
+    /// mirrored to X11 by XWayland, without an X server the monitor falls back to polling.
+    pub fn listen(changes: UnboundedSender<()>, ready: oneshot::Sender<bool>) {
+        let Ok((conn, screen_num)) = x11rb::connect(None) else {
+            let _unused = ready.send(false);
+            return;
+        };
+        let subscribed = subscribe(&conn, screen_num);
+        if let Err(e) = subscribed {
+            warn!(target: LOG_TARGET, "Failed to subscribe to X11 clipboard changes: {}", e);
+            let _unused = ready.send(false);
+            return;
+        }
+        let _unused = ready.send(true);
+
+        loop {
+            match conn.wait_for_event() {
+                Ok(Event::XfixesSelectionNotify(_)) => {
+                    if changes.send(()).is_err() {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "X11 clipboard listener stopped: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+mod platform {
+    use tokio::sync::{mpsc::UnboundedSender, oneshot};
+
+    /// macOS has no clipboard change notification, the monitor polls instead
+    pub fn listen(_changes: UnboundedSender<()>, ready: oneshot::Sender<bool>) {
+        let _unused = ready.send(false);
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use log::{info, warn};
+use serde::Serialize;
+use tari_common::configuration::Network;
+use tokio::{
+    net::{lookup_host, TcpStream},
+    time::timeout,
+};
+
+const LOG_TARGET: &str = "tari::universe::connectivity_check";
+const CONNECTIVITY_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub enum ConnectivityCategory {
+    SeedPeers,
+    P2poolPeers,
+    BinaryMirrors,
+    TelemetryApi,
+    InboundPort,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ConnectivityCheck {
+    pub category: ConnectivityCategory,
+    pub target: String,
+    pub is_reachable: bool,
+    pub latency_ms: Option<u128>,
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ConnectivityReport {
+    pub checks: Vec<ConnectivityCheck>,
+    pub is_fully_reachable: bool,
+}
+
+impl ConnectivityCheck {
+    fn reachable(category: ConnectivityCategory, target: String, started_at: Instant) -> Self {
+        Self {
+            category,
+            target,
+            is_reachable: true,
+            latency_ms: Some(started_at.elapsed().as_millis()),
+            details: None,
+        }
+    }
+
+    fn unreachable(category: ConnectivityCategory, target: String, details: String) -> Self {
+        Self {
+            category,
+            target,
+            is_reachable: false,
+            latency_ms: None,
+            details: Some(details),
+        }
+    }
+}
+
+pub fn seed_hostnames(network: Network) -> Vec<String> {
+    match network {
+        Network::MainNet => vec![
+            "ip4.seeds.tari.com".to_string(),
+            "ip6.seeds.tari.com".to_string(),
+        ],
+        _ => vec![
+            format!("ip4.seeds.{}.tari.com", network.as_key_str()),
+            format!("ip6.seeds.{}.tari.com", network.as_key_str()),
+        ],
+    }
+}
+
+pub fn binary_mirror_urls() -> Vec<String> {
+    vec![
+        "https://api.github.com".to_string(),
+        "https://github.com".to_string(),
+        "https://cdn-universe.tari.com".to_string(),
+    ]
+}
+
+/// Seed peers are published through DNS, so an unresolvable seed host means the node can't bootstrap
+pub async fn check_dns(category: ConnectivityCategory, host: String) -> ConnectivityCheck {
+    let started_at = Instant::now();
+    match timeout(
+        CONNECTIVITY_CHECK_TIMEOUT,
+        lookup_host((host.as_str(), 443)),
+    )
+    .await
+    {
+        Ok(Ok(mut addresses)) => {
+            if addresses.next().is_some() {
+                ConnectivityCheck::reachable(category, host, started_at)
+            } else {
+                ConnectivityCheck::unreachable(category, host, "No addresses resolved".to_string())
+            }
+        }
+        Ok(Err(e)) => {
+            ConnectivityCheck::unreachable(category, host, format!("DNS lookup failed: {}", e))
+        }
+        Err(_) => {
+            ConnectivityCheck::unreachable(category, host, "DNS lookup timed out".to_string())
+        }
+    }
+}
+
+/// Any HTTP response counts as reachable, only transport failures are reported
+pub async fn check_http(category: ConnectivityCategory, url: String) -> ConnectivityCheck {
+    let started_at = Instant::now();
+    let response = reqwest::Client::new()
+        .head(&url)
+        .timeout(CONNECTIVITY_CHECK_TIMEOUT)
+        .send()
+        .await;
+    match response {
+        Ok(_) => ConnectivityCheck::reachable(category, url, started_at),
+        Err(e) => ConnectivityCheck::unreachable(category, url, format!("Request failed: {}", e)),
+    }
+}
+
+/// Confirms the listener is bound locally. Whether it is reachable from outside depends on
+/// port forwarding on the router, which can't be tested from this machine.
+pub async fn check_inbound_port(port: u16) -> ConnectivityCheck {
+    let started_at = Instant::now();
+    let target = format!("tcp/{}", port);
+    match timeout(
+        CONNECTIVITY_CHECK_TIMEOUT,
+        TcpStream::connect(("127.0.0.1", port)),
+    )
+    .await
+    {
+        Ok(Ok(_)) => {
+            let mut check =
+                ConnectivityCheck::reachable(ConnectivityCategory::InboundPort, target, started_at);
+            check.details = Some(
+                "Listening locally, the port must be forwarded on the router to accept inbound peers"
+                    .to_string(),
+            );
+            check
+        }
+        Ok(Err(e)) => ConnectivityCheck::unreachable(
+            ConnectivityCategory::InboundPort,
+            target,
+            format!("Port is not listening: {}", e),
+        ),
+        Err(_) => ConnectivityCheck::unreachable(
+            ConnectivityCategory::InboundPort,
+            target,
+            "Connecting to the port timed out".to_string(),
+        ),
+    }
+}
+
+/// Extracts the port from a multiaddr such as `/ip4/127.0.0.1/tcp/18189`
+pub fn port_from_multiaddr(address: &str) -> Option<u16> {
+    let mut parts = address.split('/');
+    parts.find(|part| *part == "tcp")?;
+    parts.next()?.parse().ok()
+}
+
+pub async fn run_connectivity_checks(
+    network: Network,
+    telemetry_api_url: String,
+) -> Vec<ConnectivityCheck> {
+    info!(target: LOG_TARGET, "Running connectivity checks for network: {}", network.as_key_str());
+    let seed_checks = join_all(
+        seed_hostnames(network)
+            .into_iter()
+            .map(|host| check_dns(ConnectivityCategory::SeedPeers, host)),
+    );
+    let mirror_checks = join_all(
+        binary_mirror_urls()
+            .into_iter()
+            .map(|url| check_http(ConnectivityCategory::BinaryMirrors, url)),
+    );
+    let telemetry_check = check_http(ConnectivityCategory::TelemetryApi, telemetry_api_url);
+
+    let (seed_checks, mirror_checks, telemetry_check) =
+        tokio::join!(seed_checks, mirror_checks, telemetry_check);
+
+    let mut checks = seed_checks;
+    checks.extend(mirror_checks);
+    checks.push(telemetry_check);
+    for check in checks.iter().filter(|check| !check.is_reachable) {
+        warn!(target: LOG_TARGET, "{} is unreachable: {:?}", check.target, check.details);
+    }
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_port_from_multiaddr() {
+        assert_eq!(port_from_multiaddr("/ip4/127.0.0.1/tcp/18189"), Some(18189));
+        assert_eq!(port_from_multiaddr("/ip4/127.0.0.1/udp/18189"), None);
+        assert_eq!(port_from_multiaddr("/onion3/abcdef:18141"), None);
+    }
+
+    #[test]
+    fn seed_hostnames_include_network_key() {
+        let hosts = seed_hostnames(Network::NextNet);
+        assert!(hosts.iter().all(|host| host.contains("nextnet")));
+        assert_eq!(seed_hostnames(Network::MainNet).len(), 2);
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::LazyLock,
+    time::Duration,
+};
+
+use log::{debug, warn};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use serde::Deserialize;
+use tari_common::configuration::Network;
+use tokio::net::lookup_host;
+
+use crate::{
+    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    utils::connectivity_check::seed_hostnames,
+};
+
+const LOG_TARGET: &str = "tari::universe::doh_resolver";
+const DOH_QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Providers are addressed by IP so resolving them never depends on the system resolver
+pub fn default_doh_providers() -> Vec<String> {
+    vec![
+        "https://1.1.1.1/dns-query".to_string(),
+        "https://8.8.8.8/resolve".to_string(),
+    ]
+}
+
+static DOH_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
+    reqwest::Client::builder()
+        .timeout(DOH_QUERY_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DnsRecordType {
+    A,
+    Aaaa,
+    Txt,
+}
+
+impl DnsRecordType {
+    fn code(self) -> u16 {
+        match self {
+            DnsRecordType::A => 1,
+            DnsRecordType::Aaaa => 28,
+            DnsRecordType::Txt => 16,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            DnsRecordType::A => "A",
+            DnsRecordType::Aaaa => "AAAA",
+            DnsRecordType::Txt => "TXT",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DohAnswer {
+    #[serde(rename = "type")]
+    record_type: u16,
+    data: String,
+}
+
+#[derive(Deserialize)]
+struct DohResponse {
+    #[serde(rename = "Status")]
+    status: u32,
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DohAnswer>,
+}
+
+fn parse_doh_response(response: DohResponse, record_type: DnsRecordType) -> Vec<String> {
+    if response.status != 0 {
+        return vec![];
+    }
+    response
+        .answer
+        .into_iter()
+        .filter(|answer| answer.record_type == record_type.code())
+        .map(|answer| match record_type {
+            // TXT data is quoted and long records are split into several quoted strings
+            DnsRecordType::Txt if answer.data.contains('"') => answer
+                .data
+                .split('"')
+                .skip(1)
+                .step_by(2)
+                .collect::<String>(),
+            _ => answer.data,
+        })
+        .collect()
+}
+
+pub struct DohResolver;
+
+impl DohResolver {
+    async fn query_provider(
+        provider: &str,
+        host: &str,
+        record_type: DnsRecordType,
+    ) -> Result<Vec<String>, anyhow::Error> {
+        let response = DOH_CLIENT
+            .get(provider)
+            .query(&[("name", host), ("type", record_type.name())])
+            .header("accept", "application/dns-json")
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<DohResponse>()
+            .await?;
+        Ok(parse_doh_response(response, record_type))
+    }
+
+    /// Tries each configured provider in order, returns None when DoH is disabled
+    pub async fn resolve(host: &str, record_type: DnsRecordType) -> Option<Vec<String>> {
+        let dns_over_https = ConfigCore::content().await.dns_over_https().clone();
+        if !dns_over_https.enabled {
+            return None;
+        }
+        for provider in &dns_over_https.providers {
+            match Self::query_provider(provider, host, record_type).await {
+                Ok(records) if !records.is_empty() => return Some(records),
+                Ok(_) => {
+                    debug!(target: LOG_TARGET, "No {} records for {} from {}", record_type.name(), host, provider);
+                }
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "DoH provider {} failed to resolve {}: {:?}", provider, host, e);
+                }
+            }
+        }
+        None
+    }
+
+    async fn resolve_addresses(host: &str) -> Option<Vec<SocketAddr>> {
+        let mut records = Self::resolve(host, DnsRecordType::A)
+            .await
+            .unwrap_or_default();
+        records.extend(
+            Self::resolve(host, DnsRecordType::Aaaa)
+                .await
+                .unwrap_or_default(),
+        );
+        let addresses: Vec<SocketAddr> = records
+            .iter()
+            .filter_map(|record| record.parse::<IpAddr>().ok())
+            .map(|ip| SocketAddr::new(ip, 0))
+            .collect();
+        if addresses.is_empty() {
+            None
+        } else {
+            Some(addresses)
+        }
+    }
+
+    /// Looks up the seed peers published in the seed TXT records so the node can be handed
+    /// them directly instead of resolving the seeds through the system DNS
+    pub async fn resolve_peer_seeds(network: Network) -> Vec<String> {
+        let mut peer_seeds = vec![];
+        for host in seed_hostnames(network) {
+            if let Some(records) = Self::resolve(&host, DnsRecordType::Txt).await {
+                peer_seeds.extend(records);
+            }
+        }
+        peer_seeds.dedup();
+        peer_seeds
+    }
+}
+
+impl Resolve for DohResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            if let Some(addresses) = DohResolver::resolve_addresses(&host).await {
+                let addrs: Addrs = Box::new(addresses.into_iter());
+                return Ok(addrs);
+            }
+            let result: Result<Addrs, Box<dyn std::error::Error + Send + Sync>> =
+                match lookup_host((host.as_str(), 0)).await {
+                    Ok(addresses) => Ok(Box::new(addresses.collect::<Vec<_>>().into_iter())),
+                    Err(e) => Err(Box::new(e)),
+                };
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn txt_records_are_unquoted() {
+        let response: DohResponse = serde_json::from_str(
+            r#"{"Status":0,"Answer":[{"name":"seeds.tari.com","type":16,"TTL":60,"data":"\"abc::/ip4/1.2.3.4\" \"/tcp/18189\""}]}"#,
+        )
+        .unwrap_or(DohResponse {
+            status: 1,
+            answer: vec![],
+        });
+        assert_eq!(
+            parse_doh_response(response, DnsRecordType::Txt),
+            vec!["abc::/ip4/1.2.3.4/tcp/18189".to_string()]
+        );
+    }
+
+    #[test]
+    fn failed_lookups_return_no_records() {
+        let response = DohResponse {
+            status: 3,
+            answer: vec![DohAnswer {
+                record_type: 1,
+                data: "1.2.3.4".to_string(),
+            }],
+        };
+        assert!(parse_doh_response(response, DnsRecordType::A).is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use serde::Serialize;
+
+use crate::{utils::timezone_utils::AppTimezone, wallet_adapter::TransactionInfo};
+
+const REPORT_HEADER: &str = "local_time,date,amount_utari,block_height,tx_id";
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct DailyEarnings {
+    /// Calendar day in the app timezone, formatted as YYYY-MM-DD
+    pub date: String,
+    pub amount: u64,
+    pub blocks: u32,
+}
+
+fn transaction_instant(transaction: &TransactionInfo) -> Option<DateTime<Utc>> {
+    let timestamp = i64::try_from(transaction.timestamp).ok()?;
+    Utc.timestamp_opt(timestamp, 0).single()
+}
+
+/// Sums coinbase rewards per calendar day in `timezone`, newest day first. A reward mined at
+/// 23:30 local time is counted towards that day even when it's already the next day in UTC.
+pub fn rollup_daily_earnings(
+    transactions: &[TransactionInfo],
+    timezone: &AppTimezone,
+) -> Vec<DailyEarnings> {
+    let mut days: BTreeMap<NaiveDate, (u64, u32)> = BTreeMap::new();
+    for transaction in transactions.iter().filter(|tx| !tx.is_cancelled) {
+        let Some(instant) = transaction_instant(transaction) else {
+            continue;
+        };
+        let day = days.entry(timezone.local_date_of(instant)).or_default();
+        day.0 = day.0.saturating_add(transaction.amount.as_u64());
+        day.1 = day.1.saturating_add(1);
+    }
+    days.into_iter()
+        .rev()
+        .map(|(date, (amount, blocks))| DailyEarnings {
+            date: date.to_string(),
+            amount,
+            blocks,
+        })
+        .collect()
+}
+
+/// Renders coinbase rewards as CSV with times in `timezone`, including the UTC offset that
+/// was in effect at each instant.
+pub fn earnings_report_csv(transactions: &[TransactionInfo], timezone: &AppTimezone) -> String {
+    let rows = transactions
+        .iter()
+        .filter(|tx| !tx.is_cancelled)
+        .filter_map(|transaction| {
+            let instant = transaction_instant(transaction)?;
+            Some(format!(
+                "{},{},{},{},{}",
+                timezone.format_instant(instant),
+                timezone.local_date_of(instant),
+                transaction.amount.as_u64(),
+                transaction.mined_in_block_height,
+                transaction.tx_id
+            ))
+        });
+    std::iter::once(REPORT_HEADER.to_string())
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use tari_core::transactions::tari_amount::MicroMinotari;
+
+    use super::*;
+
+    fn coinbase(tx_id: &str, timestamp: i64, amount: u64) -> TransactionInfo {
+        TransactionInfo {
+            tx_id: tx_id.to_string(),
+            source_address: String::new(),
+            dest_address: String::new(),
+            status: 13,
+            amount: MicroMinotari(amount),
+            is_cancelled: false,
+            direction: 1,
+            excess_sig: vec![],
+            fee: 0,
+            timestamp: u64::try_from(timestamp).unwrap(),
+            payment_id: String::new(),
+            mined_in_block_height: 1,
+            payment_reference: None,
+            label: None,
+            counterparty_label: None,
+        }
+    }
+
+    #[test]
+    fn test_rollup_uses_local_calendar_day() {
+        let tz = AppTimezone::from_config_value(Some("America/New_York")).unwrap();
+        // 2024-07-15 02:00 UTC is still 2024-07-14 22:00 in New York (EDT)
+        let late_evening = Utc.with_ymd_and_hms(2024, 7, 15, 2, 0, 0).unwrap();
+        let next_morning = Utc.with_ymd_and_hms(2024, 7, 15, 14, 0, 0).unwrap();
+        let transactions = vec![
+            coinbase("1", late_evening.timestamp(), 10),
+            coinbase("2", next_morning.timestamp(), 5),
+        ];
+
+        assert_eq!(
+            rollup_daily_earnings(&transactions, &tz),
+            vec![
+                DailyEarnings {
+                    date: "2024-07-15".to_string(),
+                    amount: 5,
+                    blocks: 1,
+                },
+                DailyEarnings {
+                    date: "2024-07-14".to_string(),
+                    amount: 10,
+                    blocks: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_includes_local_offset() {
+        let tz = AppTimezone::from_config_value(Some("Europe/London")).unwrap();
+        let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+        let report = earnings_report_csv(&[coinbase("7", summer.timestamp(), 42)], &tz);
+
+        assert_eq!(
+            report.lines().nth(1),
+            Some("2024-07-15T13:00:00+01:00,2024-07-15,42,1,7")
+        );
+    }
+}
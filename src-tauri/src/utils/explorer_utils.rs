@@ -0,0 +1,116 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde::Serialize;
+use tari_common::configuration::Network;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ExplorerUrls {
+    pub base_url: String,
+    pub text_base_url: String,
+    pub block_url: Option<String>,
+    pub text_block_url: Option<String>,
+    pub tx_url: Option<String>,
+}
+
+/// Explorer hosts are shared per network group, the same way the frontend resolves them
+fn network_suffix(network: Network) -> &'static str {
+    match network {
+        Network::MainNet => "",
+        Network::StageNet | Network::NextNet => "-nextnet",
+        Network::LocalNet | Network::Igor | Network::Esmeralda => "-esmeralda",
+    }
+}
+
+pub fn get_explorer_base_url(network: Network, text_explorer: bool) -> String {
+    let subdomain = if text_explorer {
+        "textexplore"
+    } else {
+        "explore"
+    };
+    format!("https://{}{}.tari.com", subdomain, network_suffix(network))
+}
+
+pub fn build_explorer_urls(
+    network: Network,
+    block_height: Option<u64>,
+    tx_id: Option<&str>,
+) -> ExplorerUrls {
+    let base_url = get_explorer_base_url(network, false);
+    let text_base_url = get_explorer_base_url(network, true);
+
+    ExplorerUrls {
+        block_url: block_height.map(|height| format!("{}/blocks/{}", base_url, height)),
+        text_block_url: block_height.map(|height| format!("{}/blocks/{}", text_base_url, height)),
+        tx_url: tx_id
+            .map(str::trim)
+            .filter(|tx_id| !tx_id.is_empty())
+            .map(|tx_id| format!("{}/search?q={}", base_url, urlencoding::encode(tx_id))),
+        base_url,
+        text_base_url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mainnet_block_urls() {
+        let urls = build_explorer_urls(Network::MainNet, Some(1234), None);
+        assert_eq!(urls.base_url, "https://explore.tari.com");
+        assert_eq!(
+            urls.block_url.as_deref(),
+            Some("https://explore.tari.com/blocks/1234")
+        );
+        assert_eq!(
+            urls.text_block_url.as_deref(),
+            Some("https://textexplore.tari.com/blocks/1234")
+        );
+        assert_eq!(urls.tx_url, None);
+    }
+
+    #[test]
+    fn test_testnet_hosts() {
+        assert_eq!(
+            get_explorer_base_url(Network::Esmeralda, false),
+            "https://explore-esmeralda.tari.com"
+        );
+        assert_eq!(
+            get_explorer_base_url(Network::NextNet, true),
+            "https://textexplore-nextnet.tari.com"
+        );
+    }
+
+    #[test]
+    fn test_tx_url_is_encoded() {
+        let urls = build_explorer_urls(Network::MainNet, None, Some(" a b "));
+        assert_eq!(
+            urls.tx_url.as_deref(),
+            Some("https://explore.tari.com/search?q=a%20b")
+        );
+        assert_eq!(
+            build_explorer_urls(Network::MainNet, None, Some("")).tx_url,
+            None
+        );
+    }
+}
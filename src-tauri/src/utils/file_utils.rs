@@ -23,6 +23,10 @@
 use std::path::{Component, Path, PathBuf};
 
 use anyhow::anyhow;
+use log::{error, warn};
+use tokio::fs;
+
+const LOG_TARGET: &str = "tari::universe::file_utils";
 
 /// Returns a relative path from one path to another.
 pub fn make_relative_path(root: &Path, current: &Path) -> PathBuf {
@@ -62,3 +66,30 @@ pub fn convert_to_string(path: PathBuf) -> Result<String, anyhow::Error> {
         .map(|s| s.to_string())
         .ok_or_else(|| anyhow!("Could not convert path to string"))
 }
+
+/// Replaces `target_dir` with `new_dir`. The current directory is moved aside first and put back
+/// if the swap fails, so it is only removed once the new one is in place.
+pub async fn swap_in_dir(new_dir: &Path, target_dir: &Path) -> Result<(), anyhow::Error> {
+    let previous_dir = target_dir.with_extension("previous");
+    if previous_dir.exists() {
+        fs::remove_dir_all(&previous_dir).await?;
+    }
+    let has_current_dir = target_dir.exists();
+    if has_current_dir {
+        fs::rename(target_dir, &previous_dir).await?;
+    }
+    if let Err(e) = fs::rename(new_dir, target_dir).await {
+        if has_current_dir {
+            if let Err(rollback) = fs::rename(&previous_dir, target_dir).await {
+                error!(target: LOG_TARGET, "Could not put {:?} back from {:?}: {:?}", target_dir, previous_dir, rollback);
+            }
+        }
+        return Err(e.into());
+    }
+    if has_current_dir {
+        if let Err(e) = fs::remove_dir_all(&previous_dir).await {
+            warn!(target: LOG_TARGET, "Could not remove the previous copy at {:?}: {:?}", previous_dir, e);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use log::error;
+use tauri::{AppHandle, Manager};
+
+use crate::{commands, UniverseAppState};
+
+const LOG_TARGET: &str = "tari::universe::mining_utils";
+
+pub async fn start_all_miners(app_handle: &AppHandle) {
+    let state = app_handle.state::<UniverseAppState>();
+    let _unused = commands::start_cpu_mining(state.clone(), app_handle.clone())
+        .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "Could not start CPU mining: {}", e));
+    let _unused = commands::start_gpu_mining(state, app_handle.clone(), None)
+        .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "Could not start GPU mining: {}", e));
+}
+
+pub async fn stop_all_miners(app_handle: &AppHandle) {
+    let state = app_handle.state::<UniverseAppState>();
+    let _unused = commands::stop_cpu_mining(state.clone())
+        .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "Could not stop CPU mining: {}", e));
+    let _unused = commands::stop_gpu_mining(state)
+        .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "Could not stop GPU mining: {}", e));
+}
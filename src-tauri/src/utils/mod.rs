@@ -22,16 +22,25 @@
 
 pub mod address_utils;
 pub mod app_flow_utils;
+pub mod clipboard_monitor;
+pub mod connectivity_check;
+pub mod doh_resolver;
+pub mod earnings_report;
+pub mod explorer_utils;
 pub mod file_utils;
 pub mod formatting_utils;
 pub mod locks_utils;
 pub mod logging_utils;
 pub mod macos_utils;
 pub mod math_utils;
+pub mod mining_utils;
 pub mod network_status;
+pub mod pagination;
 pub mod platform_utils;
 pub mod wallet_utils;
 
 pub mod system_status;
+pub mod time_sync;
+pub mod timezone_utils;
 #[cfg(windows)]
 pub mod windows_setup_utils;
@@ -0,0 +1,121 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde::Serialize;
+
+/// Envelope returned by list commands. `next_cursor` is passed back as the `cursor` argument to
+/// fetch the following page and is absent once the list is exhausted.
+#[derive(Debug, Serialize, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub total_estimate: Option<u64>,
+}
+
+impl<T> Page<T> {
+    /// A page shorter than the requested limit is taken to be the last one
+    pub fn from_offset(items: Vec<T>, offset: usize, limit: Option<usize>) -> Self {
+        let next_cursor = match limit {
+            Some(limit) if limit > 0 && items.len() >= limit => {
+                Some((offset + items.len()).to_string())
+            }
+            _ => None,
+        };
+        Self {
+            items,
+            next_cursor,
+            total_estimate: None,
+        }
+    }
+
+    /// Pages through a list that is already fully in memory, so the total is exact
+    pub fn from_all(
+        items: Vec<T>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Self, String> {
+        let offset = parse_cursor(cursor)?;
+        let total = items.len();
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .filter(|limit| *limit > 0)
+            .unwrap_or(total);
+        let items: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+        let end = offset.saturating_add(items.len());
+        Ok(Self {
+            items,
+            next_cursor: (end < total).then(|| end.to_string()),
+            total_estimate: Some(u64::try_from(total).unwrap_or(u64::MAX)),
+        })
+    }
+
+    pub fn with_total_estimate(mut self, total_estimate: u64) -> Self {
+        self.total_estimate = Some(total_estimate);
+        self
+    }
+}
+
+pub fn parse_cursor(cursor: Option<&str>) -> Result<usize, String> {
+    cursor.map_or(Ok(0), |cursor| {
+        cursor
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid pagination cursor: {}", cursor))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_page_has_next_cursor() {
+        let page = Page::from_offset(vec![1, 2, 3], 6, Some(3));
+        assert_eq!(page.next_cursor, Some("9".to_string()));
+    }
+
+    #[test]
+    fn short_or_unlimited_page_is_last() {
+        assert_eq!(Page::from_offset(vec![1, 2], 0, Some(3)).next_cursor, None);
+        assert_eq!(Page::from_offset(vec![1, 2], 0, None).next_cursor, None);
+    }
+
+    #[test]
+    fn pages_through_in_memory_list() {
+        let first = Page::from_all(vec![1, 2, 3, 4, 5], None, Some(2)).expect("page");
+        assert_eq!(first.items, vec![1, 2]);
+        assert_eq!(first.total_estimate, Some(5));
+        let last = Page::from_all(vec![1, 2, 3, 4, 5], Some("4"), Some(2)).expect("page");
+        assert_eq!(last.items, vec![5]);
+        assert_eq!(last.next_cursor, None);
+        assert_eq!(
+            Page::from_all(vec![1, 2], None, None).map(|page| page.next_cursor),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn parses_cursor() {
+        assert_eq!(parse_cursor(None), Ok(0));
+        assert_eq!(parse_cursor(Some("20")), Ok(20));
+        assert!(parse_cursor(Some("abc")).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    sync::LazyLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use log::{error, info, warn};
+use serde::Serialize;
+use tokio::{
+    net::UdpSocket,
+    select,
+    sync::watch::{Receiver, Sender},
+    time,
+};
+
+use crate::{events_emitter::EventsEmitter, tasks_tracker::TasksTrackers};
+
+const LOG_TARGET: &str = "tari::universe::time_sync";
+const NTP_SERVERS: [&str; 3] = [
+    "pool.ntp.org:123",
+    "time.cloudflare.com:123",
+    "time.google.com:123",
+];
+const NTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const TIME_SYNC_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// Seconds between 1900-01-01 (NTP epoch) and 1970-01-01 (UNIX epoch)
+const NTP_UNIX_EPOCH_DELTA: u64 = 2_208_988_800;
+const NTP_PACKET_SIZE: usize = 48;
+pub const MAX_ALLOWED_CLOCK_DRIFT_MS: i64 = 30_000;
+
+static INSTANCE: LazyLock<TimeSync> = LazyLock::new(TimeSync::new);
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SystemTimeStatus {
+    pub drift_ms: Option<i64>,
+    pub is_drift_too_high: bool,
+    pub ntp_server: Option<String>,
+    pub last_checked_at: Option<u64>,
+    pub error: Option<String>,
+}
+
+pub struct TimeSync {
+    sender: Sender<SystemTimeStatus>,
+    receiver: Receiver<SystemTimeStatus>,
+}
+
+impl TimeSync {
+    fn new() -> Self {
+        let (sender, receiver) = tokio::sync::watch::channel(SystemTimeStatus::default());
+        Self { sender, receiver }
+    }
+
+    pub fn current() -> &'static TimeSync {
+        &INSTANCE
+    }
+
+    pub fn get_status(&self) -> SystemTimeStatus {
+        self.receiver.borrow().clone()
+    }
+
+    fn ntp_timestamp_to_ms(bytes: &[u8]) -> i64 {
+        let seconds = u64::from(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+        let fraction = u64::from(u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]));
+        let unix_seconds = seconds.saturating_sub(NTP_UNIX_EPOCH_DELTA);
+        i64::try_from(unix_seconds * 1000 + ((fraction * 1000) >> 32)).unwrap_or(i64::MAX)
+    }
+
+    fn system_time_ms() -> i64 {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        i64::try_from(millis).unwrap_or(i64::MAX)
+    }
+
+    /// Queries a single SNTP server and returns the local clock offset in milliseconds.
+    /// Positive values mean the local clock is behind the server.
+    async fn query_clock_offset(server: &str) -> Result<i64, anyhow::Error> {
+        let socket = UdpSocket::bind("0.0.0.0:0").await?;
+        socket.connect(server).await?;
+
+        let mut request = [0u8; NTP_PACKET_SIZE];
+        // LI = 0, VN = 3, Mode = 3 (client)
+        request[0] = 0x1B;
+
+        let originate_ms = Self::system_time_ms();
+        socket.send(&request).await?;
+
+        let mut response = [0u8; NTP_PACKET_SIZE];
+        let received = time::timeout(NTP_REQUEST_TIMEOUT, socket.recv(&mut response))
+            .await
+            .map_err(|_| anyhow!("NTP request to {} timed out", server))??;
+        let destination_ms = Self::system_time_ms();
+
+        if received < NTP_PACKET_SIZE {
+            return Err(anyhow!(
+                "Invalid NTP response size from {}: {}",
+                server,
+                received
+            ));
+        }
+
+        let receive_ms = Self::ntp_timestamp_to_ms(&response[32..40]);
+        let transmit_ms = Self::ntp_timestamp_to_ms(&response[40..48]);
+        if transmit_ms == 0 {
+            return Err(anyhow!(
+                "NTP server {} returned empty transmit timestamp",
+                server
+            ));
+        }
+
+        Ok(((receive_ms - originate_ms) + (transmit_ms - destination_ms)) / 2)
+    }
+
+    pub async fn check_clock_drift(&self) -> SystemTimeStatus {
+        let mut last_error = None;
+        let mut status = SystemTimeStatus::default();
+
+        for server in NTP_SERVERS {
+            match Self::query_clock_offset(server).await {
+                Ok(drift_ms) => {
+                    status = SystemTimeStatus {
+                        drift_ms: Some(drift_ms),
+                        is_drift_too_high: drift_ms.abs() > MAX_ALLOWED_CLOCK_DRIFT_MS,
+                        ntp_server: Some(server.to_string()),
+                        last_checked_at: None,
+                        error: None,
+                    };
+                    last_error = None;
+                    break;
+                }
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "Failed to query NTP server {}: {:?}", server, e);
+                    last_error = Some(e.to_string());
+                }
+            }
+        }
+
+        status.error = last_error;
+        status.last_checked_at = Some(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        );
+
+        match status.drift_ms {
+            Some(drift_ms) => {
+                info!(target: LOG_TARGET, "System clock drift: {} ms (server: {:?})", drift_ms, status.ntp_server)
+            }
+            None => {
+                error!(target: LOG_TARGET, "Could not determine system clock drift: {:?}", status.error)
+            }
+        }
+
+        let _unused = self.sender.send(status.clone()).inspect_err(|e| {
+            error!(target: LOG_TARGET, "Failed to send system time status: {:?}", e);
+        });
+
+        if status.is_drift_too_high {
+            warn!(target: LOG_TARGET, "System clock drift exceeds {} ms", MAX_ALLOWED_CLOCK_DRIFT_MS);
+            EventsEmitter::emit_system_time_drift(status.clone()).await;
+        }
+
+        status
+    }
+
+    pub async fn start_periodic_check(&self) {
+        let mut interval = time::interval(TIME_SYNC_CHECK_INTERVAL);
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                loop {
+                    select! {
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received. Stopping time sync checks.");
+                            break;
+                        }
+                        _ = interval.tick() => {
+                            TimeSync::current().check_clock_drift().await;
+                        }
+                    }
+                }
+            });
+    }
+}
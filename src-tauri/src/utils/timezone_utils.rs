@@ -0,0 +1,179 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::configs::{config_core::ConfigCore, trait_config::ConfigImpl};
+
+const TIME_FORMAT: &str = "%H:%M";
+
+/// Timezone used for scheduling and reporting. `System` follows the OS timezone,
+/// including its DST rules.
+#[derive(Debug, Clone, Copy)]
+pub enum AppTimezone {
+    System,
+    Named(Tz),
+}
+
+impl AppTimezone {
+    pub fn from_config_value(value: Option<&str>) -> Result<Self, anyhow::Error> {
+        match value {
+            None | Some("") => Ok(AppTimezone::System),
+            Some(name) => Tz::from_str(name)
+                .map(AppTimezone::Named)
+                .map_err(|e| anyhow!("Invalid timezone {}: {}", name, e)),
+        }
+    }
+
+    pub async fn current() -> Self {
+        let configured = ConfigCore::content().await.timezone().clone();
+        AppTimezone::from_config_value(configured.as_deref()).unwrap_or(AppTimezone::System)
+    }
+
+    pub fn local_time_of(&self, instant: DateTime<Utc>) -> NaiveTime {
+        match self {
+            AppTimezone::System => instant.with_timezone(&Local).time(),
+            AppTimezone::Named(tz) => instant.with_timezone(tz).time(),
+        }
+    }
+
+    pub fn local_date_of(&self, instant: DateTime<Utc>) -> NaiveDate {
+        match self {
+            AppTimezone::System => instant.with_timezone(&Local).date_naive(),
+            AppTimezone::Named(tz) => instant.with_timezone(tz).date_naive(),
+        }
+    }
+
+    /// Formats an instant as RFC 3339 with the offset valid at that instant.
+    pub fn format_instant(&self, instant: DateTime<Utc>) -> String {
+        match self {
+            AppTimezone::System => instant.with_timezone(&Local).to_rfc3339(),
+            AppTimezone::Named(tz) => instant.with_timezone(tz).to_rfc3339(),
+        }
+    }
+
+    pub fn now_time(&self) -> NaiveTime {
+        self.local_time_of(Utc::now())
+    }
+
+    /// Formats a unix timestamp (seconds) as RFC 3339 with the offset valid at that instant.
+    pub fn format_timestamp(&self, timestamp: i64) -> Option<String> {
+        let instant = Utc.timestamp_opt(timestamp, 0).single()?;
+        Some(self.format_instant(instant))
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            AppTimezone::System => "System".to_string(),
+            AppTimezone::Named(tz) => tz.name().to_string(),
+        }
+    }
+}
+
+pub fn parse_time_of_day(value: &str) -> Result<NaiveTime, anyhow::Error> {
+    NaiveTime::parse_from_str(value, TIME_FORMAT)
+        .map_err(|e| anyhow!("Invalid time {}, expected HH:MM: {}", value, e))
+}
+
+/// Checks if `time` falls into `[start, end)`. Windows that cross midnight (e.g. 22:00 - 06:00)
+/// are supported. Equal `start` and `end` mean the whole day.
+pub fn is_time_in_window(time: NaiveTime, start: NaiveTime, end: NaiveTime) -> bool {
+    if start == end {
+        return true;
+    }
+    if start < end {
+        time >= start && time < end
+    } else {
+        time >= start || time < end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn time(value: &str) -> NaiveTime {
+        parse_time_of_day(value).unwrap()
+    }
+
+    #[test]
+    fn test_window_within_same_day() {
+        assert!(is_time_in_window(
+            time("09:00"),
+            time("08:00"),
+            time("17:00")
+        ));
+        assert!(!is_time_in_window(
+            time("17:00"),
+            time("08:00"),
+            time("17:00")
+        ));
+        assert!(!is_time_in_window(
+            time("07:59"),
+            time("08:00"),
+            time("17:00")
+        ));
+    }
+
+    #[test]
+    fn test_window_crossing_midnight() {
+        assert!(is_time_in_window(
+            time("23:30"),
+            time("22:00"),
+            time("06:00")
+        ));
+        assert!(is_time_in_window(
+            time("02:00"),
+            time("22:00"),
+            time("06:00")
+        ));
+        assert!(!is_time_in_window(
+            time("12:00"),
+            time("22:00"),
+            time("06:00")
+        ));
+    }
+
+    #[test]
+    fn test_named_timezone_respects_dst() {
+        let tz = AppTimezone::from_config_value(Some("Europe/London")).unwrap();
+        // 12:00 UTC is 12:00 GMT in January and 13:00 BST in July
+        let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+        assert_eq!(tz.local_time_of(winter), time("12:00"));
+        assert_eq!(tz.local_time_of(summer), time("13:00"));
+    }
+
+    #[test]
+    fn test_invalid_timezone() {
+        assert!(AppTimezone::from_config_value(Some("Mars/Olympus")).is_err());
+        assert!(matches!(
+            AppTimezone::from_config_value(None).unwrap(),
+            AppTimezone::System
+        ));
+    }
+}
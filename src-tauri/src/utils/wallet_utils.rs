@@ -20,11 +20,13 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::env::temp_dir;
+use std::{collections::HashSet, env::temp_dir, str::FromStr};
 
 use crate::{
+    configs::config_wallet::ConfigWalletContent,
     consts::DEFAULT_MONERO_ADDRESS,
     credential_manager::{Credential, CredentialManager},
+    wallet_adapter::TransactionInfo,
     APPLICATION_FOLDER_ID,
 };
 use anyhow::Error;
@@ -32,8 +34,22 @@ use dirs::config_dir;
 use log::{info, warn};
 use monero_address_creator::network::Mainnet;
 use monero_address_creator::Seed as MoneroSeed;
+use serde::Serialize;
+use tari_common_types::tari_address::TariAddress;
+use tari_core::transactions::tari_amount::MicroMinotari;
 
 static LOG_TARGET: &str = "tari::universe::wallet_utils";
+const TX_DIRECTION_INBOUND: i32 = 1;
+const TX_DIRECTION_OUTBOUND: i32 = 2;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WatchedAddressStatus {
+    pub address: String,
+    pub label: Option<String>,
+    pub total_received: MicroMinotari,
+    pub transaction_count: usize,
+    pub last_received_at: Option<u64>,
+}
 
 pub async fn create_monereo_address() -> Result<String, Error> {
     let config_dir = config_dir()
@@ -68,3 +84,88 @@ pub async fn create_monereo_address() -> Result<String, Error> {
         .to_address::<Mainnet>()
         .unwrap_or(DEFAULT_MONERO_ADDRESS.to_string()))
 }
+
+/// Addresses can be given in base58, hex or emoji form, labels are stored against base58
+pub fn normalize_tari_address(address: &str) -> Option<String> {
+    TariAddress::from_str(address.trim())
+        .ok()
+        .map(|address| address.to_base58())
+}
+
+pub fn apply_transaction_labels(
+    transactions: &mut [TransactionInfo],
+    config: &ConfigWalletContent,
+) {
+    for transaction in transactions.iter_mut() {
+        transaction.label = config.tx_labels().get(&transaction.tx_id).cloned();
+        let counterparty = if transaction.direction == TX_DIRECTION_OUTBOUND {
+            &transaction.dest_address
+        } else {
+            &transaction.source_address
+        };
+        transaction.counterparty_label = normalize_tari_address(counterparty)
+            .and_then(|address| config.address_labels().get(&address).cloned());
+    }
+}
+
+fn is_received_at(transaction: &TransactionInfo, address: &str) -> bool {
+    !transaction.is_cancelled
+        && normalize_tari_address(&transaction.dest_address).as_deref() == Some(address)
+}
+
+/// Transactions this wallet received on a watched address since the last call. `seen_tx_ids`
+/// carries over between calls, on the first call it is filled without reporting anything so
+/// history from before the app started is not announced again.
+pub fn new_watched_receipts<'a>(
+    config: &ConfigWalletContent,
+    transactions: &'a [TransactionInfo],
+    seen_tx_ids: &mut Option<HashSet<String>>,
+) -> Vec<(String, &'a TransactionInfo)> {
+    let is_first_scan = seen_tx_ids.is_none();
+    let seen = seen_tx_ids.get_or_insert_with(HashSet::new);
+    transactions
+        .iter()
+        .filter(|transaction| transaction.direction == TX_DIRECTION_INBOUND)
+        .filter_map(|transaction| {
+            let address = config
+                .watched_addresses()
+                .iter()
+                .find(|address| is_received_at(transaction, address))?;
+            let is_new = seen.insert(transaction.tx_id.clone());
+            (is_new && !is_first_scan).then(|| (address.clone(), transaction))
+        })
+        .collect()
+}
+
+/// Totals what reached each watched address, both sends from this wallet and transactions the
+/// wallet received on it. Funds arriving there from other wallets can't be seen without the
+/// address' view key.
+pub fn summarize_watched_addresses(
+    config: &ConfigWalletContent,
+    transactions: &[TransactionInfo],
+) -> Vec<WatchedAddressStatus> {
+    config
+        .watched_addresses()
+        .iter()
+        .map(|address| {
+            let received: Vec<&TransactionInfo> = transactions
+                .iter()
+                .filter(|transaction| is_received_at(transaction, address))
+                .collect();
+            WatchedAddressStatus {
+                address: address.clone(),
+                label: config.address_labels().get(address).cloned(),
+                total_received: received
+                    .iter()
+                    .fold(MicroMinotari(0), |total, transaction| {
+                        total + transaction.amount
+                    }),
+                transaction_count: received.len(),
+                last_received_at: received
+                    .iter()
+                    .map(|transaction| transaction.timestamp)
+                    .max(),
+            }
+        })
+        .collect()
+}
@@ -28,17 +28,18 @@ use crate::process_adapter_utils::setup_working_directory;
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::file_utils::convert_to_string;
 use crate::utils::logging_utils::setup_logging;
+use crate::wallet_grpc_connection::{WalletConnectionHealth, WalletGrpcConnection};
 use anyhow::Error;
 use async_trait::async_trait;
 use log::{info, warn};
-use minotari_node_grpc_client::grpc::wallet_client::WalletClient;
 use minotari_node_grpc_client::grpc::{
-    GetAllCompletedTransactionsRequest, GetBalanceRequest, GetBalanceResponse,
+    Empty, GetAllCompletedTransactionsRequest, GetBalanceRequest, GetBalanceResponse,
     GetCompletedTransactionsRequest, GetCompletedTransactionsResponse, GetStateRequest,
     ImportTransactionsRequest, NetworkStatusResponse,
 };
 use serde::Serialize;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use tari_common::configuration::Network;
 use tari_common_types::tari_address::{TariAddress, TariAddressError};
@@ -67,6 +68,7 @@ pub struct WalletAdapter {
     pub(crate) state_broadcast: watch::Sender<Option<WalletState>>,
     pub(crate) wallet_birthday: Option<u16>,
     coinbase_transactions_stream: Mutex<Option<Streaming<GetCompletedTransactionsResponse>>>,
+    grpc_connection: Arc<WalletGrpcConnection>,
 }
 
 impl WalletAdapter {
@@ -85,6 +87,7 @@ impl WalletAdapter {
             state_broadcast,
             wallet_birthday: None,
             coinbase_transactions_stream: Mutex::new(None),
+            grpc_connection: Arc::new(WalletGrpcConnection::new(grpc_port)),
         }
     }
 
@@ -96,41 +99,60 @@ impl WalletAdapter {
         self.connect_with_local_node = connect_with_local_node;
     }
 
+    pub async fn connection_health(&self) -> WalletConnectionHealth {
+        self.grpc_connection.health().await
+    }
+
     pub async fn get_balance(&self) -> Result<WalletBalance, anyhow::Error> {
-        let mut client = WalletClient::connect(self.wallet_grpc_address())
-            .await
-            .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
-        let res = client
-            .get_balance(GetBalanceRequest { payment_id: None })
+        let balance = self
+            .grpc_connection
+            .call("get_balance", |mut client| async move {
+                client
+                    .get_balance(GetBalanceRequest { payment_id: None })
+                    .await
+            })
             .await?;
-        let balance = res.into_inner();
 
         Ok(WalletBalance::from_response(balance))
     }
 
-    pub async fn import_transaction(&self, tx_output_file: PathBuf) -> Result<(), anyhow::Error> {
+    pub async fn get_unspent_amounts(&self) -> Result<Vec<MicroMinotari>, anyhow::Error> {
+        let response = self
+            .grpc_connection
+            .call("get_unspent_amounts", |mut client| async move {
+                client.get_unspent_amounts(Empty {}).await
+            })
+            .await?;
+
+        Ok(response.amount.into_iter().map(MicroMinotari).collect())
+    }
+
+    pub async fn import_transaction(
+        &self,
+        tx_output_file: PathBuf,
+    ) -> Result<(), WalletStatusMonitorError> {
         let tx_json = std::fs::read_to_string(&tx_output_file).map_err(|e| {
             log::error!(
                 "[import_transaction] Failed to read transaction output file: {}, output_file:\n{:?}",
                 e,
                 tx_output_file
             );
-            anyhow::anyhow!("Failed to read transaction output file: {}", e)
+            WalletStatusMonitorError::UnknownError(anyhow::anyhow!(
+                "Failed to read transaction output file: {}",
+                e
+            ))
         })?;
 
-        let mut client = WalletClient::connect(self.wallet_grpc_address())
-            .await
-            .map_err(|e| {
-                log::error!(
-                    "[import_transaction] Failed to connect to wallet client: {}",
-                    e
-                );
-                anyhow::anyhow!("Failed to connect to wallet client")
-            })?;
-
-        let res = client
-            .import_transactions(ImportTransactionsRequest {
-                txs: format!("[{}]", tx_json.trim()),
+        let txs = format!("[{}]", tx_json.trim());
+        let res = self
+            .grpc_connection
+            .call_once("import_transactions", |mut client| {
+                let txs = txs.clone();
+                async move {
+                    client
+                        .import_transactions(ImportTransactionsRequest { txs })
+                        .await
+                }
             })
             .await
             .map_err(|e| {
@@ -138,13 +160,13 @@ impl WalletAdapter {
                     "[import_transaction] Failed to import transactions: {:?}",
                     e
                 );
-                anyhow::anyhow!("Failed to import transactions: {:?}", e)
+                e
             })?;
 
         info!(
             target: LOG_TARGET,
             "Transaction imported to the view wallet successfully, tx_id: {:?}",
-            res.into_inner().tx_ids.first()
+            res.tx_ids.first()
         );
 
         Ok(())
@@ -156,18 +178,19 @@ impl WalletAdapter {
         limit: Option<i32>,
         current_block_height: u64,
     ) -> Result<Vec<TransactionInfo>, WalletStatusMonitorError> {
-        let mut client = WalletClient::connect(self.wallet_grpc_address())
-            .await
-            .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
-        let res = client
-            .get_all_completed_transactions(GetAllCompletedTransactionsRequest {
-                offset: offset.unwrap_or(0) as u64,
-                limit: limit.unwrap_or(0) as u64,
-                status_bitflag: 0,
+        let request = GetAllCompletedTransactionsRequest {
+            offset: offset.unwrap_or(0) as u64,
+            limit: limit.unwrap_or(0) as u64,
+            status_bitflag: 0,
+        };
+        let transactions_raw = self
+            .grpc_connection
+            .call("get_all_completed_transactions", |mut client| {
+                let request = request.clone();
+                async move { client.get_all_completed_transactions(request).await }
             })
-            .await
-            .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?;
-        let transactions_raw = res.into_inner().transactions;
+            .await?
+            .transactions;
 
         let mut transactions: Vec<TransactionInfo> = Vec::new();
         for tx in transactions_raw {
@@ -208,6 +231,8 @@ impl WalletAdapter {
                 payment_id: PaymentId::stringify_bytes(&tx.user_payment_id),
                 mined_in_block_height: tx.mined_in_block_height,
                 payment_reference,
+                label: None,
+                counterparty_label: None,
             });
             if let Some(limit) = limit {
                 if transactions.len() >= limit as usize {
@@ -234,18 +259,17 @@ impl WalletAdapter {
                 .take()
                 .expect("coinbase_transactions_stream not found")
         } else {
-            let mut client = WalletClient::connect(self.wallet_grpc_address())
-                .await
-                .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
-            let res = client
-                .get_completed_transactions(GetCompletedTransactionsRequest {
-                    payment_id: None,
-                    block_hash: None,
-                    block_height: None,
+            self.grpc_connection
+                .call("get_completed_transactions", |mut client| async move {
+                    client
+                        .get_completed_transactions(GetCompletedTransactionsRequest {
+                            payment_id: None,
+                            block_hash: None,
+                            block_height: None,
+                        })
+                        .await
                 })
-                .await
-                .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?;
-            res.into_inner()
+                .await?
         };
 
         let mut transactions: Vec<TransactionInfo> = Vec::new();
@@ -275,6 +299,8 @@ impl WalletAdapter {
                 payment_id: PaymentId::stringify_bytes(&tx.user_payment_id),
                 mined_in_block_height: tx.mined_in_block_height,
                 payment_reference: None,
+                label: None,
+                counterparty_label: None,
             });
             if let Some(limit) = limit {
                 if transactions.len() >= limit as usize {
@@ -366,10 +392,6 @@ impl WalletAdapter {
 
         Ok(matching_tx)
     }
-
-    pub fn wallet_grpc_address(&self) -> String {
-        format!("http://127.0.0.1:{}", self.grpc_port)
-    }
 }
 
 impl ProcessAdapter for WalletAdapter {
@@ -537,7 +559,7 @@ impl ProcessAdapter for WalletAdapter {
                 },
             },
             WalletStatusMonitor {
-                grpc_port: self.grpc_port,
+                grpc_connection: self.grpc_connection.clone(),
                 state_broadcast: self.state_broadcast.clone(),
             },
         ))
@@ -553,14 +575,14 @@ impl ProcessAdapter for WalletAdapter {
 }
 
 pub struct WalletStatusMonitor {
-    grpc_port: u16,
+    grpc_connection: Arc<WalletGrpcConnection>,
     state_broadcast: watch::Sender<Option<WalletState>>,
 }
 
 impl Clone for WalletStatusMonitor {
     fn clone(&self) -> Self {
         Self {
-            grpc_port: self.grpc_port,
+            grpc_connection: self.grpc_connection.clone(),
             state_broadcast: self.state_broadcast.clone(),
         }
     }
@@ -592,19 +614,13 @@ impl StatusMonitor for WalletStatusMonitor {
 }
 
 impl WalletStatusMonitor {
-    fn wallet_grpc_address(&self) -> String {
-        format!("http://127.0.0.1:{}", self.grpc_port)
-    }
-
     pub async fn get_status(&self) -> Result<WalletState, WalletStatusMonitorError> {
-        let mut client = WalletClient::connect(self.wallet_grpc_address())
-            .await
-            .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
-        let res = client
-            .get_state(GetStateRequest {})
-            .await
-            .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?;
-        let status = res.into_inner();
+        let status = self
+            .grpc_connection
+            .call_once("get_state", |mut client| async move {
+                client.get_state(GetStateRequest {}).await
+            })
+            .await?;
 
         Ok(WalletState {
             scanned_height: status.scanned_height,
@@ -639,6 +655,8 @@ impl WalletStatusMonitor {
 pub enum WalletStatusMonitorError {
     #[error("Wallet not started")]
     WalletNotStarted,
+    #[error("Wallet temporarily unavailable during {0}, retry shortly")]
+    TemporarilyUnavailable(String),
     #[error("Tari address conversion error: {0}")]
     TariAddress(#[from] TariAddressError),
     #[error("Unknown error: {0}")]
@@ -732,6 +750,9 @@ pub struct TransactionInfo {
     pub payment_id: String,
     pub mined_in_block_height: u64,
     pub payment_reference: Option<String>,
+    pub label: Option<String>,
+    /// Label of the other party's address, the sender for inbound and the receiver for outbound
+    pub counterparty_label: Option<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -0,0 +1,451 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    num::NonZeroU32,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Error};
+use log::info;
+use ring::{
+    aead::{self, Aad, LessSafeKey, Nonce, UnboundKey},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tokio::fs;
+
+use crate::{
+    access_control::AccessControl,
+    configs::{
+        config_wallet::{ConfigWallet, ConfigWalletContent},
+        trait_config::ConfigImpl,
+    },
+    download_utils::{unpack_gz_with_manifest, write_dir_as_gz},
+    runtime_paths::ensure_same_network,
+    utils::file_utils::swap_in_dir,
+};
+
+const LOG_TARGET: &str = "tari::universe::wallet_backup";
+const ENCRYPTED_BACKUP_MAGIC: &[u8; 5] = b"TUWB2";
+const PBKDF2_ITERATIONS: u32 = 210_000;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const NONCE_PREFIX_LENGTH: usize = 7;
+const CHUNK_LENGTH: usize = 64 * 1024;
+const TAG_LENGTH: usize = 16;
+
+/// Whether the user has a way to recover the wallet, without any of the secrets involved
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletBackupStatus {
+    pub is_seed_backed_up: bool,
+    pub seed_words_revealed_at: Option<u64>,
+    pub database_backed_up_at: Option<u64>,
+    /// Most recent of the seed reveal and the database backup
+    pub last_backup_at: Option<u64>,
+    pub has_admin_pin: bool,
+}
+
+pub async fn backup_status() -> WalletBackupStatus {
+    let config = ConfigWallet::content().await;
+    let seed_words_revealed_at = *config.seed_words_revealed_at();
+    let database_backed_up_at = *config.database_backed_up_at();
+    WalletBackupStatus {
+        is_seed_backed_up: seed_words_revealed_at.is_some(),
+        seed_words_revealed_at,
+        database_backed_up_at,
+        last_backup_at: seed_words_revealed_at.max(database_backed_up_at),
+        has_admin_pin: AccessControl::status().await.has_admin_pin,
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+pub async fn record_seed_words_revealed() -> Result<(), Error> {
+    ConfigWallet::update_field(
+        ConfigWalletContent::set_seed_words_revealed_at,
+        Some(unix_now()),
+    )
+    .await
+}
+
+pub async fn record_database_backup() -> Result<(), Error> {
+    ConfigWallet::update_field(
+        ConfigWalletContent::set_database_backed_up_at,
+        Some(unix_now()),
+    )
+    .await
+}
+
+/// A new or reset wallet has not been backed up yet, whatever was recorded for the previous one
+pub async fn clear_backup_records() -> Result<(), Error> {
+    ConfigWallet::update_field(ConfigWalletContent::set_seed_words_revealed_at, None).await?;
+    ConfigWallet::update_field(ConfigWalletContent::set_database_backed_up_at, None).await
+}
+
+pub fn wallet_data_dir(base_path: &Path) -> PathBuf {
+    base_path
+        .join("wallet")
+        .join(Network::get_current().to_string().to_lowercase())
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<LessSafeKey, Error> {
+    let iterations =
+        NonZeroU32::new(PBKDF2_ITERATIONS).ok_or_else(|| anyhow!("Invalid iteration count"))?;
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let unbound_key = UnboundKey::new(&aead::CHACHA20_POLY1305, &key)
+        .map_err(|_| anyhow!("Could not create backup encryption key"))?;
+    Ok(LessSafeKey::new(unbound_key))
+}
+
+fn chunk_nonce(prefix: &[u8; NONCE_PREFIX_LENGTH], counter: u32, is_last: bool) -> Nonce {
+    let mut nonce = [0u8; NONCE_LENGTH];
+    nonce[..NONCE_PREFIX_LENGTH].copy_from_slice(prefix);
+    nonce[NONCE_PREFIX_LENGTH..NONCE_LENGTH - 1].copy_from_slice(&counter.to_be_bytes());
+    nonce[NONCE_LENGTH - 1] = u8::from(is_last);
+    Nonce::assume_unique_for_key(nonce)
+}
+
+/// Layout is magic, salt, nonce prefix, then the archive sealed in chunks. The last chunk is flagged in
+/// its nonce so a truncated backup fails to open instead of restoring a partial wallet
+struct EncryptingWriter<W: Write> {
+    inner: W,
+    key: LessSafeKey,
+    nonce_prefix: [u8; NONCE_PREFIX_LENGTH],
+    counter: u32,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> EncryptingWriter<W> {
+    fn new(mut inner: W, passphrase: &str) -> Result<Self, Error> {
+        let rng = SystemRandom::new();
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce_prefix = [0u8; NONCE_PREFIX_LENGTH];
+        rng.fill(&mut salt)
+            .and_then(|_| rng.fill(&mut nonce_prefix))
+            .map_err(|_| anyhow!("Could not generate backup encryption parameters"))?;
+        inner.write_all(ENCRYPTED_BACKUP_MAGIC)?;
+        inner.write_all(&salt)?;
+        inner.write_all(&nonce_prefix)?;
+        Ok(Self {
+            inner,
+            key: derive_key(passphrase, &salt)?,
+            nonce_prefix,
+            counter: 0,
+            buffer: Vec::with_capacity(CHUNK_LENGTH + TAG_LENGTH),
+        })
+    }
+
+    fn seal_chunk(&mut self, is_last: bool) -> io::Result<()> {
+        let nonce = chunk_nonce(&self.nonce_prefix, self.counter, is_last);
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .ok_or_else(|| io::Error::other("Wallet backup is too large"))?;
+        self.key
+            .seal_in_place_append_tag(nonce, Aad::empty(), &mut self.buffer)
+            .map_err(|_| io::Error::other("Could not encrypt wallet backup"))?;
+        self.inner.write_all(&self.buffer)?;
+        self.buffer.clear();
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<W> {
+        self.seal_chunk(true)?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for EncryptingWriter<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.buffer.len() == CHUNK_LENGTH {
+            self.seal_chunk(false)?;
+        }
+        let length = data.len().min(CHUNK_LENGTH - self.buffer.len());
+        self.buffer.extend_from_slice(&data[..length]);
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+struct DecryptingReader<R: BufRead> {
+    inner: R,
+    key: LessSafeKey,
+    nonce_prefix: [u8; NONCE_PREFIX_LENGTH],
+    counter: u32,
+    chunk: Vec<u8>,
+    position: usize,
+    is_finished: bool,
+}
+
+impl<R: BufRead> DecryptingReader<R> {
+    fn new(mut inner: R, passphrase: &str) -> Result<Self, Error> {
+        let mut magic = [0u8; ENCRYPTED_BACKUP_MAGIC.len()];
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce_prefix = [0u8; NONCE_PREFIX_LENGTH];
+        inner
+            .read_exact(&mut magic)
+            .and_then(|_| inner.read_exact(&mut salt))
+            .and_then(|_| inner.read_exact(&mut nonce_prefix))
+            .map_err(|_| anyhow!("Corrupted wallet backup header"))?;
+        if &magic != ENCRYPTED_BACKUP_MAGIC {
+            return Err(anyhow!("Not an encrypted wallet backup"));
+        }
+        Ok(Self {
+            inner,
+            key: derive_key(passphrase, &salt)?,
+            nonce_prefix,
+            counter: 0,
+            chunk: Vec::with_capacity(CHUNK_LENGTH + TAG_LENGTH),
+            position: 0,
+            is_finished: false,
+        })
+    }
+
+    fn open_next_chunk(&mut self) -> io::Result<()> {
+        self.chunk.clear();
+        self.position = 0;
+        (&mut self.inner)
+            .take((CHUNK_LENGTH + TAG_LENGTH) as u64)
+            .read_to_end(&mut self.chunk)?;
+        let is_last = self.inner.fill_buf()?.is_empty();
+        let nonce = chunk_nonce(&self.nonce_prefix, self.counter, is_last);
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Corrupted wallet backup"))?;
+        let length = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut self.chunk)
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Wrong passphrase or corrupted wallet backup",
+                )
+            })?
+            .len();
+        self.chunk.truncate(length);
+        self.is_finished = is_last;
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for DecryptingReader<R> {
+    fn read(&mut self, output: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.chunk.len() {
+            if self.is_finished {
+                return Ok(0);
+            }
+            self.open_next_chunk()?;
+        }
+        let length = output.len().min(self.chunk.len() - self.position);
+        output[..length].copy_from_slice(&self.chunk[self.position..self.position + length]);
+        self.position += length;
+        Ok(length)
+    }
+}
+
+/// Written into the archive so a restore can tell which wallet the database belongs to
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupManifest {
+    network: String,
+    wallet_address: String,
+    created_at: u64,
+}
+
+fn write_backup(
+    wallet_dir: &Path,
+    manifest: &[u8],
+    backup_file: &Path,
+    passphrase: Option<&str>,
+) -> Result<(), Error> {
+    let file = BufWriter::new(std::fs::File::create(backup_file)?);
+    let mut file = match passphrase {
+        Some(passphrase) => write_dir_as_gz(
+            wallet_dir,
+            Some(manifest),
+            EncryptingWriter::new(file, passphrase)?,
+        )?
+        .finish()?,
+        None => write_dir_as_gz(wallet_dir, Some(manifest), file)?,
+    };
+    file.flush()?;
+    Ok(())
+}
+
+/// The wallet process must be stopped first so its database is fully written to disk
+pub async fn create_backup(
+    wallet_dir: PathBuf,
+    wallet_address: String,
+    destination_dir: &Path,
+    passphrase: Option<String>,
+) -> Result<PathBuf, Error> {
+    if !wallet_dir.exists() {
+        return Err(anyhow!("Wallet database not found at {:?}", wallet_dir));
+    }
+    let created_at = unix_now();
+    let file_name = format!(
+        "tari-wallet-backup-{}-{}.tar.gz",
+        Network::get_current().as_key_str(),
+        created_at
+    );
+    let passphrase = passphrase.filter(|passphrase| !passphrase.is_empty());
+    let backup_file = match passphrase {
+        Some(_) => destination_dir.join(format!("{}.enc", file_name)),
+        None => destination_dir.join(file_name),
+    };
+    let manifest = serde_json::to_vec(&BackupManifest {
+        network: Network::get_current().as_key_str().to_string(),
+        wallet_address,
+        created_at,
+    })?;
+
+    let output = backup_file.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        write_backup(&wallet_dir, &manifest, &output, passphrase.as_deref())
+    })
+    .await?;
+    if let Err(e) = result {
+        let _unused = fs::remove_file(&backup_file).await;
+        return Err(e);
+    }
+    info!(target: LOG_TARGET, "Wallet database backed up to {:?}", backup_file);
+    Ok(backup_file)
+}
+
+fn unpack_backup(
+    backup_file: &Path,
+    destination_dir: &Path,
+    passphrase: Option<String>,
+) -> Result<BackupManifest, Error> {
+    let mut reader = BufReader::new(std::fs::File::open(backup_file)?);
+    let manifest = if reader.fill_buf()?.starts_with(ENCRYPTED_BACKUP_MAGIC) {
+        let passphrase = passphrase
+            .ok_or_else(|| anyhow!("This backup is encrypted, a passphrase is required"))?;
+        unpack_gz_with_manifest(DecryptingReader::new(reader, &passphrase)?, destination_dir)?
+    } else {
+        unpack_gz_with_manifest(reader, destination_dir)?
+    };
+    let manifest = manifest
+        .ok_or_else(|| anyhow!("This backup has no manifest so its wallet cannot be verified"))?;
+    Ok(serde_json::from_slice(&manifest)?)
+}
+
+/// Extracts next to the wallet directory first so a bad archive, or one from another wallet or network,
+/// leaves the current wallet intact
+pub async fn restore_backup(
+    backup_file: &Path,
+    wallet_dir: &Path,
+    wallet_address: &str,
+    passphrase: Option<String>,
+) -> Result<(), Error> {
+    let restore_dir = wallet_dir.with_extension("restoring");
+    if restore_dir.exists() {
+        fs::remove_dir_all(&restore_dir).await?;
+    }
+    let (source, unpack_dir) = (backup_file.to_path_buf(), restore_dir.clone());
+    let unpacked =
+        tokio::task::spawn_blocking(move || unpack_backup(&source, &unpack_dir, passphrase))
+            .await?
+            .and_then(|manifest| {
+                ensure_same_network(&manifest.network)?;
+                if manifest.wallet_address == wallet_address {
+                    Ok(())
+                } else {
+                    Err(anyhow!(
+                        "This backup belongs to a different wallet than the one on this device"
+                    ))
+                }
+            });
+    if let Err(e) = unpacked {
+        let _unused = fs::remove_dir_all(&restore_dir).await;
+        return Err(e);
+    }
+
+    swap_in_dir(&restore_dir, wallet_dir).await?;
+    info!(target: LOG_TARGET, "Wallet database restored from {:?}", backup_file);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypt(data: &[u8], passphrase: &str) -> Vec<u8> {
+        let mut writer = EncryptingWriter::new(Vec::new(), passphrase).expect("writer");
+        writer.write_all(data).expect("write");
+        writer.finish().expect("finish")
+    }
+
+    fn decrypt(data: &[u8], passphrase: &str) -> io::Result<Vec<u8>> {
+        let mut reader = DecryptingReader::new(data, passphrase).map_err(io::Error::other)?;
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output)?;
+        Ok(output)
+    }
+
+    #[test]
+    fn encrypted_backup_round_trip() {
+        let archive: Vec<u8> = (0..CHUNK_LENGTH * 2 + 17)
+            .map(|i| u8::try_from(i % 251).unwrap_or_default())
+            .collect();
+        let encrypted = encrypt(&archive, "correct horse");
+        assert!(encrypted.starts_with(ENCRYPTED_BACKUP_MAGIC));
+        assert_eq!(
+            decrypt(&encrypted, "correct horse").unwrap_or_default(),
+            archive
+        );
+    }
+
+    #[test]
+    fn wrong_passphrase_is_rejected() {
+        let encrypted = encrypt(b"wallet archive", "correct horse");
+        assert!(decrypt(&encrypted, "battery staple").is_err());
+    }
+
+    #[test]
+    fn truncated_backup_is_rejected() {
+        let archive = vec![7u8; CHUNK_LENGTH * 2];
+        let encrypted = encrypt(&archive, "correct horse");
+        let truncated = &encrypted[..encrypted.len() - (CHUNK_LENGTH + TAG_LENGTH)];
+        assert!(decrypt(truncated, "correct horse").is_err());
+    }
+}
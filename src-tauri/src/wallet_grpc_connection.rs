@@ -0,0 +1,226 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    future::Future,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::{debug, warn};
+use minotari_node_grpc_client::grpc::wallet_client::WalletClient;
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tonic::{transport::Channel, Code, Response, Status};
+
+use crate::wallet_adapter::WalletStatusMonitorError;
+
+const LOG_TARGET: &str = "tari::universe::wallet_grpc_connection";
+/// Delays between attempts; the wallet usually comes back within a couple of seconds after a restart
+const RETRY_BACKOFF: [Duration; 3] = [
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+];
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WalletConnectionHealth {
+    pub is_healthy: bool,
+    pub consecutive_failures: u32,
+    pub total_retries: u64,
+    pub last_success_timestamp: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+impl WalletConnectionHealth {
+    fn record_success(&mut self) {
+        self.is_healthy = true;
+        self.consecutive_failures = 0;
+        self.last_success_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs());
+    }
+
+    fn record_failure(&mut self, error: String) {
+        self.is_healthy = false;
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.last_error = Some(error);
+    }
+}
+
+/// Status codes the wallet returns while it is restarting or still warming up.
+fn is_retriable(status: &Status) -> bool {
+    matches!(status.code(), Code::Unavailable | Code::DeadlineExceeded)
+}
+
+enum CallError {
+    /// Could not open a connection, the wallet process is most likely not running
+    Connect(String),
+    Transient(String),
+}
+
+/// Shared gRPC channel to the wallet process. The client is reused between calls and
+/// dropped whenever the transport fails, so the next attempt reconnects.
+pub struct WalletGrpcConnection {
+    address: String,
+    client: Mutex<Option<WalletClient<Channel>>>,
+    health: Mutex<WalletConnectionHealth>,
+}
+
+impl WalletGrpcConnection {
+    pub fn new(grpc_port: u16) -> Self {
+        Self {
+            address: format!("http://127.0.0.1:{grpc_port}"),
+            client: Mutex::new(None),
+            health: Mutex::new(WalletConnectionHealth::default()),
+        }
+    }
+
+    pub async fn health(&self) -> WalletConnectionHealth {
+        self.health.lock().await.clone()
+    }
+
+    async fn client(&self) -> Result<WalletClient<Channel>, tonic::transport::Error> {
+        let mut cached = self.client.lock().await;
+        if let Some(client) = cached.as_ref() {
+            return Ok(client.clone());
+        }
+        let client = WalletClient::connect(self.address.clone()).await?;
+        *cached = Some(client.clone());
+        Ok(client)
+    }
+
+    async fn invalidate(&self) {
+        self.client.lock().await.take();
+    }
+
+    /// Runs an idempotent read against the wallet, retrying transient failures with backoff.
+    /// When every attempt fails on a transient error, `TemporarilyUnavailable` is returned
+    /// so callers can tell a restarting wallet apart from a real failure.
+    pub async fn call<T, F, Fut>(
+        &self,
+        operation: &str,
+        request: F,
+    ) -> Result<T, WalletStatusMonitorError>
+    where
+        F: Fn(WalletClient<Channel>) -> Fut,
+        Fut: Future<Output = Result<Response<T>, Status>>,
+    {
+        self.call_with_backoff(operation, request, &RETRY_BACKOFF)
+            .await
+    }
+
+    /// Runs `request` exactly once. Used for calls that must not be repeated, like
+    /// importing transactions, and for health checks that have their own schedule.
+    pub async fn call_once<T, F, Fut>(
+        &self,
+        operation: &str,
+        request: F,
+    ) -> Result<T, WalletStatusMonitorError>
+    where
+        F: Fn(WalletClient<Channel>) -> Fut,
+        Fut: Future<Output = Result<Response<T>, Status>>,
+    {
+        self.call_with_backoff(operation, request, &[]).await
+    }
+
+    async fn call_with_backoff<T, F, Fut>(
+        &self,
+        operation: &str,
+        request: F,
+        backoff: &[Duration],
+    ) -> Result<T, WalletStatusMonitorError>
+    where
+        F: Fn(WalletClient<Channel>) -> Fut,
+        Fut: Future<Output = Result<Response<T>, Status>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let error = match self.client().await {
+                Ok(client) => match request(client).await {
+                    Ok(response) => {
+                        self.health.lock().await.record_success();
+                        return Ok(response.into_inner());
+                    }
+                    Err(status) if is_retriable(&status) => {
+                        self.invalidate().await;
+                        CallError::Transient(status.to_string())
+                    }
+                    Err(status) => {
+                        self.health.lock().await.record_failure(status.to_string());
+                        return Err(WalletStatusMonitorError::UnknownError(status.into()));
+                    }
+                },
+                Err(e) => CallError::Connect(e.to_string()),
+            };
+
+            let Some(delay) = backoff.get(attempt) else {
+                let (message, result) = match error {
+                    CallError::Connect(message) => {
+                        (message, WalletStatusMonitorError::WalletNotStarted)
+                    }
+                    CallError::Transient(message) => (
+                        message,
+                        WalletStatusMonitorError::TemporarilyUnavailable(operation.to_string()),
+                    ),
+                };
+                warn!(target: LOG_TARGET, "Wallet {operation} failed after {attempt} retries: {message}");
+                self.health.lock().await.record_failure(message);
+                return Err(result);
+            };
+            let (CallError::Connect(error) | CallError::Transient(error)) = error;
+            debug!(target: LOG_TARGET, "Wallet {operation} failed, retrying in {delay:?}: {error}");
+            self.health.lock().await.total_retries += 1;
+            attempt += 1;
+            tokio::time::sleep(*delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_transient_codes_are_retried() {
+        assert!(is_retriable(&Status::unavailable("restarting")));
+        assert!(is_retriable(&Status::deadline_exceeded("slow")));
+        assert!(!is_retriable(&Status::invalid_argument("bad request")));
+        assert!(!is_retriable(&Status::not_found("missing")));
+        assert!(!is_retriable(&Status::unknown("failed")));
+        assert!(!is_retriable(&Status::aborted("conflict")));
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let mut health = WalletConnectionHealth::default();
+        health.record_failure("unavailable".to_string());
+        health.record_failure("unavailable".to_string());
+        assert_eq!(health.consecutive_failures, 2);
+        assert!(!health.is_healthy);
+
+        health.record_success();
+        assert_eq!(health.consecutive_failures, 0);
+        assert!(health.is_healthy);
+        assert_eq!(health.last_error.as_deref(), Some("unavailable"));
+    }
+}
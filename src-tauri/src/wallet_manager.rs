@@ -29,6 +29,7 @@ use crate::tasks_tracker::TasksTrackers;
 use crate::wallet_adapter::WalletStatusMonitorError;
 use crate::wallet_adapter::{TransactionInfo, WalletBalance};
 use crate::wallet_adapter::{WalletAdapter, WalletState};
+use crate::wallet_grpc_connection::WalletConnectionHealth;
 use crate::{BaseNodeStatus, UniverseAppState};
 use futures_util::future::FusedFuture;
 use std::path::{Path, PathBuf};
@@ -36,6 +37,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::time::Duration;
 use tari_common::configuration::Network;
+use tari_core::transactions::tari_amount::MicroMinotari;
 use tari_shutdown::ShutdownSignal;
 use tokio::fs;
 use tokio::sync::watch;
@@ -56,6 +58,8 @@ pub struct WalletStartupConfig {
 pub enum WalletManagerError {
     #[error("Wallet not started")]
     WalletNotStarted,
+    #[error("Wallet temporarily unavailable during {0}, retry shortly")]
+    TemporarilyUnavailable(String),
     #[error("Node manager error: {0}")]
     NodeManagerError(#[from] NodeManagerError),
     #[error("Unknown error: {0}")]
@@ -193,6 +197,16 @@ impl WalletManager {
         process_watcher.adapter.get_balance().await
     }
 
+    pub async fn get_unspent_amounts(&self) -> Result<Vec<MicroMinotari>, anyhow::Error> {
+        let process_watcher = self.watcher.read().await;
+        process_watcher.adapter.get_unspent_amounts().await
+    }
+
+    pub async fn get_connection_health(&self) -> WalletConnectionHealth {
+        let process_watcher = self.watcher.read().await;
+        process_watcher.adapter.connection_health().await
+    }
+
     pub async fn get_transactions_history(
         &self,
         offset: Option<i32>,
@@ -207,20 +221,33 @@ impl WalletManager {
             .await
             .map_err(|e| match e {
                 WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
+                WalletStatusMonitorError::TemporarilyUnavailable(operation) => {
+                    WalletManagerError::TemporarilyUnavailable(operation)
+                }
                 _ => WalletManagerError::UnknownError(e.into()),
             })
     }
 
-    pub async fn import_transaction(&self, tx_output_file: PathBuf) -> Result<(), anyhow::Error> {
+    pub async fn import_transaction(
+        &self,
+        tx_output_file: PathBuf,
+    ) -> Result<(), WalletManagerError> {
         let process_watcher = self.watcher.read().await;
         if !process_watcher.is_running() {
-            return Err(anyhow::Error::msg("Wallet not started"));
+            return Err(WalletManagerError::WalletNotStarted);
         }
 
         process_watcher
             .adapter
             .import_transaction(tx_output_file)
             .await
+            .map_err(|e| match e {
+                WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
+                WalletStatusMonitorError::TemporarilyUnavailable(operation) => {
+                    WalletManagerError::TemporarilyUnavailable(operation)
+                }
+                _ => WalletManagerError::UnknownError(e.into()),
+            })
     }
 
     pub async fn get_coinbase_transactions(
@@ -237,6 +264,9 @@ impl WalletManager {
             .await
             .map_err(|e| match e {
                 WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
+                WalletStatusMonitorError::TemporarilyUnavailable(operation) => {
+                    WalletManagerError::TemporarilyUnavailable(operation)
+                }
                 _ => WalletManagerError::UnknownError(e.into()),
             })
     }
@@ -258,6 +288,9 @@ impl WalletManager {
             .await
             .map_err(|e| match e {
                 WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
+                WalletStatusMonitorError::TemporarilyUnavailable(operation) => {
+                    WalletManagerError::TemporarilyUnavailable(operation)
+                }
                 _ => WalletManagerError::UnknownError(e.into()),
             })
     }
@@ -277,6 +310,9 @@ impl WalletManager {
             .await
             .map_err(|e| match e {
                 WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
+                WalletStatusMonitorError::TemporarilyUnavailable(operation) => {
+                    WalletManagerError::TemporarilyUnavailable(operation)
+                }
                 _ => WalletManagerError::UnknownError(e.into()),
             })
     }
@@ -21,7 +21,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use futures::lock::Mutex;
 use log::{error, info};
@@ -158,6 +158,7 @@ impl WebsocketEventsManager {
         app_version: String,
         jwt_token: String,
     ) -> Option<WebsocketMessage> {
+        let created_at = Instant::now();
         let BaseNodeStatus { block_height, .. } = *node_latest_status.borrow();
 
         let cpu_miner_status = cpu_miner_status_watch_rx.borrow().clone();
@@ -187,6 +188,7 @@ impl WebsocketEventsManager {
                     data: Some(payload),
                     signature: Some(signature),
                     pub_key: Some(pub_key),
+                    created_at,
                 });
             }
         }
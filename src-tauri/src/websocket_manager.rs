@@ -21,7 +21,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 use futures::SinkExt;
@@ -48,10 +48,12 @@ use tungstenite::Message;
 use tungstenite::Utf8Bytes;
 use urlencoding::encode;
 
+use crate::airdrop::AirdropActivity;
 use crate::app_in_memory_config::DynamicMemoryConfig;
 use crate::configs::config_core::ConfigCore;
 use crate::configs::trait_config::ConfigImpl;
 use crate::tasks_tracker::TasksTrackers;
+use crate::websocket_stats::WebsocketStats;
 
 const LOG_TARGET: &str = "tari::universe::websocket";
 
@@ -84,6 +86,9 @@ pub struct WebsocketMessage {
     pub signature: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pub_key: Option<String>,
+    /// When the state change behind this message happened, used for delivery latency stats
+    #[serde(skip, default = "Instant::now")]
+    pub created_at: Instant,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -339,6 +344,9 @@ async fn sender_task(
                     .inspect_err(|e| {
                         error!(target:LOG_TARGET,"Failed to send websocket message: {}", e);
                     })?;
+                WebsocketStats::current()
+                    .record_delivery(&msg.event, msg.created_at.elapsed())
+                    .await;
                  // info!(target:LOG_TARGET,"websocket event sent to airdrop {:?}", message_as_json);
             },
             _=wait_for_close_signal(close_channel_tx.clone().subscribe())=>{
@@ -384,6 +392,7 @@ async fn receiver_task(
                                         }).ok();
 
                                 if let Some(message) = messsage_value {
+                                    AirdropActivity::handle_websocket_message(&message).await;
                                     drop(app.emit("ws-rx", message).inspect_err(|e|{
                                                 error!(target:LOG_TARGET,"Received text websocket message cannot be sent to frontend: {}", e);
                                             }));
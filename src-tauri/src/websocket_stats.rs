@@ -0,0 +1,172 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::LazyLock,
+    time::Duration,
+};
+
+use log::warn;
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+use crate::events_emitter::EventsEmitter;
+
+const LOG_TARGET: &str = "tari::universe::websocket_stats";
+/// Number of most recent deliveries kept per event type
+const MAX_SAMPLES_PER_EVENT: usize = 500;
+pub const MAX_ACCEPTABLE_DELIVERY_LAG: Duration = Duration::from_secs(5);
+
+static INSTANCE: LazyLock<WebsocketStats> = LazyLock::new(WebsocketStats::new);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventLatencyStats {
+    pub delivered: u64,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub max_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebsocketStatsSnapshot {
+    pub messages_sent: u64,
+    pub lagging_deliveries: u64,
+    pub max_acceptable_delivery_lag_ms: u64,
+    pub events: HashMap<String, EventLatencyStats>,
+}
+
+#[derive(Default)]
+struct EventLatencySamples {
+    delivered: u64,
+    samples: VecDeque<Duration>,
+}
+
+#[derive(Default)]
+struct WebsocketStatsInner {
+    messages_sent: u64,
+    lagging_deliveries: u64,
+    events: HashMap<String, EventLatencySamples>,
+}
+
+/// Tracks how long websocket messages wait between being created from a state change and
+/// being written to the socket.
+pub struct WebsocketStats {
+    inner: RwLock<WebsocketStatsInner>,
+}
+
+/// Nearest-rank percentile of already sorted samples
+pub fn percentile(sorted_samples: &[Duration], percentile: f64) -> Duration {
+    if sorted_samples.is_empty() {
+        return Duration::ZERO;
+    }
+    let len = sorted_samples.len();
+    let target = percentile / 100.0 * len as f64;
+    let rank = (1..=len).find(|rank| *rank as f64 >= target).unwrap_or(len);
+    sorted_samples[rank - 1]
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+impl WebsocketStats {
+    fn new() -> Self {
+        Self {
+            inner: RwLock::new(WebsocketStatsInner::default()),
+        }
+    }
+
+    pub fn current() -> &'static WebsocketStats {
+        &INSTANCE
+    }
+
+    pub async fn record_delivery(&self, event: &str, latency: Duration) {
+        let is_lagging = latency > MAX_ACCEPTABLE_DELIVERY_LAG;
+        {
+            let mut inner = self.inner.write().await;
+            inner.messages_sent += 1;
+            if is_lagging {
+                inner.lagging_deliveries += 1;
+            }
+            let event_samples = inner.events.entry(event.to_string()).or_default();
+            event_samples.delivered += 1;
+            if event_samples.samples.len() >= MAX_SAMPLES_PER_EVENT {
+                event_samples.samples.pop_front();
+            }
+            event_samples.samples.push_back(latency);
+        }
+
+        if is_lagging {
+            warn!(target: LOG_TARGET, "Websocket delivery of {} lagged by {:?}", event, latency);
+            EventsEmitter::emit_websocket_delivery_lag(event.to_string(), millis(latency)).await;
+        }
+    }
+
+    pub async fn snapshot(&self) -> WebsocketStatsSnapshot {
+        let inner = self.inner.read().await;
+        let events = inner
+            .events
+            .iter()
+            .map(|(event, event_samples)| {
+                let mut sorted: Vec<Duration> = event_samples.samples.iter().copied().collect();
+                sorted.sort();
+                (
+                    event.clone(),
+                    EventLatencyStats {
+                        delivered: event_samples.delivered,
+                        p50_ms: millis(percentile(&sorted, 50.0)),
+                        p95_ms: millis(percentile(&sorted, 95.0)),
+                        max_ms: millis(sorted.last().copied().unwrap_or_default()),
+                    },
+                )
+            })
+            .collect();
+
+        WebsocketStatsSnapshot {
+            messages_sent: inner.messages_sent,
+            lagging_deliveries: inner.lagging_deliveries,
+            max_acceptable_delivery_lag_ms: millis(MAX_ACCEPTABLE_DELIVERY_LAG),
+            events,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&samples, 50.0), Duration::from_millis(50));
+        assert_eq!(percentile(&samples, 95.0), Duration::from_millis(95));
+        assert_eq!(percentile(&samples, 100.0), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_percentile_small_sample() {
+        let samples = vec![Duration::from_millis(7)];
+        assert_eq!(percentile(&samples, 50.0), Duration::from_millis(7));
+        assert_eq!(percentile(&[], 95.0), Duration::ZERO);
+    }
+}
@@ -44,6 +44,33 @@ impl XmrigHttpApiClient {
             .await
     }
 
+    /// Needs xmrig to run with `--http-no-restricted`
+    async fn json_rpc(&self, method: &str) -> Result<(), anyhow::Error> {
+        let url = format!("{}/json_rpc", self.url);
+        let response = reqwest::Client::new()
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", self.access_token))
+            .json(&serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": method }))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "xmrig {} request failed with status: {}",
+                method,
+                response.status()
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn pause(&self) -> Result<(), anyhow::Error> {
+        self.json_rpc("pause").await
+    }
+
+    pub async fn resume(&self) -> Result<(), anyhow::Error> {
+        self.json_rpc("resume").await
+    }
+
     pub async fn summary(&self) -> Result<models::Summary, anyhow::Error> {
         for _i in 0..3 {
             let response = self.get("2/summary").await?;
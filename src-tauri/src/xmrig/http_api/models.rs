@@ -25,6 +25,8 @@ use serde::Deserialize;
 #[derive(Deserialize, Debug, Clone)]
 pub(crate) struct Summary {
     pub(crate) connection: Connection,
+    #[serde(default)]
+    pub(crate) paused: bool,
 
     pub(crate) hashrate: Hashrate,
     // hugepages: bool,
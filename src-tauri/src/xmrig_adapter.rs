@@ -182,6 +182,8 @@ impl ProcessAdapter for XmrigAdapter {
 
         args.push(format!("--http-port={}", self.http_api_port));
         args.push(format!("--http-access-token={}", self.http_api_token));
+        // Lets pause and resume go through the api, it is still guarded by the access token
+        args.push("--http-no-restricted".to_string());
         args.push("--donate-level=1".to_string());
 
         // don't specify threads for ludicrous mode
@@ -259,4 +261,12 @@ impl XmrigStatusMonitor {
     pub async fn summary(&self) -> Result<xmrig::http_api::models::Summary, Error> {
         self.client.summary().await
     }
+
+    pub async fn pause(&self) -> Result<(), Error> {
+        self.client.pause().await
+    }
+
+    pub async fn resume(&self) -> Result<(), Error> {
+        self.client.resume().await
+    }
 }
@@ -2,6 +2,10 @@ import { useCallback, useEffect, useRef } from 'react';
 import { useTappletSignerStore } from '@app/store/useTappletSignerStore';
 import { MiningViewContainer } from '@app/containers/main/Dashboard/MiningView/MiningView.styles';
 import { open } from '@tauri-apps/plugin-shell';
+import { invoke } from '@tauri-apps/api/core';
+
+// A tapplet page that hasn't loaded by then is reported so it can be reloaded
+const TAPPLET_LOAD_TIMEOUT_MS = 15_000;
 
 interface TappletProps {
     source: string;
@@ -9,6 +13,7 @@ interface TappletProps {
 
 export const Tapplet: React.FC<TappletProps> = ({ source }) => {
     const tappletRef = useRef<HTMLIFrameElement | null>(null);
+    const loadTimeoutRef = useRef<ReturnType<typeof setTimeout> | null>(null);
     const provider = useTappletSignerStore((s) => s.tappletSigner);
     const runTransaction = useTappletSignerStore((s) => s.runTransaction);
 
@@ -58,6 +63,36 @@ export const Tapplet: React.FC<TappletProps> = ({ source }) => {
         [sendWindowSize, runTappletTx, openExternalLink]
     );
 
+    const reportLoadFailure = useCallback(
+        (error: string) => {
+            invoke('report_tapplet_load_failure', { source, error }).catch((e) =>
+                console.error('Could not report tapplet load failure: ', e)
+            );
+        },
+        [source]
+    );
+
+    const handleLoad = useCallback(() => {
+        if (loadTimeoutRef.current) {
+            clearTimeout(loadTimeoutRef.current);
+            loadTimeoutRef.current = null;
+        }
+        sendWindowSize();
+    }, [sendWindowSize]);
+
+    useEffect(() => {
+        loadTimeoutRef.current = setTimeout(() => {
+            loadTimeoutRef.current = null;
+            reportLoadFailure(`Page did not load within ${TAPPLET_LOAD_TIMEOUT_MS / 1000}s`);
+        }, TAPPLET_LOAD_TIMEOUT_MS);
+        return () => {
+            if (loadTimeoutRef.current) {
+                clearTimeout(loadTimeoutRef.current);
+                loadTimeoutRef.current = null;
+            }
+        };
+    }, [source, reportLoadFailure]);
+
     useEffect(() => {
         window.addEventListener('resize', sendWindowSize);
         window.addEventListener('message', handleMessage);
@@ -77,7 +112,8 @@ export const Tapplet: React.FC<TappletProps> = ({ source }) => {
                 width="100%"
                 height="100%"
                 ref={tappletRef}
-                onLoad={sendWindowSize}
+                onLoad={handleLoad}
+                onError={() => reportLoadFailure('Page failed to load')}
                 style={{ border: 'none', pointerEvents: 'all' }}
             />
         </MiningViewContainer>
@@ -8,6 +8,7 @@ import { SendReview } from './SendReview/SendReview.tsx';
 import { StyledForm, Wrapper } from './Send.styles.ts';
 import { invoke } from '@tauri-apps/api/core';
 import { setError as setStoreError } from '@app/store';
+import { sendErrorMessage } from '@app/types/transactions.ts';
 
 interface SendModalProps {
     section: string;
@@ -74,9 +75,10 @@ export default function SendModal({ section, setSection }: SendModalProps) {
                 });
                 setStatus('completed');
             } catch (error) {
-                setStoreError(`Error sending transaction: ${error}`);
+                const message = sendErrorMessage(error);
+                setStoreError(`Error sending transaction: ${message}`);
                 setError(`root.invoke_error`, {
-                    message: `${t('send.error-message')} ${error}`,
+                    message: `${t('send.error-message')} ${message}`,
                 });
                 setStatus('fields');
             }
@@ -22,10 +22,14 @@ export const fetchTransactionsHistory = async ({ offset = 0, limit }: TxArgs) =>
     try {
         useWalletStore.setState({ is_transactions_history_loading: true });
         const currentTxs = useWalletStore.getState().transactions;
-        const fetchedTxs = await invoke('get_transactions_history', { offset, limit });
+        const page = await invoke('get_transactions_history', {
+            cursor: offset > 0 ? offset.toString() : undefined,
+            limit,
+        });
+        const fetchedTxs = page.items;
 
         const transactions = offset > 0 ? [...currentTxs, ...fetchedTxs] : fetchedTxs;
-        const has_more_transactions = fetchedTxs.length > 0 && (!limit || fetchedTxs.length === limit);
+        const has_more_transactions = !!page.next_cursor;
         useWalletStore.setState({
             has_more_transactions,
             transactions,
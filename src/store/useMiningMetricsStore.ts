@@ -29,6 +29,7 @@ const initialState: MiningMetricsStoreState = {
     },
     cpu_mining_status: {
         is_mining: false,
+        is_paused: false,
         hash_rate: 0,
         estimated_earnings: 0,
         connection: { is_connected: false },
@@ -39,9 +39,7 @@ export const fetchP2poolStats = async () => {
 export const fetchP2poolConnections = async () => {
     try {
         const connections = await invoke('get_p2pool_connections');
-        if (connections) {
-            useP2poolStatsStore.setState({ peers: connections?.peers });
-        }
+        useP2poolStatsStore.setState({ peers: connections.items });
     } catch (e) {
         console.error('Could not get p2p connections: ', e);
     }
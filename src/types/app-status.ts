@@ -85,6 +85,14 @@ export interface TransactionInfo {
     payment_id: string;
     mined_in_block_height?: number;
     payment_reference?: string;
+    label?: string;
+    counterparty_label?: string;
+}
+
+export interface Page<T> {
+    items: T[];
+    next_cursor?: string;
+    total_estimate?: number;
 }
 
 export interface P2poolStatsResult {
@@ -176,6 +184,7 @@ export interface GpuDevice {
 
 export interface CpuMinerStatus {
     is_mining: boolean;
+    is_paused: boolean;
     hash_rate: number;
     estimated_earnings: number;
     connection: CpuMinerConnectionStatus;
@@ -215,6 +224,204 @@ export interface WalletBalance {
     pending_outgoing_balance: number;
 }
 
+export type InitializationState = 'not_started' | 'initializing' | 'ready' | 'failed';
+
+export interface WalletBalanceResponse extends WalletBalance {
+    initialization_state: InitializationState;
+}
+
+export type SetupPhaseName = 'Core' | 'Wallet' | 'Hardware' | 'Node' | 'Mining';
+
+export interface SetupPhaseProgress {
+    phase: SetupPhaseName;
+    status: string;
+    initialization_state: InitializationState;
+    depends_on: SetupPhaseName[];
+}
+
+export interface SetupProgress {
+    phases: SetupPhaseProgress[];
+    is_app_unlocked: boolean;
+    is_wallet_unlocked: boolean;
+    is_cpu_mining_unlocked: boolean;
+    is_gpu_mining_unlocked: boolean;
+    is_initial_setup_finished: boolean;
+}
+
+export interface DailyEarnings {
+    date: string;
+    amount: number;
+    blocks: number;
+}
+
+export interface ChainSnapshotInfo {
+    snapshot_file: string;
+    checksum: string;
+    block_height: number;
+}
+
+export interface ThreadSample {
+    threads: number;
+    average_hashrate: number;
+    samples: number;
+}
+
+export interface TuningRecommendations {
+    max_threads: number;
+    learned: {
+        eco_threads?: number;
+        ludicrous_threads?: number;
+    };
+    samples: ThreadSample[];
+}
+
+export type JobState = 'running' | 'completed' | 'failed' | 'cancelled';
+
+export interface JobStatus {
+    id: string;
+    kind: string;
+    state: JobState;
+    is_cancellable: boolean;
+    created_at: number;
+    finished_at?: number;
+    result?: unknown;
+    error?: string;
+}
+
+export interface EmergencyStopStatus {
+    is_active: boolean;
+    triggered_at?: number;
+    reason?: string;
+}
+
+export type AccessRole = 'owner' | 'guest';
+
+export interface AccessStatus {
+    role: AccessRole;
+    has_admin_pin: boolean;
+}
+
+export interface SpendAllowanceStatus {
+    limit: number;
+    spent: number;
+    remaining: number;
+    expires_at: number;
+}
+
+export interface PendingOutbound {
+    id: string;
+    amount: number;
+    destination: string;
+    payment_id?: string;
+    queued_at: number;
+    broadcast_at: number;
+}
+
+export interface PendingOutboundFailure {
+    id: string;
+    amount: number;
+    destination: string;
+    error: string;
+}
+
+export interface ConfigMigrationReport {
+    config: string;
+    from_version: number;
+    to_version: number;
+    applied: string[];
+    backup_path?: string;
+}
+
+export interface PendingConfigMigrationReport {
+    config: string;
+    stored_version: number;
+    latest_version: number;
+    pending: string[];
+    is_from_newer_app: boolean;
+}
+
+export interface GpuEngineSwitch {
+    engine: string;
+    available_engines: string[];
+    hash_rate?: number;
+}
+
+export interface RuntimePaths {
+    network: string;
+    data_dir?: string;
+    node_data_dir?: string;
+    wallet_data_dir?: string;
+    config_dir?: string;
+    log_dir?: string;
+    ports: {
+        node_grpc_address?: string;
+        p2pool_grpc_address?: string;
+        p2pool_stats_port?: number;
+        mmproxy_monero_port?: number;
+    };
+}
+
+export interface P2poolModeStatus {
+    is_enabled: boolean;
+    is_running: boolean;
+    squad_override?: string;
+    stats?: P2poolStatsResult;
+}
+
+export interface InstalledGpuDriver {
+    vendor: string;
+    description: string;
+    version: string;
+}
+
+export interface GpuDriverIssue {
+    driver: InstalledGpuDriver;
+    reason: string;
+}
+
+export interface GpuDriverCompatibility {
+    engine: string;
+    drivers: InstalledGpuDriver[];
+    issues: GpuDriverIssue[];
+    is_gpu_mining_blocked: boolean;
+}
+
+export interface TorStatus {
+    bootstrap_phase: number;
+    is_bootstrapped: boolean;
+    network_liveness: boolean;
+    circuit_ok: boolean;
+}
+
+export interface TorConnectionStatus {
+    use_tor: boolean;
+    is_running: boolean;
+    status: TorStatus;
+}
+
+export interface PowerAssertionStatus {
+    is_supported: boolean;
+    prevent_sleep_while_mining: boolean;
+    is_preventing_sleep: boolean;
+    is_app_nap_disabled: boolean;
+}
+
+export interface WalletBackupStatus {
+    is_seed_backed_up: boolean;
+    seed_words_revealed_at?: number;
+    database_backed_up_at?: number;
+    last_backup_at?: number;
+    has_admin_pin: boolean;
+}
+
+export interface WalletConnectionHealth {
+    is_healthy: boolean;
+    consecutive_failures: number;
+    total_retries: number;
+    last_success_timestamp?: number;
+    last_error?: string;
+}
+
 export interface ApplicationsVersions {
     tari_universe: string;
     xmrig: string;
@@ -6,10 +6,31 @@ import {
     TransactionInfo,
     MaxConsumptionLevels,
     GpuThreads,
-    P2poolConnections,
-    WalletBalance,
+    ConnectedPeerInfo,
     BridgeEnvs,
     TariAddressVariants,
+    Page,
+    SetupProgress,
+    ChainSnapshotInfo,
+    DailyEarnings,
+    GpuDevice,
+    TuningRecommendations,
+    WalletConnectionHealth,
+    EmergencyStopStatus,
+    JobStatus,
+    AccessStatus,
+    SpendAllowanceStatus,
+    PendingOutbound,
+    ConfigMigrationReport,
+    PendingConfigMigrationReport,
+    GpuEngineSwitch,
+    RuntimePaths,
+    P2poolModeStatus,
+    GpuDriverCompatibility,
+    TorConnectionStatus,
+    PowerAssertionStatus,
+    WalletBackupStatus,
+    WalletBalanceResponse,
 } from './app-status';
 import { Language } from '@app/i18initializer';
 import { PaperWalletDetails } from '@app/types/app-status.ts';
@@ -17,12 +38,18 @@ import { displayMode, modeType } from '@app/store/types.ts';
 import { SignData } from '@app/types/ws.ts';
 import { ConfigBackendInMemory } from '@app/types/configs.ts';
 import { ExchangeMiner } from './exchange';
-import { ActiveTapplet } from '@app/types/tapplets/tapplet.types';
+import { ActiveTapplet, TappletStatus, TappletStorageUsage } from '@app/types/tapplets/tapplet.types';
 
 declare module '@tauri-apps/api/core' {
     function invoke(
         param: 'send_one_sided_to_stealth_address',
-        payload: { amount: string; destination: string; paymentId?: string }
+        payload: {
+            amount: string;
+            destination: string;
+            paymentId?: string;
+            sweep?: boolean;
+            idempotencyKey?: string;
+        }
     ): Promise<void>;
     function invoke(
         param: 'set_should_always_use_system_language',
@@ -41,9 +68,38 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'set_mine_on_app_start', payload: { mineOnAppStart: boolean }): Promise<void>;
     function invoke(param: 'open_log_dir'): Promise<void>;
     function invoke(param: 'start_cpu_mining'): Promise<void>;
-    function invoke(param: 'start_gpu_mining'): Promise<void>;
+    function invoke(param: 'start_gpu_mining', payload?: { deviceIndices?: number[] }): Promise<void>;
     function invoke(param: 'stop_cpu_mining'): Promise<void>;
     function invoke(param: 'stop_gpu_mining'): Promise<void>;
+    function invoke(param: 'pause_mining'): Promise<void>;
+    function invoke(param: 'resume_mining'): Promise<void>;
+    function invoke(param: 'get_tuning_recommendations'): Promise<TuningRecommendations>;
+    function invoke(param: 'get_wallet_connection_health'): Promise<WalletConnectionHealth>;
+    function invoke(param: 'emergency_stop', payload?: { reason?: string }): Promise<EmergencyStopStatus>;
+    function invoke(param: 'resume_operations', payload: { confirm: boolean }): Promise<EmergencyStopStatus>;
+    function invoke(param: 'get_emergency_stop_status'): Promise<EmergencyStopStatus>;
+    function invoke(param: 'start_chain_snapshot_export_job', payload: { destinationDir: string }): Promise<string>;
+    function invoke(param: 'start_orphan_chain_check_job'): Promise<string>;
+    function invoke(param: 'get_job_status', payload: { jobId: string }): Promise<JobStatus>;
+    function invoke(param: 'list_jobs', payload?: { cursor?: string; limit?: number }): Promise<Page<JobStatus>>;
+    function invoke(param: 'cancel_job', payload: { jobId: string }): Promise<void>;
+    function invoke(param: 'get_access_role'): Promise<AccessStatus>;
+    function invoke(param: 'set_admin_pin', payload: { newPin?: string }): Promise<AccessStatus>;
+    function invoke(param: 'unlock_admin', payload: { pin: string }): Promise<AccessStatus>;
+    function invoke(param: 'lock_admin'): Promise<AccessStatus>;
+    function invoke(param: 'grant_spend_allowance', payload: { amount: string; hours: number }): Promise<SpendAllowanceStatus>;
+    function invoke(param: 'revoke_spend_allowance'): Promise<void>;
+    function invoke(param: 'get_spend_allowance'): Promise<SpendAllowanceStatus | null>;
+    function invoke(
+        param: 'get_pending_outbound',
+        payload?: { cursor?: string; limit?: number }
+    ): Promise<Page<PendingOutbound>>;
+    function invoke(param: 'cancel_pending_outbound', payload: { id: string }): Promise<void>;
+    function invoke(param: 'set_outbound_review_minutes', payload: { minutes: number }): Promise<void>;
+    function invoke(param: 'get_config_migrations'): Promise<ConfigMigrationReport[]>;
+    function invoke(param: 'get_pending_config_migrations'): Promise<PendingConfigMigrationReport[]>;
+    function invoke(param: 'set_gpu_engine', payload: { engine: string }): Promise<GpuEngineSwitch>;
+    function invoke(param: 'get_runtime_paths'): Promise<RuntimePaths>;
     function invoke(param: 'set_allow_telemetry', payload: { allow_telemetry: boolean }): Promise<void>;
     function invoke(param: 'send_data_telemetry_service', payload: { eventName: string; data: object }): Promise<void>;
     function invoke(param: 'set_user_inactivity_timeout', payload: { timeout: number }): Promise<void>;
@@ -60,9 +116,13 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'set_monero_address', payload: { moneroAddress: string }): Promise<void>;
     function invoke(param: 'send_feedback', payload: { feedback: string; includeLogs: boolean }): Promise<string>;
     function invoke(param: 'reset_settings', payload: { resetWallet: boolean }): Promise<string>;
-    function invoke(param: 'set_p2pool_enabled', payload: { p2pool_enabled: boolean }): Promise<void>;
+    function invoke(param: 'set_p2pool_enabled', payload: { p2pool_enabled: boolean }): Promise<P2poolModeStatus>;
+    function invoke(param: 'set_p2pool_squad', payload: { squad?: string }): Promise<P2poolModeStatus>;
     function invoke(param: 'get_p2pool_stats'): Promise<P2poolStatsResult>;
-    function invoke(param: 'get_p2pool_connections'): Promise<P2poolConnections>;
+    function invoke(
+        param: 'get_p2pool_connections',
+        payload?: { cursor?: string; limit?: number }
+    ): Promise<Page<ConnectedPeerInfo>>;
     function invoke(param: 'get_used_p2pool_stats_server_port'): Promise<number>;
     function invoke(param: 'set_gpu_mining_enabled', payload: { enabled: boolean }): Promise<void>;
     function invoke(param: 'set_cpu_mining_enabled', payload: { enabled: boolean }): Promise<void>;
@@ -71,14 +131,15 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'set_use_tor', payload: { useTor: boolean }): Promise<void>;
     function invoke(
         param: 'get_coinbase_transactions',
-        payload: { continuation: boolean; limit?: number }
-    ): Promise<TransactionInfo[]>;
+        payload: { cursor?: string; limit?: number }
+    ): Promise<Page<TransactionInfo>>;
     function invoke(
         param: 'get_transactions_history',
-        payload: { offset?: number; limit?: number }
-    ): Promise<TransactionInfo[]>;
+        payload: { cursor?: string; limit?: number }
+    ): Promise<Page<TransactionInfo>>;
     function invoke(param: 'import_seed_words', payload: { seedWords: string[] }): Promise<void>;
     function invoke(param: 'get_tor_config'): Promise<TorConfig>;
+    function invoke(param: 'get_tor_status'): Promise<TorConnectionStatus>;
     function invoke(param: 'set_tor_config', payload: { config: TorConfig }): Promise<TorConfig>;
     function invoke(param: 'fetch_tor_bridges'): Promise<string[]>;
     function invoke(param: 'get_tor_entry_guards'): Promise<string[]>;
@@ -124,6 +185,13 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'validate_minotari_amount', payload: { amount: string }): Promise<string>;
     function invoke(param: 'trigger_phases_restart'): Promise<void>;
     function invoke(param: 'set_node_type', payload: { nodeType: NodeType }): Promise<void>;
+    function invoke(param: 'restart_node'): Promise<void>;
+    function invoke(param: 'resync_node'): Promise<void>;
+    function invoke(param: 'get_gpu_driver_compatibility'): Promise<GpuDriverCompatibility>;
+    function invoke(param: 'get_power_assertion_status'): Promise<PowerAssertionStatus>;
+    function invoke(param: 'set_prevent_sleep_while_mining', payload: { enabled: boolean }): Promise<PowerAssertionStatus>;
+    function invoke(param: 'set_disable_app_nap', payload: { disableAppNap: boolean }): Promise<PowerAssertionStatus>;
+    function invoke(param: 'get_wallet_backup_status'): Promise<WalletBackupStatus>;
     function invoke(param: 'set_warmup_seen', payload: { warmupSeen: boolean }): Promise<void>;
     function invoke(param: 'set_tari_address', payload: { address: string }): Promise<void>;
     function invoke(param: 'confirm_exchange_address', payload: { address: string }): Promise<void>;
@@ -131,10 +199,47 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'user_selected_exchange', payload: { exchange_miner: ExchangeMiner }): Promise<void>;
     function invoke(param: 'is_universal_miner'): Promise<boolean>;
     function invoke(param: 'launch_builtin_tapplet'): Promise<ActiveTapplet>;
+    function invoke(param: 'get_tapplets_status'): Promise<TappletStatus[]>;
+    function invoke(param: 'report_tapplet_crash', payload: { tapplet: string; reason: string }): Promise<void>;
+    function invoke(param: 'reload_tapplet', payload: { tapplet: string }): Promise<TappletStatus>;
+    function invoke(param: 'report_tapplet_load_failure', payload: { source: string; error: string }): Promise<void>;
+    function invoke(param: 'get_tapplet_storage_usage', payload: { tapplet: string }): Promise<TappletStorageUsage>;
+    function invoke(param: 'clear_tapplet_data'): Promise<void>;
+    function invoke(param: 'read_tapplet_data', payload: { key: string }): Promise<string | null>;
+    function invoke(
+        param: 'write_tapplet_data',
+        payload: { key: string; value: string }
+    ): Promise<void>;
+    function invoke(param: 'install_tapplet', payload: { tapplet: string }): Promise<void>;
+    function invoke(param: 'set_tapplet_storage_quota', payload: { quotaBytes: number }): Promise<void>;
     function invoke(param: 'get_tari_wallet_address'): Promise<string>;
-    function invoke(param: 'get_tari_wallet_balance'): Promise<WalletBalance>;
+    function invoke(param: 'get_tari_wallet_balance'): Promise<WalletBalanceResponse>;
+    function invoke(param: 'get_setup_progress'): Promise<SetupProgress>;
     function invoke(param: 'get_bridge_envs'): Promise<BridgeEnvs>;
     function invoke(param: 'parse_tari_address', payload: { address: string }): Promise<TariAddressVariants>;
     function invoke(param: 'refresh_wallet_history'): Promise<void>;
+    function invoke(
+        param: 'backup_wallet_database',
+        payload: { destinationDir: string; passphrase?: string }
+    ): Promise<string>;
+    function invoke(
+        param: 'restore_wallet_database',
+        payload: { backupFile: string; passphrase?: string }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_fast_sync',
+        payload: {
+            fastSync: { enabled: boolean; mirrors: string[]; trusted_public_key: string };
+            acknowledgeTrustRisk: boolean;
+        }
+    ): Promise<void>;
+    function invoke(param: 'export_chain_snapshot', payload: { destinationDir: string }): Promise<ChainSnapshotInfo>;
+    function invoke(
+        param: 'import_chain_snapshot',
+        payload: { snapshotFile: string; expectedChecksum?: string }
+    ): Promise<void>;
+    function invoke(param: 'get_daily_earnings'): Promise<DailyEarnings[]>;
+    function invoke(param: 'export_earnings_report', payload: { destinationDir: string }): Promise<string>;
+    function invoke(param: 'rescan_hardware'): Promise<GpuDevice[]>;
     function invoke(param: 'get_universal_miner_initialized_exchange_id'): Promise<string | undefiend>;
 }
@@ -1,6 +1,7 @@
 import { setError as setStoreError, useConfigUIStore } from '@app/store';
 import { invoke } from '@tauri-apps/api/core';
 import { BridgeEnvs, WalletBalance } from '../app-status';
+import { sendErrorMessage } from '../transactions';
 import { AccountData, BridgeTxDetails, SendOneSidedRequest, TappletSignerParams, WindowSize } from './tapplet.types';
 import { useTappletsStore } from '@app/store/useTappletsStore';
 
@@ -83,7 +84,7 @@ export class TappletSigner {
             });
             return true;
         } catch (error) {
-            setStoreError(`Error sending transaction: ${error}`);
+            setStoreError(`Error sending transaction: ${sendErrorMessage(error)}`);
             return false;
         }
     }
@@ -24,6 +24,33 @@ export interface ActiveTapplet {
     supportedChain: SupportedChain[];
 }
 
+export interface TappletStorageUsage {
+    tapplet: string;
+    used_bytes: number;
+    quota_bytes: number;
+}
+
+export interface TappletDeepLink {
+    tapplet: string;
+    path: string;
+    params: [string, string][];
+    requires_install: boolean;
+}
+
+export type TappletLifecycleStatus = 'installed' | 'updated' | 'started' | 'crashed';
+
+export interface TappletStatus {
+    tapplet: string;
+    status: TappletLifecycleStatus;
+    version?: string;
+    source?: string;
+    started_at?: number;
+    last_error?: string;
+    is_healthy: boolean;
+    crash_count: number;
+    reload_attempts: number;
+}
+
 export interface SendOneSidedRequest {
     amount: string;
     address: string;
@@ -22,3 +22,14 @@ export enum TransactionStatus {
     CoinbaseConfirmed = 13,
     CoinbaseNotInBlockChain = 14,
 }
+
+export interface SendError {
+    code: number;
+    kind: string;
+    message: string;
+}
+
+export function sendErrorMessage(error: unknown): string {
+    const sendError = error as Partial<SendError> | undefined;
+    return typeof sendError?.message === 'string' ? sendError.message : `${error}`;
+}